@@ -0,0 +1,274 @@
+// Module for caching propagated states of a satellite
+
+// ------------------
+// External Libraries
+// ------------------
+use std::collections::{HashMap, VecDeque};
+
+// ------------------
+// Internal Libraries
+// ------------------
+use crate::common::StateVector;
+use crate::sgp4::{propagate, PropagationError, Sgp4};
+
+// -------
+// Structs
+// -------
+
+/// A memoizing wrapper around [`propagate`] for callers that request nearby times repeatedly, such as a UI scrubbing
+/// a timeline
+///
+/// Requested times are rounded to the nearest multiple of the time resolution, and the satellite is propagated to the
+/// rounded time, so every request that rounds to the same time returns the same state whichever request came first.
+/// The cache holds at most `capacity` states and evicts the least recently used state when it is full (LRU). Hits and
+/// misses take constant amortized time whatever the capacity, and memory is allocated as states are cached rather
+/// than for the full capacity up front. Failed propagations are not cached.
+#[derive(Debug, Clone)]
+pub struct PropagationCache {
+    /// The SGP4 parameters of the satellite, including its TLE
+    sgp4: Sgp4,
+
+    /// The time resolution of the cache \[min\]
+    resolution_min: f64,
+
+    /// The maximum number of cached states
+    capacity: usize,
+
+    /// The cached states and the generation of their last use, keyed by the requested time in multiples of the
+    /// resolution
+    states: HashMap<i64, (StateVector, u64)>,
+
+    /// The keys and generations of each use, from the least to the most recently used. A use is stale once the state
+    /// has been used again with a later generation, and stale uses are skipped on eviction.
+    usage: VecDeque<(i64, u64)>,
+
+    /// The generation of the latest use
+    generation: u64,
+}
+
+// ---------------
+// Implementations
+// ---------------
+
+impl PropagationCache {
+    /// Create an empty cache for a satellite
+    ///
+    /// # Arguments
+    /// * `sgp4` - The SGP4 parameters of the satellite
+    /// * `resolution_min` - The time resolution, within which requests share a state (e.g. `1. / 60.` for the nearest
+    ///   second) \[min\]
+    /// * `capacity` - The maximum number of cached states, at least 1
+    ///
+    /// # Returns
+    /// * `Result<PropagationCache, PropagationError>` - The empty cache
+    ///
+    /// # Errors
+    /// Returns `PropagationError::InvalidStep` if the resolution is not positive and finite.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::cache::PropagationCache;
+    /// use Rusty_SGP4::sgp4::init_sgp4;
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(line1, line2, None).unwrap();
+    ///
+    /// // Cache up to a day of states at a resolution of one second
+    /// let mut cache = PropagationCache::new(init_sgp4(&tle, None), 1. / 60., 86400)?;
+    ///
+    /// // Requests a fraction of a second apart share a state
+    /// let state = cache.propagate(10.)?;
+    /// assert_eq!(cache.propagate(10. + 0.2 / 60.)?, state);
+    /// assert_eq!(cache.len(), 1);
+    /// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+    /// ```
+    pub fn new(sgp4: Sgp4, resolution_min: f64, capacity: usize) -> Result<PropagationCache, PropagationError> {
+        if !resolution_min.is_finite() || resolution_min <= 0. {
+            return Err(PropagationError::InvalidStep { step: resolution_min });
+        }
+
+        let cache = PropagationCache {
+            sgp4: sgp4,
+            resolution_min: resolution_min,
+            capacity: capacity.max(1),
+            states: HashMap::new(),
+            usage: VecDeque::new(),
+            generation: 0,
+        };
+
+        return Ok(cache);
+    }
+
+    /// Propagate the satellite to a time since the TLE epoch, reusing a cached state within the time resolution
+    ///
+    /// # Arguments
+    /// * `minutes_since_epoch` - The time since the TLE epoch \[min\]
+    ///
+    /// # Returns
+    /// * `Result<StateVector, PropagationError>` - The TEME state at the requested time rounded to the resolution.
+    ///   Times that are not finite are propagated as requested and not cached
+    ///
+    /// # Errors
+    /// Returns the `PropagationError` of [`propagate`] if the SGP4 theory fails at the rounded time.
+    pub fn propagate(&mut self, minutes_since_epoch: f64) -> Result<StateVector, PropagationError> {
+        if !minutes_since_epoch.is_finite() {
+            return propagate(&self.sgp4, minutes_since_epoch);
+        }
+
+        // A hit becomes the most recently used state
+        let key = (minutes_since_epoch / self.resolution_min).round() as i64;
+        self.generation += 1;
+        if let Some((state, last_use)) = self.states.get_mut(&key) {
+            *last_use = self.generation;
+            let state = *state;
+            self.record_use(key);
+            return Ok(state);
+        }
+
+        // A miss is propagated to the rounded time, evicting the least recently used state if the cache is full
+        let state = propagate(&self.sgp4, key as f64 * self.resolution_min)?;
+        if self.states.len() >= self.capacity {
+            self.evict_least_recently_used();
+        }
+        self.states.insert(key, (state, self.generation));
+        self.record_use(key);
+
+        return Ok(state);
+    }
+
+    /// Append a use of a cached state with the latest generation, dropping the stale uses once they outnumber the
+    /// cached states so the queue stays within twice the number of states
+    ///
+    /// # Arguments
+    /// * `key` - The key of the used state
+    fn record_use(&mut self, key: i64) {
+        self.usage.push_back((key, self.generation));
+        if self.usage.len() > 2 * self.states.len() {
+            let states = &self.states;
+            self.usage.retain(|(key, generation)| states.get(key).is_some_and(|&(_state, last_use)| last_use == *generation));
+        }
+    }
+
+    /// Remove the least recently used state, skipping the stale uses at the front of the queue
+    fn evict_least_recently_used(&mut self) {
+        while let Some((key, generation)) = self.usage.pop_front() {
+            if self.states.get(&key).is_some_and(|&(_state, last_use)| last_use == generation) {
+                self.states.remove(&key);
+                return;
+            }
+        }
+    }
+
+    /// The SGP4 parameters of the cached satellite
+    ///
+    /// # Returns
+    /// * `&Sgp4` - The SGP4 parameters, including the TLE
+    pub fn sgp4(&self) -> &Sgp4 {
+        return &self.sgp4;
+    }
+
+    /// The number of cached states
+    ///
+    /// # Returns
+    /// * `usize` - The number of cached states, at most the capacity
+    pub fn len(&self) -> usize {
+        return self.states.len();
+    }
+
+    /// Whether the cache holds no states
+    ///
+    /// # Returns
+    /// * `bool` - True if no state is cached
+    pub fn is_empty(&self) -> bool {
+        return self.states.is_empty();
+    }
+
+    /// Remove every cached state
+    pub fn clear(&mut self) {
+        self.states.clear();
+        self.usage.clear();
+    }
+}
+
+// ----------
+// Unit Tests
+// ----------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sgp4::init_sgp4;
+    use crate::tle::from_lines;
+
+    /// Initialize the SGP4 parameters of the ISS
+    fn iss_sgp4() -> Sgp4 {
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        return init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_propagation_cache_hit() {
+        let mut cache = PropagationCache::new(iss_sgp4(), 1. / 60., 16).unwrap();
+
+        // Two requests 0.3 s apart within the same second share the state at the rounded time
+        let state = cache.propagate(10. + 0.1 / 60.).unwrap();
+        assert_eq!(cache.propagate(10. + 0.4 / 60.).unwrap(), state);
+        assert_eq!(state, propagate(cache.sgp4(), 10.).unwrap());
+        assert_eq!(cache.len(), 1);
+
+        // A request in the next second is a miss
+        let state_next = cache.propagate(10. + 1. / 60.).unwrap();
+        assert_ne!(state_next, state);
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_propagation_cache_lru() {
+        let mut cache = PropagationCache::new(iss_sgp4(), 1., 2).unwrap();
+
+        // Filling the cache and using the oldest state makes the other state the least recently used
+        cache.propagate(0.).unwrap();
+        cache.propagate(1.).unwrap();
+        cache.propagate(0.).unwrap();
+
+        // A third state evicts it and the size stays bounded
+        cache.propagate(2.).unwrap();
+        assert_eq!(cache.len(), 2);
+        assert!(cache.states.contains_key(&0) && cache.states.contains_key(&2));
+
+        // Repeated hits keep the queue of uses bounded by the number of states
+        for _ in 0..100 {
+            cache.propagate(0.).unwrap();
+            cache.propagate(2.).unwrap();
+        }
+        assert!(cache.usage.len() <= 2 * cache.len());
+
+        // The least recently used state is still the one evicted
+        cache.propagate(3.).unwrap();
+        assert!(cache.states.contains_key(&2) && cache.states.contains_key(&3));
+    }
+
+    #[test]
+    fn test_propagation_cache_errors() {
+        // The resolution must be positive
+        assert_eq!(PropagationCache::new(iss_sgp4(), 0., 16).unwrap_err(), PropagationError::InvalidStep { step: 0. });
+        assert!(PropagationCache::new(iss_sgp4(), f64::NAN, 16).is_err());
+
+        // A zero capacity still caches the latest state
+        let mut cache = PropagationCache::new(iss_sgp4(), 1., 0).unwrap();
+        cache.propagate(0.).unwrap();
+        cache.propagate(1.).unwrap();
+        assert_eq!(cache.len(), 1);
+
+        // An unbounded capacity does not allocate up front
+        let mut cache = PropagationCache::new(iss_sgp4(), 1., usize::MAX).unwrap();
+        cache.propagate(0.).unwrap();
+        assert_eq!(cache.len(), 1);
+    }
+}
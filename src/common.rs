@@ -3,11 +3,15 @@
 // ------------------
 // External Libraries
 // ------------------
-use std::f64::consts::PI;
+use core::f64::consts::PI;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
 
 // ------------------
 // Internal Libraries
 // ------------------
+use crate::math::Vector3;
+use crate::sgp4::{solve_kepler, PropagationError, KEPLER_TOLERANCE};
 
 // -------
 // Structs
@@ -19,7 +23,7 @@ use std::f64::consts::PI;
 ///
 /// References:
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Wgs {
     /// Earth's standard gravitational parameter \[km^3/s^2\]
     pub mu: f64,
@@ -27,6 +31,9 @@ pub struct Wgs {
     /// Earth's equatorial radius \[km\]
     pub r_earth_eq: f64,
 
+    /// Earth's ellipsoid flattening \[\]
+    pub flattening: f64,
+
     /// Earth's J2 harmonic \[\]
     pub j2: f64,
 
@@ -47,22 +54,24 @@ pub struct Wgs {
 
     /// The inverse of ke \[min / Earth radii^1.5\]
     pub tumin: f64,
+
+    /// Earth's sidereal rotation rate, used by the deep space resonance terms and the ECEF velocity \[rad / min\]
+    pub omega_earth: f64,
 }
 
 /// State vector
 ///
-/// This struct contains the state vector of a satellite.
-#[derive(Default, Clone, Copy)]
+/// This struct contains the state vector of a satellite, with the time it was propagated to and its coordinate frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct StateVector {
+    /// Time since the TLE epoch \[min\]
+    pub time_min: f64,
+
     /// Position vector \[km\]
-    pub r_x: f64,
-    pub r_y: f64,
-    pub r_z: f64,
+    pub position: Vector3,
 
     /// Velocity vector \[km/s\]
-    pub v_x: f64,
-    pub v_y: f64,
-    pub v_z: f64,
+    pub velocity: Vector3,
 
     /// Coordinate frame
     pub coordinate_frame: CoordinateFrame,
@@ -82,25 +91,63 @@ pub struct StateVector {
 ///
 /// let frame_teme = CoordinateFrame::TEME;
 /// let frame_j2000 = CoordinateFrame::J2000;
+/// let frame_ecef = CoordinateFrame::ECEF;
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum CoordinateFrame {
     /// True Equator Mean Equinox (TEME), an Earth-centered inertial (ECI) coordinate frame
+    #[default]
     TEME,
     /// J2000, an Earth-centered inertial (ECI) coordinate frame
     J2000,
+    /// Earth-centered, Earth-fixed (ECEF), rotating with the Earth, with velocities relative to the rotating Earth
+    ECEF,
+}
+
+/// World Geodetic System (WGS) models
+///
+/// Selects one of the predefined WGS models without importing its constants.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::{WgsModel, WGS84};
+///
+/// let wgs = WgsModel::Wgs84.constants();
+/// assert_eq!(wgs, WGS84);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WgsModel {
+    /// WGS-72, the standard for TLEs (see [`WGS72`])
+    #[default]
+    Wgs72,
+    /// The original WGS-72 model of Spacetrack Report #3 (see [`WGS72OLD`])
+    Wgs72Old,
+    /// WGS-84 (see [`WGS84`])
+    Wgs84,
 }
 
 // ---------
 // Constants
 // ---------
 
+/// Earth's sidereal rotation rate of the SGP4 reference implementation \[rad / min\]
+///
+/// References:
+/// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
+const RPTIM: f64 = 4.37526908801129966e-3;
+
+/// The iteration limit of [`mean_to_eccentric_anomaly`], enough for the bisection fallback to converge for any
+/// eccentricity below 1
+const KEPLER_MAX_ITERATIONS: usize = 100;
+
 /// Fundamental and derived constants for WGS-72
 ///
 /// mu: 398600.8 - Standard gravitational parameter, a product of the gravitational constant and the body's mass \[km^3 / s^2\]
 ///
 /// r_earth_eq: 6378.135 - The Earth's equatorial radius \[km\]
 ///
+/// flattening: 1 / 298.26 - The flattening of the Earth's reference ellipsoid
+///
 /// j2: 0.001082616 - Second zonal harmonic (Earth's oblateness). Represents the equatorial bulge
 ///
 /// k2: 0.000541308 - k2 constant 0.5 * j2 \[Earth Radii^2\]
@@ -115,18 +162,66 @@ pub enum CoordinateFrame {
 ///
 /// tumin: 13.44683969695931 - The inverse of ke \[min / Earth radii^1.5\]
 ///
+/// omega_earth: 0.00437526908801129966 - Earth's sidereal rotation rate \[rad / min\]
+///
 /// References:
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 pub const WGS72: Wgs = Wgs {
     mu: 398600.8,
     r_earth_eq: 6378.135,
+    flattening: 1. / 298.26,
     j2: 0.001082616,
     k2: 0.000541308,
     j3: -0.00000253881,
     j4: -0.00000165597,
     k4: 0.00000062098875,
     ke: 0.07436691613317,
-    tumin: 13.44683969695931
+    tumin: 13.44683969695931,
+    omega_earth: RPTIM,
+};
+
+/// Fundamental and derived constants for the original WGS-72 model
+///
+/// These are the legacy constants from Spacetrack Report #3, used by older implementations. They differ from
+/// [`WGS72`] in `mu` (398600.79964 vs 398600.8) and in `ke`, which is the truncated legacy value 0.0743669161
+/// rather than being derived from `mu` (0.07436691613317). The radius and zonal harmonics are identical.
+///
+/// mu: 398600.79964 - Standard gravitational parameter, a product of the gravitational constant and the body's mass \[km^3 / s^2\]
+///
+/// r_earth_eq: 6378.135 - The Earth's equatorial radius \[km\]
+///
+/// flattening: 1 / 298.26 - The flattening of the Earth's reference ellipsoid
+///
+/// j2: 0.001082616 - Second zonal harmonic (Earth's oblateness). Represents the equatorial bulge
+///
+/// k2: 0.000541308 - k2 constant 0.5 * j2 \[Earth Radii^2\]
+///
+/// j3: -0.00000253881 - Third zonal harmonic (Pear-shaped component)
+///
+/// j4: -0.00000165597 - Fourth zonal harmonic (Symmetric "squatness")
+///
+/// k4: 0.00000062098875 - k4 constant -3/8 * j4 \[Earth Radii^4\]
+///
+/// ke: 0.0743669161 - The square root of the standard gravitational parameter \[Earth radii^1.5 / min\]
+///
+/// tumin: 13.446839702957643 - The inverse of ke \[min / Earth radii^1.5\]
+///
+/// omega_earth: 0.00437526908801129966 - Earth's sidereal rotation rate \[rad / min\]
+///
+/// References:
+/// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
+pub const WGS72OLD: Wgs = Wgs {
+    mu: 398600.79964,
+    r_earth_eq: 6378.135,
+    flattening: 1. / 298.26,
+    j2: 0.001082616,
+    k2: 0.000541308,
+    j3: -0.00000253881,
+    j4: -0.00000165597,
+    k4: 0.00000062098875,
+    ke: 0.0743669161,
+    tumin: 13.446839702957643,
+    omega_earth: RPTIM,
 };
 
 /// Fundamental and derived constants for WGS-84
@@ -135,6 +230,8 @@ pub const WGS72: Wgs = Wgs {
 ///
 /// r_earth_eq: 6378.137 - The Earth's equatorial radius \[km\]
 ///
+/// flattening: 1 / 298.257223563 - The flattening of the Earth's reference ellipsoid
+///
 /// j2: 0.00108262998905 - Second zonal harmonic (Earth's oblateness). Represents the equatorial bulge
 ///
 /// k2: 0.000541314994525 - k2 constant 0.5 * j2 \[Earth Radii^2\]
@@ -148,21 +245,103 @@ pub const WGS72: Wgs = Wgs {
 /// ke: 0.07436685316871 - The square root of the standard gravitational parameter \[Earth radii^1.5 / min\]
 ///
 /// tumin: 13.44685108204498 - The inverse of ke \[min / Earth radii^1.5\]
+///
+/// omega_earth: 0.00437526908801129966 - Earth's sidereal rotation rate \[rad / min\]
 /// 
 /// References:
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 pub const WGS84: Wgs = Wgs {
     mu: 398600.5,
     r_earth_eq: 6378.137,
+    flattening: 1. / 298.257223563,
     j2: 0.00108262998905,
     k2: 0.000541314994525,
     j3: -0.00000253215306,
     j4: -0.00000161098761,
     k4: 0.0000006041203538,
     ke: 0.07436685316871,
-    tumin: 13.44685108204498
+    tumin: 13.44685108204498,
+    omega_earth: RPTIM,
 };
 
+// ---------------
+// Implementations
+// ---------------
+
+impl Wgs {
+    /// Builds a WGS model from its fundamental constants, deriving the remaining SGP4 constants.
+    ///
+    /// The derived constants are k2 = j2 / 2, k4 = -3/8 * j4, ke = 60 / sqrt(r_earth_eq^3 / mu) and tumin = 1 / ke.
+    /// The rotation rate is that of the predefined models, and a different sidereal rate can be modeled with struct
+    /// update syntax (`Wgs { omega_earth: rate, ..wgs }`).
+    ///
+    /// # Arguments
+    /// * `mu` - Earth's standard gravitational parameter \[km^3/s^2\]
+    /// * `r_earth_eq` - Earth's equatorial radius \[km\]
+    /// * `flattening` - Earth's ellipsoid flattening \[\]
+    /// * `j2` - Earth's J2 harmonic \[\]
+    /// * `j3` - Earth's J3 harmonic \[\]
+    /// * `j4` - Earth's J4 harmonic \[\]
+    ///
+    /// # Returns
+    /// * `Wgs` - The WGS model
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::common::{Wgs, WGS84};
+    ///
+    /// // Build the WGS-84 model from its fundamental constants
+    /// let wgs = Wgs::new(398600.5, 6378.137, 1. / 298.257223563, 0.00108262998905, -0.00000253215306, -0.00000161098761);
+    /// assert!((wgs.ke - WGS84.ke).abs() < 1e-10);
+    /// ```
+    ///
+    /// # References
+    /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
+    pub fn new(mu: f64, r_earth_eq: f64, flattening: f64, j2: f64, j3: f64, j4: f64) -> Wgs {
+        // Calculate the square root of the gravitational parameter in Earth radii and minutes
+        let ke = 60. / (r_earth_eq.powi(3) / mu).sqrt(); // [Earth radii^1.5 / min]
+
+        // Build the WGS model
+        let wgs = Wgs {
+            mu: mu,
+            r_earth_eq: r_earth_eq,
+            flattening: flattening,
+            j2: j2,
+            k2: 0.5 * j2,
+            j3: j3,
+            j4: j4,
+            k4: -0.375 * j4,
+            ke: ke,
+            tumin: 1. / ke,
+            omega_earth: RPTIM,
+        };
+
+        return wgs;
+    }
+}
+
+impl WgsModel {
+    /// Returns the constants of the WGS model.
+    ///
+    /// # Returns
+    /// * `Wgs` - The constants, one of `WGS72`, `WGS72OLD` or `WGS84`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::common::{WgsModel, WGS72};
+    ///
+    /// // The default model is the TLE standard
+    /// assert_eq!(WgsModel::default().constants(), WGS72);
+    /// ```
+    pub fn constants(&self) -> Wgs {
+        return match self {
+            WgsModel::Wgs72 => WGS72,
+            WgsModel::Wgs72Old => WGS72OLD,
+            WgsModel::Wgs84 => WGS84,
+        };
+    }
+}
+
 // ---------
 // Functions
 // ---------
@@ -177,6 +356,9 @@ pub const WGS84: Wgs = Wgs {
 ///
 /// # Examples
 /// ```rust
+/// use core::f64::consts::PI;
+/// use Rusty_SGP4::common::deg2rad;
+///
 /// // Define some angle in degrees
 /// let theta = 90.0; // Degrees
 ///
@@ -205,6 +387,8 @@ pub fn deg2rad(theta: f64) -> f64 {
 ///
 /// # Examples
 /// ```rust
+/// use Rusty_SGP4::common::calc_period;
+///
 /// // Define the semi-major axis and the standard gravitational parameter
 /// let a = 6378.137; // [km]
 /// let mu = 398600.5; // [km^3 / s^2]
@@ -222,6 +406,306 @@ pub fn calc_period(a: f64, mu: f64) -> f64 {
     return period;
 }
 
+/// Solve Kepler's equation, M = E - e sin(E), for the eccentric anomaly.
+///
+/// This is [`solve_kepler`] with a tolerance of 1e-12 rad and a limit of 100 iterations. A circular orbit returns the
+/// mean anomaly unchanged.
+///
+/// # Arguments
+/// * `mean_anom_rad` - The mean anomaly \[rad\]
+/// * `ecc` - The orbital eccentricity in \[0, 1) \[\]
+///
+/// # Returns
+/// * `Result<f64, PropagationError>` - The eccentric anomaly, in the same revolution as the mean anomaly \[rad\]
+///
+/// # Errors
+/// Returns the `PropagationError` of [`solve_kepler`] if the eccentricity is outside of \[0, 1) or the iteration does
+/// not converge.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::mean_to_eccentric_anomaly;
+///
+/// // Example 2-1 from Vallado
+/// let ecc_anom = mean_to_eccentric_anomaly(235.4_f64.to_radians(), 0.4)?;
+/// assert!((ecc_anom - 3.848661745).abs() < 1e-9);
+/// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn mean_to_eccentric_anomaly(mean_anom_rad: f64, ecc: f64) -> Result<f64, PropagationError> {
+    return solve_kepler(mean_anom_rad, ecc, KEPLER_TOLERANCE, KEPLER_MAX_ITERATIONS);
+}
+
+/// Convert an eccentric anomaly to a true anomaly.
+///
+/// The half-angle form, tan(nu / 2) = sqrt((1 + e) / (1 - e)) tan(E / 2), is evaluated with `atan2`
+/// so the true anomaly is in the same half of the orbit as the eccentric anomaly.
+///
+/// # Arguments
+/// * `ecc_anom_rad` - The eccentric anomaly \[rad\]
+/// * `ecc` - The orbital eccentricity in \[0, 1) \[\]
+///
+/// # Returns
+/// * `true_anom` - The true anomaly, in \[0, 2pi\] for an eccentric anomaly in \[0, 2pi) \[rad\]
+///
+/// # Examples
+/// ```rust
+/// use core::f64::consts::PI;
+/// use Rusty_SGP4::common::eccentric_to_true_anomaly;
+///
+/// // Apogee is at the same anomaly for every eccentricity
+/// let true_anom = eccentric_to_true_anomaly(PI, 0.7);
+/// assert!((true_anom - PI).abs() < 1e-12);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn eccentric_to_true_anomaly(ecc_anom_rad: f64, ecc: f64) -> f64 {
+    // Evaluate the half-angle form
+    let half = ecc_anom_rad / 2.;
+    let true_anom = 2. * ((1. + ecc).sqrt() * half.sin()).atan2((1. - ecc).sqrt() * half.cos()); // [rad]
+
+    return true_anom;
+}
+
+/// Calculate the speed of a satellite from its velocity vector.
+///
+/// # Arguments
+/// * `vel` - The velocity vector \[km / s\]
+///
+/// # Returns
+/// * `speed` - The magnitude of the velocity \[km / s\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::speed;
+/// use Rusty_SGP4::math::Vector3;
+///
+/// let speed = speed(Vector3::new(3., 4., 0.));
+/// assert_eq!(speed, 5.);
+/// ```
+pub fn speed(vel: Vector3) -> f64 {
+    return vel.magnitude();
+}
+
+/// Calculate the flight path angle of a satellite from its position and velocity vectors.
+///
+/// The flight path angle is measured from the local horizontal, the plane perpendicular to the position vector, to
+/// the velocity. It is positive while the satellite climbs from perigee to apogee and zero for a circular orbit. The
+/// angle is evaluated with `atan2` of the radial and horizontal velocity components, which stays accurate near zero.
+///
+/// # Arguments
+/// * `pos` - The position vector \[km\]
+/// * `vel` - The velocity vector, in the same frame as the position \[km / s\]
+///
+/// # Returns
+/// * `fpa` - The flight path angle in \[-90, 90\] \[deg\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::flight_path_angle;
+/// use Rusty_SGP4::math::Vector3;
+///
+/// // Velocity at 45 degrees above the horizontal
+/// let fpa = flight_path_angle(Vector3::new(7000., 0., 0.), Vector3::new(5., 5., 0.));
+/// assert!((fpa - 45.).abs() < 1e-12);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn flight_path_angle(pos: Vector3, vel: Vector3) -> f64 {
+    // Split the velocity into radial and horizontal components, scaled by the radius
+    let radial = pos.dot(&vel);
+    let horizontal = pos.cross(&vel).magnitude();
+
+    return radial.atan2(horizontal).to_degrees();
+}
+
+/// Calculate the specific angular momentum of a satellite from its position and velocity vectors, h = r × v.
+///
+/// The angular momentum is normal to the orbital plane and, without perturbations, constant along the orbit, so
+/// comparing it across propagated states is a check on the model.
+///
+/// # Arguments
+/// * `pos` - The position vector \[km\]
+/// * `vel` - The velocity vector, in the same frame as the position \[km / s\]
+///
+/// # Returns
+/// * `h` - The specific angular momentum vector \[km^2 / s\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::specific_angular_momentum;
+/// use Rusty_SGP4::math::Vector3;
+///
+/// // An equatorial orbit has its angular momentum along the Z axis
+/// let h = specific_angular_momentum(Vector3::new(7000., 0., 0.), Vector3::new(0., 7.5, 0.));
+/// assert_eq!(h, Vector3::new(0., 0., 52500.));
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn specific_angular_momentum(pos: Vector3, vel: Vector3) -> Vector3 {
+    return pos.cross(&vel);
+}
+
+/// Calculate the specific orbital energy of a satellite from its position and velocity vectors, ξ = v²/2 − μ/r.
+///
+/// The energy is negative for bound orbits, where it equals −μ/(2a) for the semi-major axis a, and, without
+/// perturbations, constant along the orbit.
+///
+/// # Arguments
+/// * `pos` - The position vector \[km\]
+/// * `vel` - The velocity vector, in the same frame as the position \[km / s\]
+/// * `mu` - The standard gravitational parameter \[km^3 / s^2\]
+///
+/// # Returns
+/// * `energy` - The specific orbital energy \[km^2 / s^2\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::{specific_orbital_energy, WGS72};
+/// use Rusty_SGP4::math::Vector3;
+///
+/// // A satellite at rest at the radius of the Earth
+/// let energy = specific_orbital_energy(Vector3::new(WGS72.r_earth_eq, 0., 0.), Vector3::new(0., 0., 0.), WGS72.mu);
+/// assert!((energy + WGS72.mu / WGS72.r_earth_eq).abs() < 1e-12);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn specific_orbital_energy(pos: Vector3, vel: Vector3, mu: f64) -> f64 {
+    return 0.5 * vel.dot(&vel) - mu / pos.magnitude();
+}
+
 // ----------
 // Unit Tests
-// ----------
\ No newline at end of file
+// ----------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wgs_constants_distinct() {
+        // The legacy and revised WGS-72 models share the ellipsoid and harmonics but not the gravitational constants
+        assert_eq!(WGS72OLD.r_earth_eq, WGS72.r_earth_eq);
+        assert_eq!(WGS72OLD.j2, WGS72.j2);
+        assert_ne!(WGS72OLD.mu, WGS72.mu);
+        assert_ne!(WGS72OLD.ke, WGS72.ke);
+        assert_ne!(WGS72OLD.tumin, WGS72.tumin);
+
+        // WGS-84 differs from both
+        assert_ne!(WGS84.mu, WGS72.mu);
+        assert_ne!(WGS84.mu, WGS72OLD.mu);
+        assert_ne!(WGS84.r_earth_eq, WGS72.r_earth_eq);
+
+        // tumin is the inverse of ke for every model
+        for wgs in [WGS72OLD, WGS72, WGS84] {
+            assert!((wgs.ke * wgs.tumin - 1.).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_wgs_model_constants() {
+        assert_eq!(WgsModel::Wgs84.constants(), WGS84);
+        assert_eq!(WgsModel::Wgs72.constants(), WGS72);
+        assert_eq!(WgsModel::Wgs72Old.constants(), WGS72OLD);
+        assert_eq!(WgsModel::default(), WgsModel::Wgs72);
+    }
+
+    #[test]
+    fn test_wgs_new() {
+        // Build the WGS-84 and WGS-72 models from their fundamental constants
+        let wgs84 = Wgs::new(WGS84.mu, WGS84.r_earth_eq, WGS84.flattening, WGS84.j2, WGS84.j3, WGS84.j4);
+        let wgs72 = Wgs::new(WGS72.mu, WGS72.r_earth_eq, WGS72.flattening, WGS72.j2, WGS72.j3, WGS72.j4);
+
+        // Compare the derived constants against the hard-coded ones
+        for (wgs, expected) in [(wgs84, WGS84), (wgs72, WGS72)] {
+            assert_eq!(wgs.mu, expected.mu);
+            assert_eq!(wgs.r_earth_eq, expected.r_earth_eq);
+            assert!((wgs.k2 - expected.k2).abs() < 1e-10);
+            assert!((wgs.k4 - expected.k4).abs() < 1e-10);
+            assert!((wgs.ke - expected.ke).abs() < 1e-10);
+            assert!((wgs.tumin - expected.tumin).abs() < 1e-10);
+            assert_eq!(wgs.omega_earth, expected.omega_earth);
+        }
+    }
+
+    #[test]
+    fn test_speed_and_flight_path_angle() {
+        // A circular orbit state has perpendicular position and velocity
+        let pos = Vector3::new(4000., 5000., 2000.);
+        let vel = Vector3::new(-5., 4., 0.);
+        assert!((speed(vel) - 41_f64.sqrt()).abs() < 1e-12);
+        assert!(flight_path_angle(pos, vel).abs() < 1e-12, "Flight path angle: expected 0 deg, got {}", flight_path_angle(pos, vel));
+
+        // Climbing and descending states have opposite angles
+        let pos = Vector3::new(7000., 0., 0.);
+        let vel = Vector3::new(1., 7., 0.);
+        let fpa = flight_path_angle(pos, vel);
+        assert!((fpa - (1_f64 / 7.).atan().to_degrees()).abs() < 1e-12);
+        assert!((flight_path_angle(pos, Vector3::new(-1., 7., 0.)) + fpa).abs() < 1e-12);
+
+        // Purely radial motion is vertical
+        assert!((flight_path_angle(pos, Vector3::new(2., 0., 0.)) - 90.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_angular_momentum_and_energy() {
+        // A circular orbit inclined by 30 degrees
+        let a = 7000.; // [km]
+        let v = (WGS72.mu / a).sqrt(); // [km/s]
+        let (sin_i, cos_i) = 30_f64.to_radians().sin_cos();
+        let pos = Vector3::new(a, 0., 0.);
+        let vel = Vector3::new(0., v * cos_i, v * sin_i);
+
+        // The energy is -mu / (2a)
+        let energy = specific_orbital_energy(pos, vel, WGS72.mu);
+        assert!((energy + WGS72.mu / (2. * a)).abs() < 1e-12, "Energy: expected {}, got {}", -WGS72.mu / (2. * a), energy);
+
+        // The angular momentum is sqrt(mu a), normal to the orbital plane
+        let h = specific_angular_momentum(pos, vel);
+        assert!((h.magnitude() - (WGS72.mu * a).sqrt()).abs() < 1e-9);
+        assert!(h.dot(&pos).abs() < 1e-6 && h.dot(&vel).abs() < 1e-9);
+        assert!((h.z / h.magnitude() - cos_i).abs() < 1e-12);
+
+        // Both are the same at another point of the orbit
+        let pos_quarter = Vector3::new(0., a * cos_i, a * sin_i);
+        let vel_quarter = Vector3::new(-v, 0., 0.);
+        assert!((specific_orbital_energy(pos_quarter, vel_quarter, WGS72.mu) - energy).abs() < 1e-12);
+        assert!((specific_angular_momentum(pos_quarter, vel_quarter) - h).magnitude() < 1e-9);
+    }
+
+    #[test]
+    fn test_anomaly_conversions() {
+        // Example 2-1 from Vallado
+        let ecc_anom = mean_to_eccentric_anomaly(235.4_f64.to_radians(), 0.4).unwrap();
+        assert!((ecc_anom - 3.848661745097170).abs() < 1e-12, "Eccentric anomaly: expected 3.848661745, got {}", ecc_anom);
+        let true_anom = eccentric_to_true_anomaly(ecc_anom, 0.4);
+        assert!((true_anom.to_degrees() - 207.163991769214).abs() < 1e-9, "True anomaly: expected 207.163991769 deg, got {}", true_anom.to_degrees());
+
+        // Highly eccentric orbit near perigee
+        let ecc_anom = mean_to_eccentric_anomaly(10_f64.to_radians(), 0.99).unwrap();
+        assert!((ecc_anom - 1.016084831317552).abs() < 1e-12, "Eccentric anomaly: expected 1.016084831, got {}", ecc_anom);
+        let true_anom = eccentric_to_true_anomaly(ecc_anom, 0.99);
+        assert!((true_anom.to_degrees() - 165.488799855839).abs() < 1e-9, "True anomaly: expected 165.488799856 deg, got {}", true_anom.to_degrees());
+
+        // Kepler's equation holds across eccentricities and revolutions
+        for ecc in [0., 1e-7, 0.1859667, 0.6877146, 0.95, 0.999] {
+            for mean_anom in [-7., -0.5, 0., 0.3, 3., PI, 5.9, 13.] {
+                let ecc_anom = mean_to_eccentric_anomaly(mean_anom, ecc).unwrap();
+                assert!((ecc_anom - ecc * ecc_anom.sin() - mean_anom).abs() < 1e-10, "Kepler's equation failed for M = {}, e = {}", mean_anom, ecc);
+            }
+        }
+
+        // A circular orbit has equal mean, eccentric and true anomalies
+        assert_eq!(mean_to_eccentric_anomaly(1.2, 0.), Ok(1.2));
+        assert!((eccentric_to_true_anomaly(1.2, 0.) - 1.2).abs() < 1e-12);
+
+        // Unbound orbits and invalid anomalies are reported instead of returning a meaningless anomaly
+        assert!(matches!(mean_to_eccentric_anomaly(1.2, 1.), Err(PropagationError::EccentricityOutOfRange { .. })));
+        assert!(matches!(mean_to_eccentric_anomaly(f64::NAN, 0.5), Err(PropagationError::ConvergenceFailure { .. })));
+    }
+}
@@ -0,0 +1,226 @@
+// Module for screening close approaches between two satellites
+
+// ------------------
+// External Libraries
+// ------------------
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+// ------------------
+// Internal Libraries
+// ------------------
+use crate::sgp4::{count_range_steps, propagate, PropagationError, Sgp4};
+
+// ---------
+// Constants
+// ---------
+
+/// The time resolution of the refined time of closest approach \[min\]
+const CONJUNCTION_TIME_TOLERANCE: f64 = 1e-6;
+
+// ---------
+// Functions
+// ---------
+
+/// Find the time and range of the closest approach between two satellites over a search window
+///
+/// The range is sampled every `step_min` minutes from `start_min` up to and including `stop_min`, and each local
+/// minimum of the samples is refined with a golden-section search between its neighbors. Times are measured from the
+/// epoch of satellite `a`, and satellite `b` is propagated from its own epoch to the same absolute time, so the two
+/// element sets may have different epochs. The step must be short compared to the time the satellites take to
+/// close and separate (a minute or less for low Earth orbits), or the closest approach can fall between samples.
+///
+/// # Arguments
+/// * `a` - The SGP4 parameters of the first satellite
+/// * `b` - The SGP4 parameters of the second satellite
+/// * `start_min` - The start of the search window since the epoch of `a` \[min\]
+/// * `stop_min` - The end of the search window since the epoch of `a` \[min\]
+/// * `step_min` - The time between the coarse samples \[min\]
+///
+/// # Returns
+/// * `Result<(f64, f64), PropagationError>` - The time of closest approach since the epoch of `a` \[min\] and the
+///   minimum range \[km\]
+///
+/// # Errors
+/// Returns `PropagationError::InvalidStep` if the step is not positive.
+/// Returns `PropagationError::InvalidTimeRange` if the start or stop time is not finite, or the start time is after
+/// the stop time.
+/// Returns `PropagationError::TooManySamples` if the window holds more than 2^30 steps.
+/// Returns the first `PropagationError` of [`propagate`] if the SGP4 theory fails for either satellite.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::init_sgp4;
+/// use Rusty_SGP4::conjunction::closest_approach;
+///
+/// // Define the ISS TLE and a copy in a slightly more inclined orbit
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle_a = from_lines(line1, line2, None).unwrap();
+/// let mut tle_b = tle_a.clone();
+/// tle_b.inclination += 0.1;
+///
+/// // The orbits cross at the nodes, where the satellites come within a few km
+/// let (_tca, range) = closest_approach(&init_sgp4(&tle_a, None), &init_sgp4(&tle_b, None), 0., 100., 1.)?;
+/// assert!(range < 5.);
+/// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+/// ```
+pub fn closest_approach(a: &Sgp4, b: &Sgp4, start_min: f64, stop_min: f64, step_min: f64) -> Result<(f64, f64), PropagationError> {
+    // Validate the step and the time range
+    let n_steps = count_range_steps(start_min, stop_min, step_min)?;
+
+    // The time since the epoch of b at the epoch of a, with the whole and fractional days differenced separately
+    let offset_b = ((a.jd0 - b.jd0) + (a.jdfrac0 - b.jdfrac0)) * 1440.; // [min]
+
+    // The range between the satellites at a time since the epoch of a
+    let range = |t: f64| -> Result<f64, PropagationError> {
+        let state_a = propagate(a, t)?;
+        let state_b = propagate(b, t + offset_b)?;
+        return Ok((state_a.position - state_b.position).magnitude()); // [km]
+    };
+
+    // Sample the search window, including its end
+    let sample_time = |i: usize| if i > n_steps { stop_min } else { start_min + i as f64 * step_min }; // [min]
+    let n_samples = if sample_time(n_steps) < stop_min { n_steps + 2 } else { n_steps + 1 };
+
+    // Refine every local minimum of the samples, since the satellites can approach several times per orbit
+    let mut closest = (f64::NAN, f64::INFINITY); // (time [min], range [km])
+    let mut range_prev = f64::INFINITY; // [km]
+    let mut range_i = range(start_min)?; // [km]
+    for i in 0..n_samples {
+        let range_next = if i + 1 < n_samples { range(sample_time(i + 1))? } else { f64::INFINITY }; // [km]
+        if range_i <= range_prev && range_i <= range_next {
+            // Bracket the minimum by the neighboring samples, keeping the sample if the search does not improve on it
+            let (t_min, range_min) = golden_section_min(&range, sample_time(i.saturating_sub(1)), sample_time((i + 1).min(n_samples - 1)))?;
+            let candidate = if range_min < range_i { (t_min, range_min) } else { (sample_time(i), range_i) };
+            if candidate.1 < closest.1 {
+                closest = candidate;
+            }
+        }
+        range_prev = range_i;
+        range_i = range_next;
+    }
+
+    return Ok(closest);
+}
+
+/// Find the minimum of a function within a bracket with a golden-section search
+///
+/// # Arguments
+/// * `f` - The function, with a single minimum between `t_lo` and `t_hi`
+/// * `t_lo` - The start of the bracket \[min\]
+/// * `t_hi` - The end of the bracket \[min\]
+///
+/// # Returns
+/// * `Result<(f64, f64), PropagationError>` - The time of the minimum, within `CONJUNCTION_TIME_TOLERANCE` \[min\],
+///   and the value of the function there
+fn golden_section_min(f: &dyn Fn(f64) -> Result<f64, PropagationError>, t_lo: f64, t_hi: f64) -> Result<(f64, f64), PropagationError> {
+    let mut t_lo = t_lo;
+    let mut t_hi = t_hi;
+
+    // Shrink the bracket by the golden ratio, keeping the lower interior point
+    let inv_phi = (5_f64.sqrt() - 1.) / 2.;
+    let mut t_1 = t_hi - inv_phi * (t_hi - t_lo);
+    let mut t_2 = t_lo + inv_phi * (t_hi - t_lo);
+    let mut f_1 = f(t_1)?;
+    let mut f_2 = f(t_2)?;
+    while t_hi - t_lo > CONJUNCTION_TIME_TOLERANCE {
+        if f_1 < f_2 {
+            t_hi = t_2;
+            t_2 = t_1;
+            f_2 = f_1;
+            t_1 = t_hi - inv_phi * (t_hi - t_lo);
+            f_1 = f(t_1)?;
+        } else {
+            t_lo = t_1;
+            t_1 = t_2;
+            f_1 = f_2;
+            t_2 = t_lo + inv_phi * (t_hi - t_lo);
+            f_2 = f(t_2)?;
+        }
+    }
+
+    let t_min = 0.5 * (t_lo + t_hi);
+    return Ok((t_min, f(t_min)?));
+}
+
+// ----------
+// Unit Tests
+// ----------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sgp4::init_sgp4;
+    use crate::tle::{from_lines, Tle};
+
+    /// Parse the ISS TLE
+    fn iss_tle() -> Tle {
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        return from_lines(line1, line2, None).unwrap();
+    }
+
+    /// Find the closest approach by brute force on a fine grid
+    fn brute_force(a: &Sgp4, b: &Sgp4, offset_b: f64, start_min: f64, stop_min: f64) -> (f64, f64) {
+        let mut best = (start_min, f64::MAX);
+        let n = ((stop_min - start_min) / 1e-3) as usize;
+        for i in 0..=n {
+            let t = start_min + i as f64 * 1e-3;
+            let range = (propagate(a, t).unwrap().position - propagate(b, t + offset_b).unwrap().position).magnitude();
+            if range < best.1 {
+                best = (t, range);
+            }
+        }
+        return best;
+    }
+
+    #[test]
+    fn test_closest_approach_crossing_orbits() {
+        // The ISS and a copy in a slightly more inclined orbit cross at the nodes
+        let tle_a = iss_tle();
+        let mut tle_b = tle_a.clone();
+        tle_b.inclination += 0.1;
+        let sgp4_a = init_sgp4(&tle_a, None);
+        let sgp4_b = init_sgp4(&tle_b, None);
+
+        // The refined minimum matches a brute force search
+        let (tca, range) = closest_approach(&sgp4_a, &sgp4_b, 0., 100., 1.).unwrap();
+        let (tca_brute, range_brute) = brute_force(&sgp4_a, &sgp4_b, 0., 0., 100.);
+        assert!(range < 5., "Range at closest approach: {} km", range);
+        assert!(range <= range_brute + 1e-9);
+        assert!((tca - tca_brute).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_closest_approach_different_epochs() {
+        // A crossing orbit whose element set has an epoch 6 hours later
+        let tle_a = iss_tle();
+        let mut tle_b = tle_a.clone();
+        tle_b.inclination += 0.1;
+        tle_b.epoch_day += 0.25;
+        tle_b.mean_anomaly = (tle_b.mean_anomaly + 0.25 * 360. * tle_b.mean_motion) % 360.;
+        let sgp4_a = init_sgp4(&tle_a, None);
+        let sgp4_b = init_sgp4(&tle_b, None);
+
+        // The refined minimum matches a brute force search with b propagated from its own epoch
+        let (tca, range) = closest_approach(&sgp4_a, &sgp4_b, 0., 100., 1.).unwrap();
+        let (tca_brute, range_brute) = brute_force(&sgp4_a, &sgp4_b, -360., 0., 100.);
+        assert!(range <= range_brute + 1e-9);
+        assert!((tca - tca_brute).abs() < 1e-3);
+
+        // Swapping the satellites measures the time from the epoch of b instead
+        let (tca_swap, range_swap) = closest_approach(&sgp4_b, &sgp4_a, -360., -260., 1.).unwrap();
+        assert!((tca_swap - (tca - 360.)).abs() < 1e-4);
+        assert!((range_swap - range).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_closest_approach_errors() {
+        let sgp4 = init_sgp4(&iss_tle(), None);
+        assert_eq!(closest_approach(&sgp4, &sgp4, 0., 10., 0.), Err(PropagationError::InvalidStep { step: 0. }));
+        assert_eq!(closest_approach(&sgp4, &sgp4, 10., 0., 1.), Err(PropagationError::InvalidTimeRange { start: 10., stop: 0. }));
+        assert_eq!(closest_approach(&sgp4, &sgp4, 0., f64::INFINITY, 1.), Err(PropagationError::InvalidTimeRange { start: 0., stop: f64::INFINITY }));
+        assert!(matches!(closest_approach(&sgp4, &sgp4, 0., 1e12, 1.), Err(PropagationError::TooManySamples { .. })));
+    }
+}
@@ -0,0 +1,1288 @@
+// Module for coordinate frame transformations
+
+// ------------------
+// External Libraries
+// ------------------
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+// ------------------
+// Internal Libraries
+// ------------------
+use crate::common::{CoordinateFrame, StateVector, Wgs, WGS72};
+use crate::math::{normalize_deg_0_360, normalize_deg_pm_180, Deg, Rad, Vector3};
+use crate::sgp4::PropagationError;
+#[cfg(feature = "std")]
+use crate::sgp4::{calc_theta_g, propagate_range, Sgp4};
+#[cfg(feature = "chrono")]
+use crate::sgp4::propagate_at;
+#[cfg(feature = "chrono")]
+use crate::time::{utc2jday, DateTime};
+
+// -------
+// Structs
+// -------
+
+/// A sample of a timestamped ground track, as (time, latitude \[deg\], longitude \[deg\], altitude \[km\])
+#[cfg(feature = "chrono")]
+pub type TimedTrackPoint = (chrono::DateTime<chrono::Utc>, f64, f64, f64);
+
+// ---------
+// Constants
+// ---------
+
+/// Earth's nominal rotation rate about its polar axis \[rad/s\]
+///
+/// This is the rate of [`WGS72`] converted to seconds. The frame transformations take the rate from the WGS model
+/// they are given (`Wgs::omega_earth`).
+pub const EARTH_ROTATION_RATE: f64 = WGS72.omega_earth / 60.;
+
+/// The speed of light in vacuum \[km/s\]
+pub const SPEED_OF_LIGHT: f64 = 299792.458;
+
+/// A conversion from arcseconds to radians
+const ARCSEC2RAD: f64 = core::f64::consts::PI / 648000.;
+
+/// The IAU 1980 nutation series
+///
+/// Each term holds the multipliers of the Delaunay arguments (l, l', F, D, Ω) followed by the longitude coefficients
+/// (A, B) and the obliquity coefficients (C, D) in units of 0.0001 arcseconds, where Δψ = Σ (A + B T) sin(arg) and
+/// Δε = Σ (C + D T) cos(arg) for T in Julian centuries of TT since J2000.0.
+///
+/// References:
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+/// - [Explanatory Supplement to the Astronomical Almanac by Seidelmann](https://aa.usno.navy.mil/publications/exp_supp)
+#[rustfmt::skip]
+const NUTATION_1980: [([i8; 5], f64, f64, f64, f64); 106] = [
+    ([ 0,  0,  0,  0,  1], -171996., -174.2, 92025.,  8.9),
+    ([ 0,  0,  2, -2,  2],  -13187.,   -1.6,  5736., -3.1),
+    ([ 0,  0,  2,  0,  2],   -2274.,   -0.2,   977., -0.5),
+    ([ 0,  0,  0,  0,  2],    2062.,    0.2,  -895.,  0.5),
+    ([ 0,  1,  0,  0,  0],    1426.,   -3.4,    54., -0.1),
+    ([ 1,  0,  0,  0,  0],     712.,    0.1,    -7.,   0.),
+    ([ 0,  1,  2, -2,  2],    -517.,    1.2,   224., -0.6),
+    ([ 0,  0,  2,  0,  1],    -386.,   -0.4,   200.,   0.),
+    ([ 1,  0,  2,  0,  2],    -301.,     0.,   129., -0.1),
+    ([ 0, -1,  2, -2,  2],     217.,   -0.5,   -95.,  0.3),
+    ([ 1,  0,  0, -2,  0],    -158.,     0.,    -1.,   0.),
+    ([ 0,  0,  2, -2,  1],     129.,    0.1,   -70.,   0.),
+    ([-1,  0,  2,  0,  2],     123.,     0.,   -53.,   0.),
+    ([ 1,  0,  0,  0,  1],      63.,    0.1,   -33.,   0.),
+    ([ 0,  0,  0,  2,  0],      63.,     0.,    -2.,   0.),
+    ([-1,  0,  2,  2,  2],     -59.,     0.,    26.,   0.),
+    ([-1,  0,  0,  0,  1],     -58.,   -0.1,    32.,   0.),
+    ([ 1,  0,  2,  0,  1],     -51.,     0.,    27.,   0.),
+    ([ 2,  0,  0, -2,  0],      48.,     0.,     1.,   0.),
+    ([-2,  0,  2,  0,  1],      46.,     0.,   -24.,   0.),
+    ([ 0,  0,  2,  2,  2],     -38.,     0.,    16.,   0.),
+    ([ 2,  0,  2,  0,  2],     -31.,     0.,    13.,   0.),
+    ([ 2,  0,  0,  0,  0],      29.,     0.,    -1.,   0.),
+    ([ 1,  0,  2, -2,  2],      29.,     0.,   -12.,   0.),
+    ([ 0,  0,  2,  0,  0],      26.,     0.,    -1.,   0.),
+    ([ 0,  0,  2, -2,  0],     -22.,     0.,     0.,   0.),
+    ([-1,  0,  2,  0,  1],      21.,     0.,   -10.,   0.),
+    ([ 0,  2,  0,  0,  0],      17.,   -0.1,     0.,   0.),
+    ([ 0,  2,  2, -2,  2],     -16.,    0.1,     7.,   0.),
+    ([-1,  0,  0,  2,  1],      16.,     0.,    -8.,   0.),
+    ([ 0,  1,  0,  0,  1],     -15.,     0.,     9.,   0.),
+    ([ 1,  0,  0, -2,  1],     -13.,     0.,     7.,   0.),
+    ([ 0, -1,  0,  0,  1],     -12.,     0.,     6.,   0.),
+    ([ 2,  0, -2,  0,  0],      11.,     0.,     0.,   0.),
+    ([-1,  0,  2,  2,  1],     -10.,     0.,     5.,   0.),
+    ([ 1,  0,  2,  2,  2],      -8.,     0.,     3.,   0.),
+    ([ 0, -1,  2,  0,  2],      -7.,     0.,     3.,   0.),
+    ([ 0,  0,  2,  2,  1],      -7.,     0.,     3.,   0.),
+    ([ 1,  1,  0, -2,  0],      -7.,     0.,     0.,   0.),
+    ([ 0,  1,  2,  0,  2],       7.,     0.,    -3.,   0.),
+    ([-2,  0,  0,  2,  1],      -6.,     0.,     3.,   0.),
+    ([ 0,  0,  0,  2,  1],      -6.,     0.,     3.,   0.),
+    ([ 2,  0,  2, -2,  2],       6.,     0.,    -3.,   0.),
+    ([ 1,  0,  0,  2,  0],       6.,     0.,     0.,   0.),
+    ([ 1,  0,  2, -2,  1],       6.,     0.,    -3.,   0.),
+    ([ 0,  0,  0, -2,  1],      -5.,     0.,     3.,   0.),
+    ([ 0, -1,  2, -2,  1],      -5.,     0.,     3.,   0.),
+    ([ 2,  0,  2,  0,  1],      -5.,     0.,     3.,   0.),
+    ([ 1, -1,  0,  0,  0],       5.,     0.,     0.,   0.),
+    ([ 1,  0,  0, -1,  0],      -4.,     0.,     0.,   0.),
+    ([ 0,  0,  0,  1,  0],      -4.,     0.,     0.,   0.),
+    ([ 0,  1,  0, -2,  0],      -4.,     0.,     0.,   0.),
+    ([ 1,  0, -2,  0,  0],       4.,     0.,     0.,   0.),
+    ([ 2,  0,  0, -2,  1],       4.,     0.,    -2.,   0.),
+    ([ 0,  1,  2, -2,  1],       4.,     0.,    -2.,   0.),
+    ([ 1,  1,  0,  0,  0],      -3.,     0.,     0.,   0.),
+    ([ 1, -1,  0, -1,  0],      -3.,     0.,     0.,   0.),
+    ([-1, -1,  2,  2,  2],      -3.,     0.,     1.,   0.),
+    ([ 0, -1,  2,  2,  2],      -3.,     0.,     1.,   0.),
+    ([ 1, -1,  2,  0,  2],      -3.,     0.,     1.,   0.),
+    ([ 3,  0,  2,  0,  2],      -3.,     0.,     1.,   0.),
+    ([-2,  0,  2,  0,  2],      -3.,     0.,     1.,   0.),
+    ([ 1,  0,  2,  0,  0],       3.,     0.,     0.,   0.),
+    ([-1,  0,  2,  4,  2],      -2.,     0.,     1.,   0.),
+    ([ 1,  0,  0,  0,  2],      -2.,     0.,     1.,   0.),
+    ([-1,  0,  2, -2,  1],      -2.,     0.,     1.,   0.),
+    ([ 0, -2,  2, -2,  1],      -2.,     0.,     1.,   0.),
+    ([-2,  0,  0,  0,  1],      -2.,     0.,     1.,   0.),
+    ([ 2,  0,  0,  0,  1],       2.,     0.,    -1.,   0.),
+    ([ 3,  0,  0,  0,  0],       2.,     0.,     0.,   0.),
+    ([ 1,  1,  2,  0,  2],       2.,     0.,    -1.,   0.),
+    ([ 0,  0,  2,  1,  2],       2.,     0.,    -1.,   0.),
+    ([ 1,  0,  0,  2,  1],      -1.,     0.,     0.,   0.),
+    ([ 1,  0,  2,  2,  1],      -1.,     0.,     1.,   0.),
+    ([ 1,  1,  0, -2,  1],      -1.,     0.,     0.,   0.),
+    ([ 0,  1,  0,  2,  0],      -1.,     0.,     0.,   0.),
+    ([ 0,  1,  2, -2,  0],      -1.,     0.,     0.,   0.),
+    ([ 0,  1, -2,  2,  0],      -1.,     0.,     0.,   0.),
+    ([ 1,  0, -2,  2,  0],      -1.,     0.,     0.,   0.),
+    ([ 1,  0, -2, -2,  0],      -1.,     0.,     0.,   0.),
+    ([ 1,  0,  2, -2,  0],      -1.,     0.,     0.,   0.),
+    ([ 1,  0,  0, -4,  0],      -1.,     0.,     0.,   0.),
+    ([ 2,  0,  0, -4,  0],      -1.,     0.,     0.,   0.),
+    ([ 0,  0,  2,  4,  2],      -1.,     0.,     0.,   0.),
+    ([ 0,  0,  2, -1,  2],      -1.,     0.,     0.,   0.),
+    ([-2,  0,  2,  4,  2],      -1.,     0.,     1.,   0.),
+    ([ 2,  0,  2,  2,  2],      -1.,     0.,     0.,   0.),
+    ([ 0, -1,  2,  0,  1],      -1.,     0.,     0.,   0.),
+    ([ 0,  0, -2,  0,  1],      -1.,     0.,     0.,   0.),
+    ([ 0,  0,  4, -2,  2],       1.,     0.,     0.,   0.),
+    ([ 0,  1,  0,  0,  2],       1.,     0.,     0.,   0.),
+    ([ 1,  1,  2, -2,  2],       1.,     0.,    -1.,   0.),
+    ([ 3,  0,  2, -2,  2],       1.,     0.,     0.,   0.),
+    ([-2,  0,  2,  2,  2],       1.,     0.,    -1.,   0.),
+    ([-1,  0,  0,  0,  2],       1.,     0.,    -1.,   0.),
+    ([ 0,  0, -2,  2,  1],       1.,     0.,     0.,   0.),
+    ([ 0,  1,  2,  0,  1],       1.,     0.,     0.,   0.),
+    ([-1,  0,  4,  0,  2],       1.,     0.,     0.,   0.),
+    ([ 2,  1,  0, -2,  0],       1.,     0.,     0.,   0.),
+    ([ 2,  0,  0,  2,  0],       1.,     0.,     0.,   0.),
+    ([ 2,  0,  2, -2,  1],       1.,     0.,    -1.,   0.),
+    ([ 2,  0, -2,  0,  1],       1.,     0.,     0.,   0.),
+    ([ 1, -1,  0, -2,  0],       1.,     0.,     0.,   0.),
+    ([-1,  0,  0,  1,  1],       1.,     0.,     0.,   0.),
+    ([-1, -1,  0,  2,  1],       1.,     0.,     0.,   0.),
+    ([ 0,  1,  0,  1,  0],       1.,     0.,     0.,   0.),
+];
+
+// ---------
+// Functions
+// ---------
+
+/// Convert a TEME position to geodetic latitude, longitude and altitude
+///
+/// The position is first rotated about the Z axis by the Greenwich mean sidereal time into the Earth-fixed frame, then
+/// the geodetic latitude is found iteratively (Bowring's method) on the WGS reference ellipsoid.
+///
+/// # Arguments
+/// * `pos` - The position in TEME coordinates \[km\]
+/// * `gmst` - The Greenwich mean sidereal time \[rad\]
+/// * `wgs` - The WGS model defining the reference ellipsoid
+///
+/// # Returns
+/// * `(lat, lon, alt)` - The geodetic latitude \[deg\], longitude in (-180, 180\] \[deg\] and altitude above the ellipsoid \[km\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::WGS84;
+/// use Rusty_SGP4::math::Vector3;
+/// use Rusty_SGP4::coordinates::teme_to_geodetic;
+///
+/// // A point 400 km above the equator on the Greenwich meridian
+/// let pos = Vector3::new(WGS84.r_earth_eq + 400., 0., 0.);
+///
+/// // Convert to geodetic coordinates
+/// let (lat, lon, alt) = teme_to_geodetic(pos, 0., &WGS84);
+/// assert!(lat.abs() < 1e-9 && lon.abs() < 1e-9);
+/// assert!((alt - 400.).abs() < 1e-9);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn teme_to_geodetic(pos: Vector3, gmst: f64, wgs: &Wgs) -> (f64, f64, f64) {
+    // Rotate the position into the Earth-fixed frame
+    let Vector3 { x, y, z } = rotate_z(pos, -gmst); // [km]
+
+    // Calculate the eccentricity squared of the ellipsoid
+    let a = wgs.r_earth_eq; // [km]
+    let e2 = wgs.flattening * (2. - wgs.flattening);
+
+    // Calculate the longitude and the distance from the polar axis
+    let lon = y.atan2(x); // [rad]
+    let p = (x.powi(2) + y.powi(2)).sqrt(); // [km]
+
+    // Iterate on the geodetic latitude starting from the geocentric estimate
+    let mut lat = z.atan2(p * (1. - e2)); // [rad]
+    for _ in 0..10 {
+        // Calculate the radius of curvature in the prime vertical and the altitude at the current latitude
+        let (n, alt) = calc_curvature_altitude(lat, p, z, a, e2);
+
+        // Update the latitude and stop once converged
+        let lat_next = z.atan2(p * (1. - e2 * n / (n + alt)));
+        let delta = (lat_next - lat).abs();
+        lat = lat_next;
+        if delta < 1e-12 {
+            break;
+        }
+    }
+
+    // Calculate the altitude at the converged latitude
+    let (_n, alt) = calc_curvature_altitude(lat, p, z, a, e2); // [km]
+
+    // Return latitude and longitude in degrees and altitude in km
+    return (lat.to_degrees(), normalize_deg_pm_180(lon.to_degrees()), alt);
+}
+
+/// Rotate a TEME state vector into the Earth-centered, Earth-fixed (ECEF) frame
+///
+/// The position and velocity are rotated about the Z axis by `-gmst`, and the velocity additionally has the Earth's
+/// rotation (ω × r) removed so it is relative to the rotating Earth, with ω the rotation rate of the WGS model. Polar
+/// motion is neglected. The result keeps the time of the state and is tagged with `CoordinateFrame::ECEF`, so the
+/// frame is tracked through a chain of transformations.
+///
+/// # Arguments
+/// * `state` - The state vector in TEME coordinates
+/// * `gmst` - The Greenwich mean sidereal time \[rad\]
+/// * `wgs` - The WGS model defining the Earth's rotation rate
+///
+/// # Returns
+/// * `Result<StateVector, PropagationError>` - The position \[km\] and velocity \[km/s\] in ECEF coordinates
+///
+/// # Errors
+/// Returns `PropagationError::CoordinateFrameMismatch` if the state is not in TEME coordinates, such as a state that
+/// has already been rotated.
+///
+/// # Examples
+/// ```rust
+/// use core::f64::consts::FRAC_PI_2;
+/// use Rusty_SGP4::common::{CoordinateFrame, StateVector, WGS72};
+/// use Rusty_SGP4::math::Vector3;
+/// use Rusty_SGP4::coordinates::teme_to_ecef;
+///
+/// // A point on the TEME y axis lies on the Greenwich meridian when GMST is 90 degrees
+/// let state = StateVector {
+///     time_min: 0.,
+///     position: Vector3::new(0., 7000., 0.),
+///     velocity: Vector3::new(-7.5, 0., 0.),
+///     coordinate_frame: CoordinateFrame::TEME,
+/// };
+/// let state_ecef = teme_to_ecef(&state, FRAC_PI_2, &WGS72)?;
+/// assert!((state_ecef.position - Vector3::new(7000., 0., 0.)).magnitude() < 1e-9);
+/// assert_eq!(state_ecef.coordinate_frame, CoordinateFrame::ECEF);
+/// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn teme_to_ecef(state: &StateVector, gmst: f64, wgs: &Wgs) -> Result<StateVector, PropagationError> {
+    if state.coordinate_frame != CoordinateFrame::TEME {
+        return Err(PropagationError::CoordinateFrameMismatch { expected: CoordinateFrame::TEME, actual: state.coordinate_frame });
+    }
+
+    // Rotate the position into the Earth-fixed frame
+    let pos_ecef = rotate_z(state.position, -gmst); // [km]
+
+    // Rotate the velocity and remove the Earth's rotation
+    let omega_earth = Vector3::new(0., 0., wgs.omega_earth / 60.); // [rad/s]
+    let vel_ecef = rotate_z(state.velocity, -gmst) - omega_earth.cross(&pos_ecef); // [km/s]
+
+    let state_ecef = StateVector {
+        time_min: state.time_min,
+        position: pos_ecef,
+        velocity: vel_ecef,
+        coordinate_frame: CoordinateFrame::ECEF,
+    };
+
+    return Ok(state_ecef);
+}
+
+/// Convert a geodetic latitude, longitude and altitude to an Earth-centered, Earth-fixed (ECEF) position
+///
+/// This is the inverse of `teme_to_geodetic` with a zero GMST, and is the natural way to define a ground station for
+/// `look_angles`. The position is found from the prime vertical radius of curvature N = a / sqrt(1 - e² sin²φ).
+///
+/// # Arguments
+/// * `lat` - The geodetic latitude
+/// * `lon` - The longitude
+/// * `alt` - The altitude above the ellipsoid \[km\]
+/// * `wgs` - The WGS model defining the reference ellipsoid
+///
+/// # Returns
+/// * `Vector3` - The position in ECEF coordinates \[km\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::WGS84;
+/// use Rusty_SGP4::coordinates::geodetic_to_ecef;
+/// use Rusty_SGP4::math::Deg;
+///
+/// // A point on the equator at the Greenwich meridian lies on the X axis
+/// let pos = geodetic_to_ecef(Deg(0.), Deg(0.), 0., &WGS84);
+/// assert!((pos.x - WGS84.r_earth_eq).abs() < 1e-9);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn geodetic_to_ecef(lat: Deg, lon: Deg, alt: f64, wgs: &Wgs) -> Vector3 {
+    // Calculate the eccentricity squared of the ellipsoid
+    let e2 = wgs.flattening * (2. - wgs.flattening);
+
+    // Calculate the radius of curvature in the prime vertical
+    let (sin_lat, cos_lat) = Rad::from(lat).0.sin_cos();
+    let (sin_lon, cos_lon) = Rad::from(lon).0.sin_cos();
+    let n = wgs.r_earth_eq / (1. - e2 * sin_lat.powi(2)).sqrt(); // [km]
+
+    // Calculate the ECEF position
+    return Vector3::new((n + alt) * cos_lat * cos_lon, (n + alt) * cos_lat * sin_lon, (n * (1. - e2) + alt) * sin_lat);
+}
+
+/// Convert a ground observer's geodetic position to an Earth-centered inertial (ECI) position
+///
+/// The geodetic position is converted to ECEF with [`geodetic_to_ecef`] and rotated about the Z axis by the Greenwich
+/// mean sidereal time, the inverse of the rotation in [`teme_to_ecef`], so the result is in the TEME frame of SGP4.
+///
+/// # Arguments
+/// * `lat` - The geodetic latitude
+/// * `lon` - The longitude
+/// * `alt` - The altitude above the ellipsoid \[km\]
+/// * `gmst` - The Greenwich mean sidereal time \[rad\]
+/// * `wgs` - The WGS model defining the reference ellipsoid
+///
+/// # Returns
+/// * `Vector3` - The observer position in ECI (TEME) coordinates \[km\]
+///
+/// # Examples
+/// ```rust
+/// use core::f64::consts::FRAC_PI_2;
+/// use Rusty_SGP4::common::WGS84;
+/// use Rusty_SGP4::coordinates::observer_eci;
+/// use Rusty_SGP4::math::Deg;
+///
+/// // An observer on the Greenwich meridian lies on the Y axis when GMST is 90 degrees
+/// let pos = observer_eci(Deg(0.), Deg(0.), 0., FRAC_PI_2, &WGS84);
+/// assert!((pos.y - WGS84.r_earth_eq).abs() < 1e-9);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn observer_eci(lat: Deg, lon: Deg, alt: f64, gmst: f64, wgs: &Wgs) -> Vector3 {
+    return rotate_z(geodetic_to_ecef(lat, lon, alt, wgs), gmst);
+}
+
+/// Calculate the line of sight from an observer to a satellite in Earth-centered inertial (ECI) coordinates
+///
+/// # Arguments
+/// * `sat_eci` - The satellite position in ECI coordinates \[km\]
+/// * `observer_eci` - The observer position in the same frame, e.g. from [`observer_eci`] \[km\]
+///
+/// # Returns
+/// * `Vector3` - The slant vector from the observer to the satellite, whose magnitude is the slant range \[km\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::math::Vector3;
+/// use Rusty_SGP4::coordinates::slant_vector_eci;
+///
+/// // The slant range to a satellite 400 km above an observer
+/// let rho = slant_vector_eci(Vector3::new(6778., 0., 0.), Vector3::new(6378., 0., 0.));
+/// assert!((rho.magnitude() - 400.).abs() < 1e-9);
+/// ```
+pub fn slant_vector_eci(sat_eci: Vector3, observer_eci: Vector3) -> Vector3 {
+    return sat_eci - observer_eci;
+}
+
+/// Rotate the line of sight from a ground observer to a satellite into the topocentric south-east-zenith (SEZ) frame
+///
+/// The observer's geodetic position is converted to ECEF and the line of sight is rotated by the observer's longitude
+/// and latitude, so the X axis points south, the Y axis east and the Z axis along the ellipsoid normal. Differencing
+/// the SEZ vectors of two nearby times gives the range rate for Doppler calculations.
+///
+/// # Arguments
+/// * `sat_ecef` - The satellite position in ECEF coordinates \[km\]
+/// * `observer_lat` - The observer geodetic latitude
+/// * `observer_lon` - The observer longitude
+/// * `observer_alt` - The observer altitude above the ellipsoid \[km\]
+/// * `wgs` - The WGS model defining the reference ellipsoid
+///
+/// # Returns
+/// * `Vector3` - The line of sight in SEZ coordinates \[km\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::WGS84;
+/// use Rusty_SGP4::coordinates::{ecef_to_sez, geodetic_to_ecef};
+/// use Rusty_SGP4::math::Deg;
+///
+/// // A satellite 400 km directly above the observer is along the Z axis
+/// let sat_ecef = geodetic_to_ecef(Deg(40.), Deg(-105.), 400., &WGS84);
+/// let sez = ecef_to_sez(sat_ecef, Deg(40.), Deg(-105.), 0., &WGS84);
+/// assert!((sez.z - 400.).abs() < 1e-9);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn ecef_to_sez(sat_ecef: Vector3, observer_lat: Deg, observer_lon: Deg, observer_alt: f64, wgs: &Wgs) -> Vector3 {
+    // Calculate the line of sight from the observer to the satellite
+    let observer_ecef = geodetic_to_ecef(observer_lat, observer_lon, observer_alt, wgs); // [km]
+    let rho = sat_ecef - observer_ecef; // [km]
+
+    // Rotate the line of sight into the SEZ frame
+    let (sin_lat, cos_lat) = Rad::from(observer_lat).0.sin_cos();
+    let (sin_lon, cos_lon) = Rad::from(observer_lon).0.sin_cos();
+    let rho_s = sin_lat * cos_lon * rho.x + sin_lat * sin_lon * rho.y - cos_lat * rho.z; // [km]
+    let rho_e = -sin_lon * rho.x + cos_lon * rho.y; // [km]
+    let rho_z = cos_lat * cos_lon * rho.x + cos_lat * sin_lon * rho.y + sin_lat * rho.z; // [km]
+
+    return Vector3::new(rho_s, rho_e, rho_z);
+}
+
+/// Calculate the look angles from a ground observer to a satellite
+///
+/// The line of sight is rotated into the topocentric south-east-zenith (SEZ) frame with [`ecef_to_sez`], from which the
+/// azimuth, elevation and range follow.
+///
+/// # Arguments
+/// * `sat_ecef` - The satellite position in ECEF coordinates \[km\]
+/// * `observer_lat` - The observer geodetic latitude
+/// * `observer_lon` - The observer longitude
+/// * `observer_alt` - The observer altitude above the ellipsoid \[km\]
+/// * `wgs` - The WGS model defining the reference ellipsoid
+///
+/// # Returns
+/// * `(az, el, range)` - The azimuth clockwise from north in \[0, 360) degrees, elevation in \[-90, 90\] degrees and
+///   slant range \[km\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::WGS84;
+/// use Rusty_SGP4::coordinates::{geodetic_to_ecef, look_angles};
+/// use Rusty_SGP4::math::Deg;
+///
+/// // A satellite 400 km directly above the observer is at the zenith
+/// let sat_ecef = geodetic_to_ecef(Deg(40.), Deg(-105.), 400., &WGS84);
+/// let (_az, el, range) = look_angles(sat_ecef, Deg(40.), Deg(-105.), 0., &WGS84);
+/// assert!((el.0 - 90.).abs() < 1e-6);
+/// assert!((range - 400.).abs() < 1e-6);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn look_angles(sat_ecef: Vector3, observer_lat: Deg, observer_lon: Deg, observer_alt: f64, wgs: &Wgs) -> (Deg, Deg, f64) {
+    // Rotate the line of sight into the SEZ frame
+    let rho = ecef_to_sez(sat_ecef, observer_lat, observer_lon, observer_alt, wgs); // [km]
+
+    // Calculate the slant range and elevation
+    let range = rho.magnitude(); // [km]
+    let el = (rho.z / range).clamp(-1., 1.).asin(); // [rad]
+
+    // Calculate the azimuth clockwise from north, wrapped to [0, 360)
+    let az = normalize_deg_0_360(rho.y.atan2(-rho.x).to_degrees()); // [deg]
+
+    return (Deg(az), Deg::from(Rad(el)), range);
+}
+
+/// Calculate the frequency received by a ground observer from a satellite transmitter
+///
+/// The range rate is the projection of the satellite's Earth-fixed velocity onto the line of sight, since the observer
+/// is fixed to the Earth. The received frequency is shifted by the first order Doppler factor f (1 - ṙ / c), so an
+/// approaching satellite (negative range rate) is heard above its transmit frequency. Relativistic terms are below a
+/// part per billion for Earth orbits and are neglected.
+///
+/// # Arguments
+/// * `sat_ecef` - The satellite position in ECEF coordinates \[km\]
+/// * `sat_vel_ecef` - The satellite velocity in ECEF coordinates, relative to the rotating Earth \[km/s\]
+/// * `observer_ecef` - The observer position in ECEF coordinates \[km\]
+/// * `tx_freq_hz` - The transmit frequency \[Hz\]
+///
+/// # Returns
+/// * `f64` - The received frequency \[Hz\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::math::Vector3;
+/// use Rusty_SGP4::coordinates::doppler_shift;
+///
+/// // A satellite closing at 7 km/s raises a 437 MHz downlink by about 10 kHz
+/// let observer = Vector3::new(6378., 0., 0.);
+/// let sat = Vector3::new(7378., 0., 0.);
+/// let rx = doppler_shift(sat, Vector3::new(-7., 0., 0.), observer, 437e6);
+/// assert!((rx - 437e6 - 10204.).abs() < 1.);
+/// ```
+pub fn doppler_shift(sat_ecef: Vector3, sat_vel_ecef: Vector3, observer_ecef: Vector3, tx_freq_hz: f64) -> f64 {
+    // Project the velocity onto the line of sight
+    let rho = sat_ecef - observer_ecef; // [km]
+    let range_rate = sat_vel_ecef.dot(&rho) / rho.magnitude(); // [km/s]
+
+    return tx_freq_hz * (1. - range_rate / SPEED_OF_LIGHT);
+}
+
+/// Calculate the topocentric right ascension and declination of a satellite
+///
+/// The line of sight from the observer to the satellite, in an inertial frame with the Z axis along the celestial pole
+/// and the X axis towards the equinox (such as TEME or J2000), is converted to spherical angles. The result is in the
+/// equator and equinox of the input frame.
+///
+/// # Arguments
+/// * `topocentric_pos` - The line of sight from the observer to the satellite in inertial coordinates \[km\]
+///
+/// # Returns
+/// * `(ra, dec)` - The right ascension in \[0, 24) \[hours\] and declination in \[-90, 90\] \[deg\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::coordinates::eci_to_radec;
+/// use Rusty_SGP4::math::Vector3;
+///
+/// // A satellite along the celestial equator, a quarter turn east of the equinox
+/// let (ra, dec) = eci_to_radec(Vector3::new(0., 1000., 0.));
+/// assert!((ra - 6.).abs() < 1e-12);
+/// assert!(dec.abs() < 1e-12);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn eci_to_radec(topocentric_pos: Vector3) -> (f64, f64) {
+    // Calculate the declination from the polar component
+    let range = topocentric_pos.magnitude(); // [km]
+    let dec = (topocentric_pos.z / range).clamp(-1., 1.).asin(); // [rad]
+
+    // Calculate the right ascension east of the equinox, wrapped to [0, 24)
+    let ra = normalize_deg_0_360(topocentric_pos.y.atan2(topocentric_pos.x).to_degrees()) / 15.; // [hours]
+
+    return (ra, dec.to_degrees());
+}
+
+/// Rotate a TEME state vector into the J2000 inertial frame
+///
+/// The position and velocity are rotated from TEME to the true equator and equinox of date by the equation of the equinoxes, then to
+/// the mean equator and equinox of date by the IAU 1980 nutation, then to J2000 by the IAU 1976 precession. This is the
+/// IAU-76/FK5 reduction used with the SGP4 reference implementation. Without the observed nutation corrections (δΔψ,
+/// δΔε) J2000 agrees with GCRF to within a few tens of milliarcseconds. The result keeps the time of the state and is
+/// tagged with `CoordinateFrame::J2000`.
+///
+/// # Arguments
+/// * `state` - The state vector in TEME coordinates
+/// * `jd_tt` - The Julian date in terrestrial time (TT) \[days\]
+///
+/// # Returns
+/// * `Result<StateVector, PropagationError>` - The position \[km\] and velocity \[km/s\] in J2000 coordinates
+///
+/// # Errors
+/// Returns `PropagationError::CoordinateFrameMismatch` if the state is not in TEME coordinates.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::{CoordinateFrame, StateVector};
+/// use Rusty_SGP4::math::Vector3;
+/// use Rusty_SGP4::coordinates::teme_to_j2000;
+///
+/// // Example 3-15 from Vallado, 2004-04-06 07:52:32.570009 TT
+/// let state = StateVector {
+///     time_min: 0.,
+///     position: Vector3::new(5094.18016210, 6127.64465950, 6380.34453270),
+///     velocity: Vector3::new(-4.746131487, 0.785818041, 5.531931288),
+///     coordinate_frame: CoordinateFrame::TEME,
+/// };
+/// let state_j2000 = teme_to_j2000(&state, 2453101.828154745)?;
+/// assert!((state_j2000.position - Vector3::new(5102.5096, 6123.01152, 6378.1363)).magnitude() < 1e-4);
+/// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+/// ```
+///
+/// # References
+/// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn teme_to_j2000(state: &StateVector, jd_tt: f64) -> Result<StateVector, PropagationError> {
+    if state.coordinate_frame != CoordinateFrame::TEME {
+        return Err(PropagationError::CoordinateFrameMismatch { expected: CoordinateFrame::TEME, actual: state.coordinate_frame });
+    }
+
+    // Calculate the Julian centuries of TT since J2000.0
+    let ttt = (jd_tt - 2451545.0) / 36525.0; // [centuries]
+
+    // Calculate the nutation in longitude and obliquity
+    let (delta_psi, delta_eps, mean_eps) = calc_nutation_1980(ttt); // [rad]
+    let true_eps = mean_eps + delta_eps; // [rad]
+
+    // TEME to true of date, rotating by the equation of the equinoxes
+    let eqe = delta_psi * mean_eps.cos(); // [rad]
+    let pos_tod = rotate_z(state.position, eqe); // [km]
+    let vel_tod = rotate_z(state.velocity, eqe); // [km/s]
+
+    // True of date to mean of date
+    let (sin_psi, cos_psi) = delta_psi.sin_cos();
+    let (sin_eps, cos_eps) = mean_eps.sin_cos();
+    let (sin_true_eps, cos_true_eps) = true_eps.sin_cos();
+    let nutation = [
+        [cos_psi, cos_true_eps * sin_psi, sin_true_eps * sin_psi],
+        [-cos_eps * sin_psi, cos_true_eps * cos_eps * cos_psi + sin_true_eps * sin_eps, sin_true_eps * cos_eps * cos_psi - sin_eps * cos_true_eps],
+        [-sin_eps * sin_psi, cos_true_eps * sin_eps * cos_psi - sin_true_eps * cos_eps, sin_true_eps * sin_eps * cos_psi + cos_true_eps * cos_eps],
+    ];
+    let pos_mod = mat_vec(&nutation, pos_tod); // [km]
+    let vel_mod = mat_vec(&nutation, vel_tod); // [km/s]
+
+    // Mean of date to J2000, with the IAU 1976 precession angles
+    let zeta = ((0.017998 * ttt + 0.30188) * ttt + 2306.2181) * ttt * ARCSEC2RAD; // [rad]
+    let theta = ((-0.041833 * ttt - 0.42665) * ttt + 2004.3109) * ttt * ARCSEC2RAD; // [rad]
+    let z = ((0.018203 * ttt + 1.09468) * ttt + 2306.2181) * ttt * ARCSEC2RAD; // [rad]
+    let (sin_zeta, cos_zeta) = zeta.sin_cos();
+    let (sin_theta, cos_theta) = theta.sin_cos();
+    let (sin_z, cos_z) = z.sin_cos();
+    let precession = [
+        [cos_zeta * cos_theta * cos_z - sin_zeta * sin_z, cos_zeta * cos_theta * sin_z + sin_zeta * cos_z, cos_zeta * sin_theta],
+        [-sin_zeta * cos_theta * cos_z - cos_zeta * sin_z, -sin_zeta * cos_theta * sin_z + cos_zeta * cos_z, -sin_zeta * sin_theta],
+        [-sin_theta * cos_z, -sin_theta * sin_z, cos_theta],
+    ];
+    let pos_j2000 = mat_vec(&precession, pos_mod); // [km]
+    let vel_j2000 = mat_vec(&precession, vel_mod); // [km/s]
+
+    let state_j2000 = StateVector {
+        time_min: state.time_min,
+        position: pos_j2000,
+        velocity: vel_j2000,
+        coordinate_frame: CoordinateFrame::J2000,
+    };
+
+    return Ok(state_j2000);
+}
+
+/// Generate the sub-satellite ground track of a satellite over a range of times since the TLE epoch
+///
+/// The states are sampled as in [`propagate_range`] and each position is converted to geodetic coordinates on the
+/// propagator's WGS ellipsoid. Longitudes are kept in (-180, 180\], so the track crossing the antimeridian shows up as a
+/// jump of more than 180 degrees between consecutive samples.
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `start_min` - The first time since the TLE epoch \[min\]
+/// * `stop_min` - The last time since the TLE epoch \[min\]
+/// * `step_min` - The time between samples \[min\]
+///
+/// # Returns
+/// * `Result<Vec<(f64, f64, f64)>, PropagationError>` - The (minutes since epoch, latitude \[deg\], longitude \[deg\]) triples
+///
+/// # Errors
+/// Returns the `PropagationError` of [`propagate_range`] if the time range or step is invalid (including a start or
+/// stop time that is not finite, or a range of more than 2^30 steps), or if the SGP4 theory fails at any sample.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::init_sgp4;
+/// use Rusty_SGP4::coordinates::ground_track;
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Generate the ground track over one orbit in one minute steps
+/// let sgp4 = init_sgp4(&tle, None);
+/// let track = ground_track(&sgp4, 0., 92., 1.).unwrap();
+/// assert_eq!(track.len(), 93);
+/// ```
+#[cfg(feature = "std")]
+pub fn ground_track(sgp4: &Sgp4, start_min: f64, stop_min: f64, step_min: f64) -> Result<Vec<(f64, f64, f64)>, PropagationError> {
+    // Propagate the states over the time range
+    let states = propagate_range(sgp4, start_min, stop_min, step_min)?;
+
+    // Convert each position to the sub-satellite point
+    let mut track = Vec::with_capacity(states.len());
+    for (minutes, state) in states {
+        let gmst = calc_theta_g(sgp4.jd0, sgp4.jdfrac0 + minutes / 1440.); // [rad]
+        let (lat, lon, _alt) = teme_to_geodetic(state.position, gmst, &sgp4.wgs);
+        track.push((minutes, lat, lon));
+    }
+
+    return Ok(track);
+}
+
+/// Generate the timestamped geodetic track of a satellite between two [`chrono::DateTime`] in UTC
+///
+/// Samples are taken every `step` from `start` up to and including `stop`. Each state is propagated with
+/// [`propagate_at`] and rotated out of TEME with the GMST of the sample's own absolute time, so the longitudes stay
+/// consistent with the timestamps however far the window is from the TLE epoch.
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `start` - The time of the first sample
+/// * `stop` - The latest time of the last sample
+/// * `step` - The time between samples
+///
+/// # Returns
+/// * `Result<Vec<TimedTrackPoint>, PropagationError>` - The (time, latitude \[deg\], longitude \[deg\], altitude \[km\])
+///   of each sample
+///
+/// # Errors
+/// Returns `PropagationError::InvalidStep` if the step is not positive, with the step in minutes.
+/// Returns `PropagationError::InvalidTimeRange` if the start time is after the stop time, in minutes since the epoch.
+/// Returns `PropagationError::InvalidEpoch` if the TLE epoch or a sample time cannot be converted to a Julian date.
+/// Returns the first `PropagationError` of [`propagate_at`] if the SGP4 theory fails at any sample.
+///
+/// # Examples
+/// ```rust
+/// use chrono::{Duration, TimeZone, Utc};
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::init_sgp4;
+/// use Rusty_SGP4::coordinates::ground_track_timed;
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Generate the track over the first hour of the next day in one minute steps
+/// let sgp4 = init_sgp4(&tle, None);
+/// let start = Utc.with_ymd_and_hms(2008, 9, 21, 0, 0, 0).unwrap();
+/// let track = ground_track_timed(&sgp4, start, start + Duration::hours(1), Duration::minutes(1))?;
+/// assert_eq!(track.len(), 61);
+/// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+/// ```
+#[cfg(feature = "chrono")]
+pub fn ground_track_timed(sgp4: &Sgp4, start: chrono::DateTime<chrono::Utc>, stop: chrono::DateTime<chrono::Utc>, step: chrono::Duration) -> Result<Vec<TimedTrackPoint>, PropagationError> {
+    // Validate the step and the time range
+    let to_minutes = |delta: chrono::Duration| delta.num_seconds() as f64 / 60. + delta.subsec_nanos() as f64 / 60e9; // [min]
+    if step <= chrono::Duration::zero() {
+        return Err(PropagationError::InvalidStep { step: to_minutes(step) });
+    }
+    if start > stop {
+        let epoch = sgp4.tle.epoch_chrono().map_err(|error| PropagationError::InvalidEpoch { error: error })?;
+        return Err(PropagationError::InvalidTimeRange { start: to_minutes(start - epoch), stop: to_minutes(stop - epoch) });
+    }
+
+    // Convert each sample to the sub-satellite point at its own absolute time
+    let mut track = Vec::new();
+    let mut when = start;
+    while when <= stop {
+        let state = propagate_at(sgp4, when)?;
+        let (jd, jdfrac) = utc2jday(&DateTime::from(when)).map_err(|error| PropagationError::InvalidEpoch { error: error })?;
+        let gmst = calc_theta_g(jd, jdfrac); // [rad]
+        let (lat, lon, alt) = teme_to_geodetic(state.position, gmst, &sgp4.wgs);
+        track.push((when, lat, lon, alt));
+        when = when + step;
+    }
+
+    return Ok(track);
+}
+
+/// Split a ground track into segments at each crossing of the antimeridian
+///
+/// A crossing is detected when the longitudes of consecutive samples differ by more than 180 degrees, which is where
+/// a flat map would otherwise draw a line across the whole map. Each segment can be drawn as a separate polyline.
+///
+/// # Arguments
+/// * `track` - The (minutes since epoch, latitude \[deg\], longitude \[deg\]) triples of [`ground_track`]
+///
+/// # Returns
+/// * `Vec<Vec<(f64, f64, f64)>>` - The segments of the track in order, which is empty for an empty track
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::coordinates::split_track_at_antimeridian;
+///
+/// // A track heading east across the antimeridian
+/// let track = [(0., 10., 170.), (1., 11., 178.), (2., 12., -174.), (3., 13., -166.)];
+/// let segments = split_track_at_antimeridian(&track);
+/// assert_eq!(segments, vec![vec![(0., 10., 170.), (1., 11., 178.)], vec![(2., 12., -174.), (3., 13., -166.)]]);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_track_at_antimeridian(track: &[(f64, f64, f64)]) -> Vec<Vec<(f64, f64, f64)>> {
+    let mut segments: Vec<Vec<(f64, f64, f64)>> = Vec::new();
+    let mut segment: Vec<(f64, f64, f64)> = Vec::new();
+    for &point in track {
+        // Start a new segment when the longitude jumps across the map
+        if segment.last().is_some_and(|&(_t, _lat, lon_prev)| (point.2 - lon_prev).abs() > 180.) {
+            segments.push(segment);
+            segment = Vec::new();
+        }
+        segment.push(point);
+    }
+    if !segment.is_empty() {
+        segments.push(segment);
+    }
+
+    return segments;
+}
+
+/// Rotate a vector about the Z axis by an angle
+///
+/// # Arguments
+/// * `vector` - The vector to rotate
+/// * `angle` - The rotation angle, positive counterclockwise looking down the Z axis \[rad\]
+///
+/// # Returns
+/// * `Vector3` - The rotated vector
+fn rotate_z(vector: Vector3, angle: f64) -> Vector3 {
+    let (sin_angle, cos_angle) = angle.sin_cos();
+    return Vector3::new(cos_angle * vector.x - sin_angle * vector.y, sin_angle * vector.x + cos_angle * vector.y, vector.z);
+}
+
+/// Multiply a vector by a 3x3 matrix
+///
+/// # Arguments
+/// * `matrix` - The matrix, stored by rows
+/// * `vector` - The vector
+///
+/// # Returns
+/// * `Vector3` - The product of the matrix and the vector
+fn mat_vec(matrix: &[[f64; 3]; 3], vector: Vector3) -> Vector3 {
+    let row = |r: &[f64; 3]| r[0] * vector.x + r[1] * vector.y + r[2] * vector.z;
+    return Vector3::new(row(&matrix[0]), row(&matrix[1]), row(&matrix[2]));
+}
+
+/// Calculate the IAU 1980 nutation in longitude and obliquity and the IAU 1976 mean obliquity of the ecliptic
+///
+/// # Arguments
+/// * `ttt` - The Julian centuries of TT since J2000.0 \[centuries\]
+///
+/// # Returns
+/// * `(delta_psi, delta_eps, mean_eps)` - The nutation in longitude \[rad\], nutation in obliquity \[rad\] and mean obliquity \[rad\]
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+fn calc_nutation_1980(ttt: f64) -> (f64, f64, f64) {
+    // Calculate the Delaunay arguments (l, l', F, D, Ω)
+    let delaunay = [
+        ((0.064 * ttt + 31.310) * ttt + 1717915922.6330) * ttt / 3600. + 134.96298139,
+        ((-0.012 * ttt - 0.577) * ttt + 129596581.2240) * ttt / 3600. + 357.52772333,
+        ((0.011 * ttt - 13.257) * ttt + 1739527263.1370) * ttt / 3600. + 93.27191028,
+        ((0.019 * ttt - 6.891) * ttt + 1602961601.3280) * ttt / 3600. + 297.85036306,
+        ((0.008 * ttt + 7.455) * ttt - 6962890.5390) * ttt / 3600. + 125.04452222,
+    ]
+    .map(|arg: f64| (arg % 360.).to_radians()); // [rad]
+
+    // Calculate the mean obliquity of the ecliptic
+    let mean_eps = (((0.001813 * ttt - 0.00059) * ttt - 46.8150) * ttt + 84381.448) * ARCSEC2RAD; // [rad]
+
+    // Sum the nutation series, smallest terms first
+    let mut delta_psi = 0.;
+    let mut delta_eps = 0.;
+    for (multipliers, a, b, c, d) in NUTATION_1980.iter().rev() {
+        let arg: f64 = multipliers.iter().zip(delaunay.iter()).map(|(&k, &angle)| k as f64 * angle).sum(); // [rad]
+        delta_psi = delta_psi + (a + b * ttt) * arg.sin();
+        delta_eps = delta_eps + (c + d * ttt) * arg.cos();
+    }
+
+    // Convert from 0.0001 arcseconds to radians
+    return (delta_psi * 1e-4 * ARCSEC2RAD, delta_eps * 1e-4 * ARCSEC2RAD, mean_eps);
+}
+
+/// Calculate the prime vertical radius of curvature/// Calculate the prime vertical radius of curvature and the altitude above the ellipsoid at a geodetic latitude
+///
+/// The altitude form used here stays well conditioned at all latitudes, including the poles.
+///
+/// # Arguments
+/// * `lat` - The geodetic latitude \[rad\]
+/// * `p` - The distance from the polar axis \[km\]
+/// * `z` - The distance along the polar axis \[km\]
+/// * `a` - The ellipsoid equatorial radius \[km\]
+/// * `e2` - The ellipsoid eccentricity squared \[\]
+///
+/// # Returns
+/// * `(n, alt)` - The prime vertical radius of curvature \[km\] and the altitude \[km\]
+fn calc_curvature_altitude(lat: f64, p: f64, z: f64, a: f64, e2: f64) -> (f64, f64) {
+    // Calculate the radius of curvature in the prime vertical
+    let (sin_lat, cos_lat) = lat.sin_cos();
+    let n = a / (1. - e2 * sin_lat.powi(2)).sqrt(); // [km]
+
+    // Calculate the altitude above the ellipsoid
+    let alt = p * cos_lat + (z + e2 * n * sin_lat) * sin_lat - n; // [km]
+
+    return (n, alt);
+}
+
+// ----------
+// Unit Tests
+// ----------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{WGS72, WGS84};
+    use crate::sgp4::{calc_theta_g, init_sgp4, propagate};
+    use crate::tle::from_lines;
+
+    #[test]
+    fn test_teme_to_geodetic_axes() {
+        // Equator, rotated by 90 degrees of sidereal time
+        let pos = Vector3::new(0., WGS84.r_earth_eq + 400., 0.);
+        let (lat, lon, alt) = teme_to_geodetic(pos, core::f64::consts::FRAC_PI_2, &WGS84);
+        assert!(lat.abs() < 1e-9);
+        assert!(lon.abs() < 1e-9);
+        assert!((alt - 400.).abs() < 1e-9);
+
+        // North pole
+        let r_polar = WGS84.r_earth_eq * (1. - WGS84.flattening);
+        let pos = Vector3::new(0., 0., r_polar + 100.);
+        let (lat, _lon, alt) = teme_to_geodetic(pos, 0., &WGS84);
+        assert!((lat - 90.).abs() < 1e-9);
+        assert!((alt - 100.).abs() < 1e-9);
+    }
+
+    /// A TEME state vector at 6 hours past the epoch
+    fn teme_state(position: Vector3, velocity: Vector3) -> StateVector {
+        return StateVector { time_min: 360., position: position, velocity: velocity, coordinate_frame: CoordinateFrame::TEME };
+    }
+
+    #[test]
+    fn test_teme_to_ecef() {
+        let pos = Vector3::new(-7154.03120202, -3783.17682504, -3536.19412294);
+        let vel = Vector3::new(4.741887409, -4.151817765, -2.093935425);
+        let state = teme_state(pos, vel);
+        let omega_earth = Vector3::new(0., 0., WGS72.omega_earth / 60.);
+
+        // A zero rotation leaves the position unchanged and only removes the Earth rotation from the velocity
+        let state_ecef = teme_to_ecef(&state, 0., &WGS72).unwrap();
+        assert_eq!(state_ecef.position, pos);
+        assert!((state_ecef.velocity - (vel - omega_earth.cross(&pos))).magnitude() < 1e-12);
+
+        // The rotation rate comes from the WGS model, so a different rate changes only the velocity
+        let wgs_fast = Wgs { omega_earth: 2. * WGS72.omega_earth, ..WGS72 };
+        let state_fast = teme_to_ecef(&state, 0., &wgs_fast).unwrap();
+        assert_eq!(state_fast.position, state_ecef.position);
+        assert!((state_fast.velocity - (state_ecef.velocity - omega_earth.cross(&pos))).magnitude() < 1e-12);
+
+        // A full rotation returns the input
+        let state_full = teme_to_ecef(&state, 2. * core::f64::consts::PI, &WGS72).unwrap();
+        assert!((state_full.position - state_ecef.position).magnitude() < 1e-9);
+        assert!((state_full.velocity - state_ecef.velocity).magnitude() < 1e-12);
+
+        // The rotation preserves the position magnitude
+        let state_rot = teme_to_ecef(&state, 1.234, &WGS72).unwrap();
+        assert!((state_rot.position.magnitude() - pos.magnitude()).abs() < 1e-9);
+        assert_eq!(state_rot.position.z, pos.z);
+    }
+
+    #[test]
+    fn test_state_frame_transforms() {
+        let state = teme_state(Vector3::new(-7154.03120202, -3783.17682504, -3536.19412294), Vector3::new(4.741887409, -4.151817765, -2.093935425));
+
+        // The rotation into the Earth-fixed frame flips the frame tag and keeps the time
+        let state_ecef = teme_to_ecef(&state, 1.234, &WGS72).unwrap();
+        assert_eq!(state_ecef.coordinate_frame, CoordinateFrame::ECEF);
+        assert_eq!(state_ecef.time_min, state.time_min);
+
+        // As does the rotation into J2000
+        let state_j2000 = teme_to_j2000(&state, 2453101.828154745).unwrap();
+        assert_eq!(state_j2000.coordinate_frame, CoordinateFrame::J2000);
+        assert_eq!(state_j2000.time_min, state.time_min);
+
+        // States that are not in TEME coordinates are rejected rather than rotated twice
+        let mismatch = |actual| Err(PropagationError::CoordinateFrameMismatch { expected: CoordinateFrame::TEME, actual: actual });
+        assert_eq!(teme_to_ecef(&state_ecef, 1.234, &WGS72), mismatch(CoordinateFrame::ECEF));
+        assert_eq!(teme_to_j2000(&state_j2000, 2453101.828154745), mismatch(CoordinateFrame::J2000));
+        assert_eq!(teme_to_ecef(&state_j2000, 1.234, &WGS72), mismatch(CoordinateFrame::J2000));
+    }
+
+    #[test]
+    fn test_geodetic_to_ecef() {
+        // Points on the reference ellipsoid convert back to the same geodetic coordinates
+        for (lat, lon, alt) in [(0., 0., 0.), (45., 90., 1.), (-33.9, 151.2, 0.05), (89.9, -120., 400.)] {
+            let pos = geodetic_to_ecef(Deg(lat), Deg(lon), alt, &WGS84);
+            let (lat_out, lon_out, alt_out) = teme_to_geodetic(pos, 0., &WGS84);
+            assert!((lat_out - lat).abs() < 1e-9);
+            assert!((lon_out - lon).abs() < 1e-9);
+            assert!((alt_out - alt).abs() < 1e-9);
+        }
+
+        // The poles round trip within 1 m, where the longitude is undefined
+        for (lat, alt) in [(90., 0.), (-90., 0.), (90., 500.), (-90., 35786.)] {
+            let pos = geodetic_to_ecef(Deg(lat), Deg(45.), alt, &WGS84);
+            let (lat_out, _lon_out, alt_out) = teme_to_geodetic(pos, 0., &WGS84);
+            assert!((lat_out - lat).abs() < 1e-9);
+            assert!((alt_out - alt).abs() < 1e-3);
+            assert!((geodetic_to_ecef(Deg(lat_out), Deg(45.), alt_out, &WGS84) - pos).magnitude() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_slant_vector_eci() {
+        for (lat, lon, gmst) in [(0., 0., 0.), (40.015, -105.27, 1.234), (-33.9, 151.2, 4.5), (89., 10., 2.)] {
+            // An observer directly beneath a satellite 400 km up
+            let observer = observer_eci(Deg(lat), Deg(lon), 0., gmst, &WGS84);
+            let sat = observer_eci(Deg(lat), Deg(lon), 400., gmst, &WGS84);
+            let rho = slant_vector_eci(sat, observer);
+            assert!((rho.magnitude() - 400.).abs() < 1e-9);
+
+            // The slant vector points along the observer's local vertical
+            let (sin_lat, cos_lat) = lat.to_radians().sin_cos();
+            let (sin_lon, cos_lon) = (lon.to_radians() + gmst).sin_cos();
+            let up = Vector3::new(cos_lat * cos_lon, cos_lat * sin_lon, sin_lat);
+            assert!((rho * (1. / 400.) - up).magnitude() < 1e-12);
+
+            // The observer rotates back onto its geodetic position
+            let (lat_out, lon_out, alt_out) = teme_to_geodetic(observer, gmst, &WGS84);
+            assert!((lat_out - lat).abs() < 1e-9 && (lon_out - lon).abs() < 1e-9 && alt_out.abs() < 1e-9);
+        }
+
+        // On the equator the vertical is the radial direction
+        let observer = observer_eci(Deg(0.), Deg(30.), 0., 0.5, &WGS84);
+        let rho = slant_vector_eci(observer_eci(Deg(0.), Deg(30.), 400., 0.5, &WGS84), observer);
+        assert!(rho.cross(&observer).magnitude() < 1e-6);
+    }
+
+    #[test]
+    fn test_ecef_to_sez() {
+        // A satellite at the zenith has only a positive Z component, at any latitude and longitude
+        for (lat, lon) in [(0., 0.), (40., -105.), (-33.9, 151.2), (89.9, 45.)] {
+            let sat_ecef = geodetic_to_ecef(Deg(lat), Deg(lon), 500., &WGS84);
+            let sez = ecef_to_sez(sat_ecef, Deg(lat), Deg(lon), 0.1, &WGS84);
+            assert!(sez.x.abs() < 1e-9 && sez.y.abs() < 1e-9, "SEZ: {:?}", sez);
+            assert!((sez.z - 499.9).abs() < 1e-9);
+        }
+
+        // On the equator at the Greenwich meridian, north is +Z in ECEF and east is +Y
+        let sez = ecef_to_sez(Vector3::new(WGS84.r_earth_eq, 0., 1000.), Deg(0.), Deg(0.), 0., &WGS84);
+        assert!((sez - Vector3::new(-1000., 0., 0.)).magnitude() < 1e-9);
+        let sez = ecef_to_sez(Vector3::new(WGS84.r_earth_eq, 1000., 0.), Deg(0.), Deg(0.), 0., &WGS84);
+        assert!((sez - Vector3::new(0., 1000., 0.)).magnitude() < 1e-9);
+
+        // The rotation preserves the range
+        let sat_ecef = Vector3::new(-4500., 2100., 4800.);
+        let observer_ecef = geodetic_to_ecef(Deg(40.), Deg(-105.), 1.6, &WGS84);
+        let sez = ecef_to_sez(sat_ecef, Deg(40.), Deg(-105.), 1.6, &WGS84);
+        assert!((sez.magnitude() - (sat_ecef - observer_ecef).magnitude()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_doppler_shift() {
+        let observer = geodetic_to_ecef(Deg(40.), Deg(-105.), 1.6, &WGS84);
+        let sat = geodetic_to_ecef(Deg(45.), Deg(-100.), 500., &WGS84);
+        let toward = (observer - sat) * (1. / (observer - sat).magnitude());
+        let across = toward.cross(&Vector3::new(0., 0., 1.));
+
+        // An approaching satellite shifts the frequency up and a receding one down, by the same amount
+        let up = doppler_shift(sat, toward * 7., observer, 145.8e6);
+        let down = doppler_shift(sat, toward * -7., observer, 145.8e6);
+        assert!((up - 145.8e6 - 145.8e6 * 7. / SPEED_OF_LIGHT).abs() < 1e-6);
+        assert!((up - 145.8e6 + (down - 145.8e6)).abs() < 1e-6);
+
+        // At the closest approach the velocity is across the line of sight and there is no shift
+        assert!((doppler_shift(sat, across * 7., observer, 145.8e6) - 145.8e6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_look_angles() {
+        // A satellite due north of an equatorial observer, on the horizon plane
+        let (az, el, range) = look_angles(Vector3::new(WGS84.r_earth_eq, 0., 1000.), Deg(0.), Deg(0.), 0., &WGS84);
+        assert!(az.0.abs() < 1e-9);
+        assert!(el.0.abs() < 1e-9);
+        assert!((range - 1000.).abs() < 1e-9);
+
+        // A satellite due east of and above an equatorial observer
+        let (az, el, range) = look_angles(Vector3::new(WGS84.r_earth_eq + 1000., 1000., 0.), Deg(0.), Deg(0.), 0., &WGS84);
+        assert!((az.0 - 90.).abs() < 1e-9);
+        assert!((el.0 - 45.).abs() < 1e-9);
+        assert!((range - 2_f64.sqrt() * 1000.).abs() < 1e-9);
+
+        // A satellite due west is at 270 degrees of azimuth
+        let (az, _el, _range) = look_angles(Vector3::new(WGS84.r_earth_eq, -1000., 0.), Deg(0.), Deg(0.), 0., &WGS84);
+        assert!((az.0 - 270.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_eci_to_radec() {
+        // A satellite at 30 degrees of right ascension and 45 degrees of declination, 1000 km from the observer
+        let rho = Vector3::new(1000. * 0.5_f64.sqrt() * 3_f64.sqrt() / 2., 1000. * 0.5_f64.sqrt() / 2., 1000. * 0.5_f64.sqrt());
+        let (ra, dec) = eci_to_radec(rho);
+        assert!((ra - 2.).abs() < 1e-12, "Right ascension: expected 2 h, got {}", ra);
+        assert!((dec - 45.).abs() < 1e-12, "Declination: expected 45 deg, got {}", dec);
+
+        // A satellite south of the equator and west of the equinox wraps to the last quadrant
+        let (ra, dec) = eci_to_radec(Vector3::new(1000., -1000., -2_f64.sqrt() * 1000.));
+        assert!((ra - 21.).abs() < 1e-12, "Right ascension: expected 21 h, got {}", ra);
+        assert!((dec + 45.).abs() < 1e-12, "Declination: expected -45 deg, got {}", dec);
+
+        // The celestial poles
+        assert!((eci_to_radec(Vector3::new(0., 0., 500.)).1 - 90.).abs() < 1e-12);
+        assert!((eci_to_radec(Vector3::new(0., 0., -500.)).1 + 90.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_look_angles_iss() {
+        // Define the ISS TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // Locate the ISS in the Earth-fixed frame ten minutes after the epoch
+        let state = propagate(&sgp4, 10.).unwrap();
+        let gmst = calc_theta_g(sgp4.jd0, sgp4.jdfrac0 + 10. / 1440.);
+        let sat_ecef = teme_to_ecef(&state, gmst, &WGS72).unwrap().position;
+        let (lat, lon, alt) = teme_to_geodetic(state.position, gmst, &WGS72);
+
+        // A station at the sub-satellite point sees the ISS at the zenith
+        let (_az, el, range) = look_angles(sat_ecef, Deg(lat), Deg(lon), 0., &WGS72);
+        assert!((el.0 - 90.).abs() < 1e-6);
+        assert!((range - alt).abs() < 1e-6);
+
+        // A station 10 degrees of latitude south of the sub-satellite point sees it above the horizon, roughly north
+        let (az, el, range) = look_angles(sat_ecef, Deg(lat - 10.), Deg(lon), 0., &WGS72);
+        assert!(!(10. ..=350.).contains(&az.0));
+        assert!(el > Deg(0.) && el < Deg(90.));
+        assert!(range > alt && range < 2000.);
+
+        // A station on the far side of the Earth sees it below the horizon
+        let (_az, el, range) = look_angles(sat_ecef, Deg(-lat), Deg(lon + 180.), 0., &WGS72);
+        assert!(el < Deg(-45.));
+        assert!(range > 12000.);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_ground_track_iss() {
+        // Define the ISS TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // Generate the track over a single orbit
+        let track = ground_track(&sgp4, 0., 91., 1.).unwrap();
+        assert_eq!(track.len(), 92);
+        for window in track.windows(2) {
+            let (t0, lat0, lon0) = window[0];
+            let (t1, lat1, lon1) = window[1];
+            assert_eq!(t1 - t0, 1.);
+
+            // The latitude stays within the inclination
+            assert!(lat0.abs() < 52. && lat1.abs() < 52.);
+
+            // The longitude advances eastward, allowing for the wrap at the antimeridian
+            let mut delta_lon = lon1 - lon0;
+            if delta_lon < -180. {
+                delta_lon = delta_lon + 360.;
+            }
+            assert!(delta_lon > 0. && delta_lon < 180.);
+        }
+
+        // An invalid step is reported
+        assert!(ground_track(&sgp4, 0., 91., 0.).is_err());
+
+        // As are infinite and unbounded ranges, before any sample is allocated
+        assert_eq!(ground_track(&sgp4, 0., f64::INFINITY, 1.), Err(PropagationError::InvalidTimeRange { start: 0., stop: f64::INFINITY }));
+        assert!(matches!(ground_track(&sgp4, -f64::MAX, f64::MAX, 1.), Err(PropagationError::TooManySamples { .. })));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_split_track_at_antimeridian() {
+        // Define the ISS TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // Two orbits of the track cross the antimeridian twice
+        let track = ground_track(&sgp4, 0., 180., 1.).unwrap();
+        let segments = split_track_at_antimeridian(&track);
+        assert_eq!(segments.len(), 3);
+
+        // The segments hold every sample in order, and no segment jumps across the map
+        assert_eq!(segments.concat(), track);
+        for segment in &segments {
+            for window in segment.windows(2) {
+                assert!((window[1].2 - window[0].2).abs() <= 180.);
+            }
+        }
+
+        // Consecutive segments end and start on opposite sides of the antimeridian
+        for pair in segments.windows(2) {
+            let lon_end = pair[0].last().unwrap().2;
+            let lon_start = pair[1][0].2;
+            assert!(lon_end > 90. && lon_start < -90.);
+        }
+
+        // A track that does not cross is a single segment, and an empty track has none
+        assert_eq!(split_track_at_antimeridian(&track[10..20]), vec![track[10..20].to_vec()]);
+        assert!(split_track_at_antimeridian(&[]).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_ground_track_timed() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        // Define the ISS TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // Sample once per orbit, a day after the epoch, so each sample is at nearly the same point of the orbit
+        let period_s = (86400. / tle.mean_motion).round() as i64; // [s]
+        let start = Utc.with_ymd_and_hms(2008, 9, 21, 12, 0, 0).unwrap();
+        let track = ground_track_timed(&sgp4, start, start + Duration::seconds(15 * period_s), Duration::seconds(period_s)).unwrap();
+        assert_eq!(track.len(), 16);
+
+        // The track moves west by the Earth's rotation over one period, plus the regression of the node
+        let earth_rotation = (WGS72.omega_earth / 60.).to_degrees() * period_s as f64; // [deg]
+        let nodal_regression = -tle.nodal_regression(&WGS72) * period_s as f64 / 86400.; // [deg]
+        for window in track.windows(2) {
+            let (t0, lat0, lon0, alt0) = window[0];
+            let (t1, lat1, lon1, alt1) = window[1];
+            assert_eq!(t1 - t0, Duration::seconds(period_s));
+            let mut delta_lon = lon1 - lon0;
+            if delta_lon > 180. {
+                delta_lon = delta_lon - 360.;
+            }
+            assert!((delta_lon + earth_rotation + nodal_regression).abs() < 0.5, "Westward shift: {} deg", -delta_lon);
+            assert!((lat1 - lat0).abs() < 1.);
+            assert!(alt0 > 300. && alt0 < 400. && alt1 > 300. && alt1 < 400.);
+        }
+
+        // The samples match the ground track at the same minutes since the epoch
+        let epoch = tle.epoch_chrono().unwrap();
+        let delta = start - epoch;
+        let minutes = delta.num_seconds() as f64 / 60. + delta.subsec_nanos() as f64 / 60e9; // [min]
+        let (_, lat, lon) = ground_track(&sgp4, minutes, minutes, 1.).unwrap()[0];
+        assert!((track[0].1 - lat).abs() < 1e-6 && (track[0].2 - lon).abs() < 1e-6);
+
+        // Invalid steps and ranges are reported
+        assert!(matches!(ground_track_timed(&sgp4, start, start, Duration::zero()), Err(PropagationError::InvalidStep { .. })));
+        assert!(matches!(ground_track_timed(&sgp4, start, start - Duration::minutes(1), Duration::minutes(1)), Err(PropagationError::InvalidTimeRange { .. })));
+    }
+
+    #[test]
+    fn test_teme_to_geodetic_iss() {
+        // Define the ISS TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // Check the sub-satellite point over the first orbit
+        for minutes in [0., 15., 30., 45., 60., 75., 90.] {
+            let state = propagate(&sgp4, minutes).unwrap();
+            let gmst = calc_theta_g(sgp4.jd0, sgp4.jdfrac0 + minutes / 1440.);
+            let (lat, lon, alt) = teme_to_geodetic(state.position, gmst, &WGS72);
+
+            // The latitude is bounded by the inclination and the altitude is around 350 km
+            assert!(lat.abs() <= 51.6416 + 0.2);
+            assert!((-180. ..=180.).contains(&lon));
+            assert!((300. ..=450.).contains(&alt));
+        }
+    }
+
+    #[test]
+    fn test_teme_to_j2000() {
+        // Example 3-15 from Vallado, 2004-04-06 07:51:28.386009 UTC (T = 0.0426236319 centuries of TT)
+        let pos = Vector3::new(5094.18016210, 6127.64465950, 6380.34453270);
+        let vel = Vector3::new(-4.746131487, 0.785818041, 5.531931288);
+        let jd_tt = 2451545.0 + 0.0426236319 * 36525.0;
+
+        // The nutation matches the published values
+        let (delta_psi, delta_eps, _mean_eps) = calc_nutation_1980(0.0426236319);
+        assert!((delta_psi.to_degrees() - -0.0034108).abs() < 1e-7, "Nutation in longitude: expected -0.0034108 deg, got {}", delta_psi.to_degrees());
+        assert!((delta_eps.to_degrees() - 0.0020316).abs() < 1e-7, "Nutation in obliquity: expected 0.0020316 deg, got {}", delta_eps.to_degrees());
+
+        // The J2000 state matches the published values
+        let state_j2000 = teme_to_j2000(&teme_state(pos, vel), jd_tt).unwrap();
+        let (pos_j2000, vel_j2000) = (state_j2000.position, state_j2000.velocity);
+        let r_expect = Vector3::new(5102.5096, 6123.01152, 6378.1363);
+        let v_expect = Vector3::new(-4.7432196, 0.7905366, 5.5337561);
+        assert!((pos_j2000 - r_expect).magnitude() < 1e-4, "Position test failed: expected {:?}, got {:?}", r_expect, pos_j2000);
+        assert!((vel_j2000 - v_expect).magnitude() < 1e-6, "Velocity test failed: expected {:?}, got {:?}", v_expect, vel_j2000);
+
+        // The rotation preserves lengths and is the identity at J2000.0 up to nutation
+        assert!((pos_j2000.magnitude() - pos.magnitude()).abs() < 1e-9);
+        let state_epoch = teme_to_j2000(&teme_state(pos, vel), 2451545.0).unwrap();
+        assert!((state_epoch.position - pos).magnitude() < 2.);
+    }
+}
@@ -0,0 +1,111 @@
+// Module for exporting propagated ephemerides
+
+// ------------------
+// External Libraries
+// ------------------
+use std::io;
+
+// ------------------
+// Internal Libraries
+// ------------------
+use crate::common::StateVector;
+
+// ---------
+// Constants
+// ---------
+
+/// The header row of an ephemeris CSV file
+const EPHEMERIS_CSV_HEADER: &str = "t_min,x_km,y_km,z_km,vx,vy,vz";
+
+// ---------
+// Functions
+// ---------
+
+/// Write a propagated ephemeris as CSV
+///
+/// A header row (`t_min,x_km,y_km,z_km,vx,vy,vz`) is written first, followed by one row per sample holding the
+/// minutes since epoch, the position \[km\] and the velocity \[km/s\]. Values are written in full precision so the
+/// file can be read back without loss. The rows are typically the output of
+/// [`propagate_range`](crate::sgp4::propagate_range).
+///
+/// # Arguments
+/// * `w` - The writer to write to (e.g. a file or an in-memory buffer)
+/// * `rows` - The (minutes since epoch, state vector) pairs
+///
+/// # Returns
+/// * `io::Result<()>` - Ok once every row is written
+///
+/// # Errors
+/// * Any I/O error returned by the writer
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::{init_sgp4, propagate_range};
+/// use Rusty_SGP4::export::write_ephemeris_csv;
+///
+/// // Propagate the ISS for 10 minutes
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+/// let states = propagate_range(&sgp4, 0., 10., 1.).unwrap();
+///
+/// // Write the ephemeris to an in-memory buffer
+/// let mut buffer = Vec::new();
+/// write_ephemeris_csv(&mut buffer, &states)?;
+/// assert_eq!(String::from_utf8(buffer).unwrap().lines().count(), 12);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn write_ephemeris_csv<W: io::Write>(mut w: W, rows: &[(f64, StateVector)]) -> io::Result<()> {
+    // Write the header
+    writeln!(w, "{}", EPHEMERIS_CSV_HEADER)?;
+
+    // Write one row per sample
+    for (minutes_since_epoch, state) in rows {
+        let r = &state.position;
+        let v = &state.velocity;
+        writeln!(w, "{},{},{},{},{},{},{}", minutes_since_epoch, r.x, r.y, r.z, v.x, v.y, v.z)?;
+    }
+
+    return Ok(());
+}
+
+// ----------
+// Unit Tests
+// ----------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::CoordinateFrame;
+    use crate::math::Vector3;
+
+    #[test]
+    fn test_write_ephemeris_csv() {
+        // Define two samples
+        let state = StateVector {
+            time_min: 0.,
+            position: Vector3::new(7022.5, -1400.25, 0.125),
+            velocity: Vector3::new(1.5, 6.25, -4.5),
+            coordinate_frame: CoordinateFrame::TEME,
+        };
+        let rows = [(0., state), (360., state)];
+
+        // Write to an in-memory buffer
+        let mut buffer = Vec::new();
+        write_ephemeris_csv(&mut buffer, &rows).unwrap();
+        let csv = String::from_utf8(buffer).unwrap();
+
+        // Check the header and the data rows
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "t_min,x_km,y_km,z_km,vx,vy,vz");
+        assert_eq!(lines[1], "0,7022.5,-1400.25,0.125,1.5,6.25,-4.5");
+        assert_eq!(lines[2], "360,7022.5,-1400.25,0.125,1.5,6.25,-4.5");
+
+        // No samples writes only the header
+        let mut buffer = Vec::new();
+        write_ephemeris_csv(&mut buffer, &[]).unwrap();
+        assert_eq!(buffer, b"t_min,x_km,y_km,z_km,vx,vy,vz\n");
+    }
+}
@@ -0,0 +1,188 @@
+// Module for downloading TLEs over HTTP
+
+// ------------------
+// External Libraries
+// ------------------
+
+// ------------------
+// Internal Libraries
+// ------------------
+use crate::tle::{from_string, Tle, TleError};
+
+// -----
+// Enums
+// -----
+
+/// Errors that can occur while downloading TLEs
+#[derive(Debug)]
+pub enum FetchError {
+    /// The request failed or the server responded with an error status
+    Http(reqwest::Error),
+    /// The downloaded text could not be parsed as Two-Line Element sets
+    Parse(TleError),
+}
+
+// ---------
+// Constants
+// ---------
+
+/// The Celestrak general perturbations (GP) query endpoint
+///
+/// References:
+/// - [Celestrak GP Data Formats](https://celestrak.org/NORAD/documentation/gp-data-formats.php)
+const CELESTRAK_GP_URL: &str = "https://celestrak.org/NORAD/elements/gp.php";
+
+// ---------------
+// Implementations
+// ---------------
+
+impl From<reqwest::Error> for FetchError {
+    fn from(error: reqwest::Error) -> Self {
+        return FetchError::Http(error);
+    }
+}
+
+impl From<TleError> for FetchError {
+    fn from(error: TleError) -> Self {
+        return FetchError::Parse(error);
+    }
+}
+
+// ---------
+// Functions
+// ---------
+
+/// Download and parse the Two-Line Element sets served at a URL
+///
+/// The response body is parsed with [`from_string`], so 2-line and 3-line entries may be mixed but every entry must
+/// parse.
+///
+/// # Arguments
+/// * `url` - The URL of a plain text TLE file
+///
+/// # Returns
+/// * `Result<Vec<Tle>, FetchError>` - On success, a vector containing all of the downloaded TLEs
+///
+/// # Errors
+/// * `FetchError::Http` if the request fails or the server responds with an error status
+/// * `FetchError::Parse` carrying the error of the first entry that fails to parse
+///
+/// # Examples
+/// ```rust,no_run
+/// use Rusty_SGP4::fetch::fetch_tles;
+///
+/// # async fn run() -> Result<(), Rusty_SGP4::fetch::FetchError> {
+/// // Download the space stations group
+/// let tles = fetch_tles("https://celestrak.org/NORAD/elements/gp.php?GROUP=stations&FORMAT=tle").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn fetch_tles(url: &str) -> Result<Vec<Tle>, FetchError> {
+    // Download the TLE text
+    let response = reqwest::get(url).await?.error_for_status()?;
+    let tle_string = response.text().await?;
+
+    // Parse the TLEs
+    let tles = from_string(&tle_string)?;
+
+    return Ok(tles);
+}
+
+/// Download and parse the Two-Line Element sets of a named Celestrak group
+///
+/// # Arguments
+/// * `name` - The Celestrak group name (e.g. `stations`, `starlink`, `gps-ops`)
+///
+/// # Returns
+/// * `Result<Vec<Tle>, FetchError>` - On success, a vector containing all of the TLEs in the group
+///
+/// # Errors
+/// * Any error returned by [`fetch_tles`]
+///
+/// # Examples
+/// ```rust,no_run
+/// use Rusty_SGP4::fetch::fetch_group;
+///
+/// # async fn run() -> Result<(), Rusty_SGP4::fetch::FetchError> {
+/// // Download the space stations group
+/// let tles = fetch_group("stations").await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+/// - [Celestrak GP Data Formats](https://celestrak.org/NORAD/documentation/gp-data-formats.php)
+pub async fn fetch_group(name: &str) -> Result<Vec<Tle>, FetchError> {
+    return fetch_tles(&celestrak_group_url(name)).await;
+}
+
+/// Build the Celestrak GP query URL for a named group in TLE format
+///
+/// The group name is URL-encoded, so a name containing `&`, `=` or spaces cannot change the other query parameters.
+///
+/// # Arguments
+/// * `name` - The Celestrak group name
+///
+/// # Returns
+/// * `String` - The query URL
+fn celestrak_group_url(name: &str) -> String {
+    let mut url = reqwest::Url::parse(CELESTRAK_GP_URL).expect("The Celestrak GP URL is valid");
+    url.query_pairs_mut().append_pair("GROUP", name).append_pair("FORMAT", "tle");
+
+    return url.into();
+}
+
+// ----------
+// Unit Tests
+// ----------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve a single HTTP response with the given body on a local port, returning the URL
+    async fn serve_fixture(status: &'static str, body: String) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/tles.txt", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 1024];
+            let _ = socket.read(&mut request).await.unwrap();
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        });
+        return url;
+    }
+
+    #[test]
+    fn test_celestrak_group_url() {
+        assert_eq!(celestrak_group_url("stations"), "https://celestrak.org/NORAD/elements/gp.php?GROUP=stations&FORMAT=tle");
+
+        // Reserved characters in the group name are encoded rather than starting new parameters
+        assert_eq!(celestrak_group_url("gps ops&FORMAT=json"), "https://celestrak.org/NORAD/elements/gp.php?GROUP=gps+ops%26FORMAT%3Djson&FORMAT=tle");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_tles() {
+        // Serve two TLEs with CRLF line endings, as Celestrak does
+        let body = "ISS (ZARYA)\r\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\r\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537\r\n1 28057U 03049A   06177.78615833  .00000060  00000-0  35940-4 0  1836\r\n2 28057  98.4283 247.6961 0000884  88.1964 271.9322 14.35478080140550\r\n";
+        let url = serve_fixture("200 OK", body.to_string()).await;
+        let tles = fetch_tles(&url).await.unwrap();
+        assert_eq!(tles.len(), 2);
+        assert_eq!(tles[0].common_name, "ISS (ZARYA)");
+        assert_eq!(tles[1].satellite_catalog_number, 28057);
+
+        // A corrupted entry is a parse error
+        let body = std::fs::read_to_string("assets/three_sats.tle").unwrap();
+        let url = serve_fixture("200 OK", body).await;
+        let result = fetch_tles(&url).await;
+        assert!(matches!(result, Err(FetchError::Parse(TleError::ChecksumFailed { line: 1 }))));
+
+        // An error status is an HTTP error
+        let url = serve_fixture("404 Not Found", String::new()).await;
+        let result = fetch_tles(&url).await;
+        assert!(matches!(result, Err(FetchError::Http(_))));
+    }
+}
@@ -0,0 +1,27 @@
+// Rusty SGP4: Two-Line Element parsing and SGP4 orbit propagation
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(non_snake_case)]
+
+// Lints that conflict with the explicit, equation-by-equation style used throughout the crate
+#![allow(clippy::needless_return)]
+#![allow(clippy::redundant_field_names)]
+#![allow(clippy::assign_op_pattern)]
+#![allow(clippy::excessive_precision)]
+
+#[cfg(feature = "std")]
+pub mod cache;
+pub mod common;
+pub mod conjunction;
+pub mod coordinates;
+#[cfg(feature = "csv")]
+pub mod export;
+#[cfg(feature = "reqwest")]
+pub mod fetch;
+pub mod math;
+#[cfg(feature = "omm")]
+pub mod omm;
+pub mod passes;
+pub mod sgp4;
+pub mod sun;
+pub mod time;
+pub mod tle;
@@ -1,7 +0,0 @@
-mod tle;
-mod time;
-mod sgp4;
-mod common;
-
-fn main() {
-}
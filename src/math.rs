@@ -0,0 +1,451 @@
+// Module for vector math
+
+// ------------------
+// External Libraries
+// ------------------
+use core::ops;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+// ------------------
+// Internal Libraries
+// ------------------
+
+// -------
+// Structs
+// -------
+
+/// A 3D Cartesian vector
+///
+/// This struct is used for the position and velocity outputs of the propagator and the coordinate transforms.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::math::Vector3;
+///
+/// // Define two vectors
+/// let a = Vector3::new(1., 2., 3.);
+/// let b = Vector3::new(4., 5., 6.);
+///
+/// // Combine them with the vector operators
+/// let c = (a + b) * 2. - a;
+/// assert_eq!(c, Vector3::new(9., 12., 15.));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Vector3 {
+    /// x component
+    pub x: f64,
+
+    /// y component
+    pub y: f64,
+
+    /// z component
+    pub z: f64,
+}
+
+/// An angle in degrees
+///
+/// Angles are taken and returned as [`Deg`] or [`Rad`] where a unit mixup is easy, such as observer coordinates and
+/// look angles. The two convert into each other with `From` and `Into`, but not from a bare `f64`, so passing an
+/// angle in the wrong unit is a compile error.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::math::{Deg, Rad};
+///
+/// // Convert a right angle to radians
+/// let angle: Rad = Deg(90.).into();
+/// assert_eq!(angle, Rad(core::f64::consts::FRAC_PI_2));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Deg(pub f64);
+
+/// An angle in radians, see [`Deg`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Rad(pub f64);
+
+// ---------
+// Constants
+// ---------
+
+// ---------------
+// Implementations
+// ---------------
+
+impl Vector3 {
+    /// Builds a vector from its components.
+    ///
+    /// # Arguments
+    /// * `x` - The x component
+    /// * `y` - The y component
+    /// * `z` - The z component
+    ///
+    /// # Returns
+    /// * `Vector3` - The vector
+    pub fn new(x: f64, y: f64, z: f64) -> Vector3 {
+        return Vector3 { x: x, y: y, z: z };
+    }
+
+    /// Calculates the magnitude (Euclidean norm) of the vector.
+    ///
+    /// # Returns
+    /// * `f64` - The magnitude of the vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::math::Vector3;
+    ///
+    /// assert_eq!(Vector3::new(3., 4., 12.).magnitude(), 13.);
+    /// ```
+    pub fn magnitude(&self) -> f64 {
+        return self.dot(self).sqrt();
+    }
+
+    /// Calculates the dot product with another vector.
+    ///
+    /// # Arguments
+    /// * `other` - The other vector
+    ///
+    /// # Returns
+    /// * `f64` - The dot product
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::math::Vector3;
+    ///
+    /// assert_eq!(Vector3::new(1., 2., 3.).dot(&Vector3::new(4., -5., 6.)), 12.);
+    /// ```
+    pub fn dot(&self, other: &Vector3) -> f64 {
+        return self.x * other.x + self.y * other.y + self.z * other.z;
+    }
+
+    /// Calculates the cross product with another vector (`self` × `other`).
+    ///
+    /// # Arguments
+    /// * `other` - The other vector
+    ///
+    /// # Returns
+    /// * `Vector3` - The cross product
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::math::Vector3;
+    ///
+    /// // x cross y is z
+    /// let z = Vector3::new(1., 0., 0.).cross(&Vector3::new(0., 1., 0.));
+    /// assert_eq!(z, Vector3::new(0., 0., 1.));
+    /// ```
+    pub fn cross(&self, other: &Vector3) -> Vector3 {
+        return Vector3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        };
+    }
+
+    /// Adds another vector component-wise.
+    ///
+    /// # Arguments
+    /// * `other` - The vector to add
+    ///
+    /// # Returns
+    /// * `Vector3` - The sum of the vectors
+    pub fn add(&self, other: &Vector3) -> Vector3 {
+        return Vector3::new(self.x + other.x, self.y + other.y, self.z + other.z);
+    }
+
+    /// Subtracts another vector component-wise.
+    ///
+    /// # Arguments
+    /// * `other` - The vector to subtract
+    ///
+    /// # Returns
+    /// * `Vector3` - The difference of the vectors
+    pub fn sub(&self, other: &Vector3) -> Vector3 {
+        return Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z);
+    }
+
+    /// Multiplies every component by a scalar.
+    ///
+    /// # Arguments
+    /// * `scalar` - The scale factor
+    ///
+    /// # Returns
+    /// * `Vector3` - The scaled vector
+    pub fn scale(&self, scalar: f64) -> Vector3 {
+        return Vector3::new(self.x * scalar, self.y * scalar, self.z * scalar);
+    }
+}
+
+impl ops::Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, other: Vector3) -> Vector3 {
+        return Vector3::add(&self, &other);
+    }
+}
+
+impl ops::Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, other: Vector3) -> Vector3 {
+        return Vector3::sub(&self, &other);
+    }
+}
+
+impl ops::Mul<f64> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, scalar: f64) -> Vector3 {
+        return self.scale(scalar);
+    }
+}
+
+impl ops::Mul<Vector3> for f64 {
+    type Output = Vector3;
+
+    fn mul(self, vector: Vector3) -> Vector3 {
+        return vector.scale(self);
+    }
+}
+
+impl From<Deg> for Rad {
+    fn from(angle: Deg) -> Rad {
+        return Rad(angle.0.to_radians());
+    }
+}
+
+impl From<Rad> for Deg {
+    fn from(angle: Rad) -> Deg {
+        return Deg(angle.0.to_degrees());
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vector3> for nalgebra::Vector3<f64> {
+    fn from(vector: Vector3) -> nalgebra::Vector3<f64> {
+        return nalgebra::Vector3::new(vector.x, vector.y, vector.z);
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f64>> for Vector3 {
+    fn from(vector: nalgebra::Vector3<f64>) -> Vector3 {
+        return Vector3::new(vector.x, vector.y, vector.z);
+    }
+}
+
+// ---------
+// Functions
+// ---------
+
+/// Normalize an angle to \[0, 360) degrees
+///
+/// Negative angles wrap up from 360 and angles of 360 or more wrap down to 0, so an angle a hair below zero is just
+/// under 360 rather than negative. A negative zero is returned as zero.
+///
+/// # Arguments
+/// * `angle` - The angle \[deg\]
+///
+/// # Returns
+/// * `f64` - The equivalent angle in \[0, 360) \[deg\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::math::normalize_deg_0_360;
+///
+/// assert_eq!(normalize_deg_0_360(360.), 0.);
+/// assert_eq!(normalize_deg_0_360(-1.), 359.);
+/// assert_eq!(normalize_deg_0_360(725.), 5.);
+/// ```
+pub fn normalize_deg_0_360(angle: f64) -> f64 {
+    return wrap_to_period(angle, 360.);
+}
+
+/// Normalize an angle to \[-180, 180) degrees
+///
+/// # Arguments
+/// * `angle` - The angle \[deg\]
+///
+/// # Returns
+/// * `f64` - The equivalent angle in \[-180, 180) \[deg\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::math::normalize_deg_pm_180;
+///
+/// assert_eq!(normalize_deg_pm_180(180.), -180.);
+/// assert_eq!(normalize_deg_pm_180(359.), -1.);
+/// assert_eq!(normalize_deg_pm_180(-190.), 170.);
+/// ```
+pub fn normalize_deg_pm_180(angle: f64) -> f64 {
+    let wrapped = wrap_to_period(angle, 360.); // [deg]
+    return if wrapped >= 180. { wrapped - 360. } else { wrapped };
+}
+
+/// Normalize an angle to \[0, 2π) radians
+///
+/// # Arguments
+/// * `angle` - The angle \[rad\]
+///
+/// # Returns
+/// * `f64` - The equivalent angle in \[0, 2π) \[rad\]
+///
+/// # Examples
+/// ```rust
+/// use core::f64::consts::PI;
+/// use Rusty_SGP4::math::normalize_rad_0_2pi;
+///
+/// assert_eq!(normalize_rad_0_2pi(2. * PI), 0.);
+/// assert_eq!(normalize_rad_0_2pi(-PI), PI);
+/// ```
+pub fn normalize_rad_0_2pi(angle: f64) -> f64 {
+    return wrap_to_period(angle, 2. * core::f64::consts::PI);
+}
+
+/// Wrap a value to \[0, period)
+///
+/// # Arguments
+/// * `value` - The value to wrap
+/// * `period` - The period, greater than zero
+///
+/// # Returns
+/// * `f64` - The value in \[0, period), positive zero for any multiple of the period
+fn wrap_to_period(value: f64, period: f64) -> f64 {
+    let mut wrapped = value % period;
+    if wrapped < 0. {
+        wrapped = wrapped + period;
+    }
+
+    // A tiny negative remainder rounds up to the period itself, and a negative zero stays negative
+    if wrapped >= period || wrapped == 0. {
+        wrapped = 0.;
+    }
+
+    return wrapped;
+}
+
+// ----------
+// Unit Tests
+// ----------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_angles() {
+        // Wraps at the boundaries
+        assert_eq!(normalize_deg_0_360(0.), 0.);
+        assert_eq!(normalize_deg_0_360(360.), 0.);
+        assert_eq!(normalize_deg_0_360(-1.), 359.);
+        assert_eq!(normalize_deg_0_360(-360.), 0.);
+        assert_eq!(normalize_deg_0_360(719.5), 359.5);
+        assert_eq!(normalize_deg_pm_180(180.), -180.);
+        assert_eq!(normalize_deg_pm_180(-180.), -180.);
+        assert_eq!(normalize_deg_pm_180(179.5), 179.5);
+        assert_eq!(normalize_deg_pm_180(181.), -179.);
+        assert_eq!(normalize_deg_pm_180(-181.), 179.);
+        assert_eq!(normalize_rad_0_2pi(-core::f64::consts::FRAC_PI_2), 1.5 * core::f64::consts::PI);
+        assert_eq!(normalize_rad_0_2pi(4. * core::f64::consts::PI), 0.);
+
+        // Negative zero and angles a hair below zero stay inside the interval
+        assert!(normalize_deg_0_360(-0.).is_sign_positive());
+        assert!(normalize_deg_pm_180(-0.).is_sign_positive());
+        let tiny = normalize_deg_0_360(-1e-15);
+        assert!((0. ..360.).contains(&tiny), "Wrapped to {}", tiny);
+        let tiny = normalize_deg_0_360(-1e-20);
+        assert!((0. ..360.).contains(&tiny), "Wrapped to {}", tiny);
+        let tiny = normalize_rad_0_2pi(-1e-20);
+        assert!((0. ..2. * core::f64::consts::PI).contains(&tiny), "Wrapped to {}", tiny);
+    }
+
+    #[test]
+    fn test_dot() {
+        // Orthogonal vectors have a zero dot product
+        let a = Vector3::new(1., 2., 3.);
+        let b = Vector3::new(-2., 1., 0.);
+        assert_eq!(a.dot(&b), 0.);
+
+        // Known dot product
+        let c = Vector3::new(4., -5., 6.);
+        assert_eq!(a.dot(&c), 12.);
+
+        // The dot product with itself is the magnitude squared
+        assert!((a.dot(&a) - a.magnitude().powi(2)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cross() {
+        // Right-handed unit vectors
+        let x = Vector3::new(1., 0., 0.);
+        let y = Vector3::new(0., 1., 0.);
+        let z = Vector3::new(0., 0., 1.);
+        assert_eq!(x.cross(&y), z);
+        assert_eq!(y.cross(&z), x);
+        assert_eq!(z.cross(&x), y);
+        assert_eq!(y.cross(&x), z * -1.);
+
+        // Known cross product
+        let a = Vector3::new(1., 2., 3.);
+        let b = Vector3::new(4., 5., 6.);
+        let c = a.cross(&b);
+        assert_eq!(c, Vector3::new(-3., 6., -3.));
+
+        // The cross product is orthogonal to both inputs
+        assert_eq!(c.dot(&a), 0.);
+        assert_eq!(c.dot(&b), 0.);
+
+        // The cross product of parallel vectors is zero
+        assert_eq!(a.cross(&(a * 2.)), Vector3::default());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Vector3::new(1., 2., 3.);
+        let b = Vector3::new(4., 5., 6.);
+
+        // Methods and operators agree
+        assert_eq!(a + b, Vector3::new(5., 7., 9.));
+        assert_eq!(a + b, a.add(&b));
+        assert_eq!(b - a, Vector3::new(3., 3., 3.));
+        assert_eq!(b - a, b.sub(&a));
+        assert_eq!(a * 2., Vector3::new(2., 4., 6.));
+        assert_eq!(2. * a, a.scale(2.));
+
+        // Magnitude
+        assert_eq!(Vector3::new(2., 3., 6.).magnitude(), 7.);
+    }
+
+    #[test]
+    fn test_angle_conversions() {
+        // Degrees and radians convert both ways
+        assert_eq!(Rad::from(Deg(180.)), Rad(core::f64::consts::PI));
+        assert_eq!(Deg::from(Rad(core::f64::consts::PI)), Deg(180.));
+        let angle: Deg = Rad(1.).into();
+        assert!((angle.0 - 57.29577951308232).abs() < 1e-12);
+
+        // The round trip preserves the angle
+        for degrees in [-720., -90., 0., 51.6416, 359.9999] {
+            let round_trip: Deg = Rad::from(Deg(degrees)).into();
+            assert!((round_trip.0 - degrees).abs() < 1e-12);
+        }
+
+        // Angles of the same unit are ordered
+        assert!(Deg(10.) < Deg(20.));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra_roundtrip() {
+        let a = Vector3::new(-7154.03120202, -3783.17682504, -3536.19412294);
+
+        // Convert to nalgebra and check the components
+        let b: nalgebra::Vector3<f64> = a.into();
+        assert_eq!(b, nalgebra::Vector3::new(a.x, a.y, a.z));
+        assert_eq!(b.norm(), a.magnitude());
+
+        // Convert back
+        let c: Vector3 = b.into();
+        assert_eq!(c, a);
+    }
+}
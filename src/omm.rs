@@ -0,0 +1,471 @@
+// Module for reading and writing CCSDS Orbit Mean-Elements Messages (OMM)
+
+// ------------------
+// External Libraries
+// ------------------
+use serde_json::Value;
+
+// ------------------
+// Internal Libraries
+// ------------------
+use crate::time::{dayofyr2utc, utc2dayofyr, DateTime, Timezone};
+use crate::tle::{Tle, TleError};
+
+// ---------
+// Functions
+// ---------
+
+/// Builds a [`Tle`] struct from a CCSDS Orbit Mean-Elements Message (OMM) in JSON.
+///
+/// This reads the JSON OMM served by Celestrak (`FORMAT=json`) and Space-Track (`format/json`). The message may be a
+/// single object or an array holding exactly one object, and each value may be a JSON number or a string. The mean
+/// motion derivatives are stored in OMM as they appear in the TLE columns (n-dot / 2 and n-ddot / 6), so they are
+/// scaled as in [`from_lines`](crate::tle::from_lines). The epoch is read as an ISO-8601 UTC timestamp
+/// (`YYYY-MM-DDTHH:MM:SS.ffffff`, with an optional trailing `Z`).
+///
+/// The following fields are required: `EPOCH`, `MEAN_MOTION`, `ECCENTRICITY`, `INCLINATION`, `RA_OF_ASC_NODE`,
+/// `ARG_OF_PERICENTER`, `MEAN_ANOMALY` and `NORAD_CAT_ID`. The remaining fields (`OBJECT_NAME`, `OBJECT_ID`,
+/// `CLASSIFICATION_TYPE`, `EPHEMERIS_TYPE`, `ELEMENT_SET_NO`, `REV_AT_EPOCH`, `BSTAR`, `MEAN_MOTION_DOT` and
+/// `MEAN_MOTION_DDOT`) default to the values of [`TleBuilder::new`](crate::tle::TleBuilder::new) when absent.
+///
+/// # Arguments
+/// * `s` - The OMM JSON text
+///
+/// # Returns
+/// * `Result<Tle, TleError>` - On success, the struct containing the OMM elements
+///
+/// # Errors
+/// * `TleError::InvalidJson` if the text is not JSON or does not hold a single OMM object
+/// * `TleError::FieldParse` if a field is missing or cannot be parsed, carrying the OMM field name and the offending value
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::omm::from_omm_json;
+///
+/// // Define the OMM of the ISS
+/// let omm = r#"{"OBJECT_NAME": "ISS (ZARYA)", "OBJECT_ID": "1998-067A", "EPOCH": "2008-09-20T12:25:40.104192",
+///     "MEAN_MOTION": 15.72125391, "ECCENTRICITY": 0.0006703, "INCLINATION": 51.6416, "RA_OF_ASC_NODE": 247.4627,
+///     "ARG_OF_PERICENTER": 130.536, "MEAN_ANOMALY": 325.0288, "NORAD_CAT_ID": 25544, "BSTAR": -1.1606e-5}"#;
+///
+/// // Parse the OMM into a TLE struct
+/// let tle = from_omm_json(omm)?;
+/// assert_eq!(tle.satellite_catalog_number, 25544);
+/// assert_eq!(tle.international_designator, "98067A");
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+///
+/// # References
+/// - [CCSDS Orbit Data Messages (502.0-B-3)](https://public.ccsds.org/Pubs/502x0b3e1.pdf)
+/// - [Celestrak GP Data Formats](https://celestrak.org/NORAD/documentation/gp-data-formats.php)
+pub fn from_omm_json(s: &str) -> Result<Tle, TleError> {
+    // Parse the JSON document
+    let document: Value = serde_json::from_str(s).map_err(|_| TleError::InvalidJson)?;
+
+    // Accept a single object or an array holding exactly one object
+    let omm = match &document {
+        Value::Array(records) if records.len() == 1 => &records[0],
+        _ => &document,
+    };
+    if !omm.is_object() {
+        return Err(TleError::InvalidJson);
+    }
+
+    // Start from the builder defaults
+    let mut tle = Tle {
+        classification: 'U',
+        ..Tle::default()
+    };
+
+    // Identification
+    tle.common_name = omm_text(omm, "OBJECT_NAME").unwrap_or_default();
+    tle.satellite_catalog_number = omm_number(omm, "NORAD_CAT_ID")?;
+    if let Some(object_id) = omm_text(omm, "OBJECT_ID") {
+        tle.international_designator = parse_object_id(&object_id)
+            .ok_or(TleError::FieldParse { field: "OBJECT_ID", columns: None, raw: object_id })?;
+    }
+    if let Some(classification) = omm_text(omm, "CLASSIFICATION_TYPE") {
+        let mut chars = classification.chars();
+        tle.classification = match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => return Err(TleError::FieldParse { field: "CLASSIFICATION_TYPE", columns: None, raw: classification }),
+        };
+    }
+
+    // Epoch
+    let epoch = omm_text(omm, "EPOCH")
+        .ok_or(TleError::FieldParse { field: "EPOCH", columns: None, raw: String::new() })?;
+    let epoch_datetime = parse_iso8601(&epoch)
+        .ok_or_else(|| TleError::FieldParse { field: "EPOCH", columns: None, raw: epoch.clone() })?;
+    (tle.epoch_year, tle.epoch_day) = utc2dayofyr(&epoch_datetime)
+        .map_err(|_| TleError::FieldParse { field: "EPOCH", columns: None, raw: epoch.clone() })?;
+
+    // Drag and mean motion derivatives, stored as they appear in the TLE columns
+    tle.first_derivative_of_mean_motion = omm_number_or(omm, "MEAN_MOTION_DOT", 0.)? * 2.0;
+    tle.second_derivative_of_mean_motion = omm_number_or(omm, "MEAN_MOTION_DDOT", 0.)? * 6.0;
+    tle.bstar = omm_number_or(omm, "BSTAR", 0.)?;
+    tle.ephemeris_type = omm_number_or(omm, "EPHEMERIS_TYPE", 0)?;
+    tle.element_set_number = omm_number_or(omm, "ELEMENT_SET_NO", 0)?;
+
+    // Mean elements
+    tle.inclination = omm_number(omm, "INCLINATION")?;
+    tle.right_ascension_of_ascending_node = omm_number(omm, "RA_OF_ASC_NODE")?;
+    tle.eccentricity = omm_number(omm, "ECCENTRICITY")?;
+    tle.argument_of_perigee = omm_number(omm, "ARG_OF_PERICENTER")?;
+    tle.mean_anomaly = omm_number(omm, "MEAN_ANOMALY")?;
+    tle.mean_motion = omm_number(omm, "MEAN_MOTION")?;
+    tle.revolution_number_at_epoch = omm_number_or(omm, "REV_AT_EPOCH", 0)?;
+
+    return Ok(tle);
+}
+
+/// Writes a [`Tle`] struct as a CCSDS Orbit Mean-Elements Message (OMM) in JSON.
+///
+/// This is the inverse of [`from_omm_json`]: the message is a single object with the field names and order served by
+/// Celestrak. The units follow the CCSDS OMM conventions for SGP4 elements, so the mean motion is in rev/day, the
+/// eccentricity is the decimal value and the angles are in degrees. The mean motion derivatives are written as they
+/// appear in the TLE columns (n-dot / 2 and n-ddot / 6). The epoch is an ISO-8601 UTC timestamp reconstructed from
+/// the epoch year and day of year, at microsecond resolution.
+///
+/// # Arguments
+/// * `tle` - The TLE struct to write
+///
+/// # Returns
+/// * `Result<String, TleError>` - On success, the OMM JSON text
+///
+/// # Errors
+/// * `TleError::OutOfRange` if the epoch day is less than 1 or exceeds the number of days in the epoch year
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::omm::{from_omm_json, to_omm_json};
+///
+/// // Define the ISS TLE
+/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(tle_line1, tle_line2, Some("ISS (ZARYA)"))?;
+///
+/// // Write the OMM and read it back
+/// let omm = to_omm_json(&tle)?;
+/// assert!(omm.contains(r#""EPOCH":"2008-09-20T12:25:40.104192""#));
+/// assert_eq!(from_omm_json(&omm)?.mean_motion, tle.mean_motion);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+///
+/// # References
+/// - [CCSDS Orbit Data Messages (502.0-B-3)](https://public.ccsds.org/Pubs/502x0b3e1.pdf)
+/// - [Celestrak GP Data Formats](https://celestrak.org/NORAD/documentation/gp-data-formats.php)
+pub fn to_omm_json(tle: &Tle) -> Result<String, TleError> {
+    // Reconstruct the epoch timestamp
+    let epoch = format_iso8601(tle.epoch_year, tle.epoch_day)
+        .ok_or(TleError::OutOfRange { field: "epoch_day", value: tle.epoch_day })?;
+
+    // Expand the international designator into a COSPAR object ID
+    let object_id = match tle.designator_parts() {
+        Some((launch_year, launch_number, piece)) => Value::from(format!("{:04}-{:03}{}", launch_year, launch_number, piece)),
+        None => Value::Null,
+    };
+
+    // Collect the fields in the order served by Celestrak
+    let fields: [(&str, Value); 17] = [
+        ("OBJECT_NAME", Value::from(tle.common_name.as_str())),
+        ("OBJECT_ID", object_id),
+        ("EPOCH", Value::from(epoch)),
+        ("MEAN_MOTION", Value::from(tle.mean_motion)),
+        ("ECCENTRICITY", Value::from(tle.eccentricity)),
+        ("INCLINATION", Value::from(tle.inclination)),
+        ("RA_OF_ASC_NODE", Value::from(tle.right_ascension_of_ascending_node)),
+        ("ARG_OF_PERICENTER", Value::from(tle.argument_of_perigee)),
+        ("MEAN_ANOMALY", Value::from(tle.mean_anomaly)),
+        ("EPHEMERIS_TYPE", Value::from(tle.ephemeris_type)),
+        ("CLASSIFICATION_TYPE", Value::from(tle.classification.to_string())),
+        ("NORAD_CAT_ID", Value::from(tle.satellite_catalog_number)),
+        ("ELEMENT_SET_NO", Value::from(tle.element_set_number)),
+        ("REV_AT_EPOCH", Value::from(tle.revolution_number_at_epoch)),
+        ("BSTAR", Value::from(tle.bstar)),
+        ("MEAN_MOTION_DOT", Value::from(tle.first_derivative_of_mean_motion / 2.0)),
+        ("MEAN_MOTION_DDOT", Value::from(tle.second_derivative_of_mean_motion / 6.0)),
+    ];
+
+    // Write the object
+    let members: Vec<String> = fields.iter().map(|(field, value)| format!("{}:{}", Value::from(*field), value)).collect();
+    return Ok(format!("{{{}}}", members.join(",")));
+}
+
+/// Read a text field of an OMM object.
+///
+/// # Arguments
+/// * `omm` - The OMM object
+/// * `field` - The OMM field name
+///
+/// # Returns
+/// * `Option<String>` - The text of the field (numbers are written as text), or `None` if it is absent or null
+fn omm_text(omm: &Value, field: &str) -> Option<String> {
+    return match omm.get(field)? {
+        Value::String(text) => Some(text.trim().to_string()),
+        Value::Null => None,
+        value => Some(value.to_string()),
+    };
+}
+
+/// Read a required numeric field of an OMM object, accepting JSON numbers and numeric strings.
+///
+/// # Arguments
+/// * `omm` - The OMM object
+/// * `field` - The OMM field name
+///
+/// # Returns
+/// * `Result<T, TleError>` - The parsed field, or `TleError::FieldParse` carrying the offending value (empty if absent)
+fn omm_number<T: core::str::FromStr>(omm: &Value, field: &'static str) -> Result<T, TleError> {
+    let raw = omm_text(omm, field).unwrap_or_default();
+    return raw.parse::<T>().map_err(|_| TleError::FieldParse { field: field, columns: None, raw: raw });
+}
+
+/// Read an optional numeric field of an OMM object, accepting JSON numbers and numeric strings.
+///
+/// # Arguments
+/// * `omm` - The OMM object
+/// * `field` - The OMM field name
+/// * `default` - The value used when the field is absent
+///
+/// # Returns
+/// * `Result<T, TleError>` - The parsed field, or `TleError::FieldParse` carrying the offending value
+fn omm_number_or<T: core::str::FromStr>(omm: &Value, field: &'static str, default: T) -> Result<T, TleError> {
+    if omm_text(omm, field).is_none() {
+        return Ok(default);
+    }
+    return omm_number(omm, field);
+}
+
+/// Convert a COSPAR object ID into a TLE international designator (e.g. `1998-067A` = `98067A`).
+///
+/// # Arguments
+/// * `object_id` - The COSPAR object ID, a four digit launch year, a three digit launch number and an optional piece
+///
+/// # Returns
+/// * `Option<String>` - The international designator, or `None` if the object ID is malformed
+fn parse_object_id(object_id: &str) -> Option<String> {
+    let (year, rest) = object_id.split_once('-')?;
+    if year.len() != 4 || rest.len() < 3 || !year.chars().chain(rest.get(..3)?.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    return Some(format!("{}{}", &year[2..], rest));
+}
+
+/// Parse an ISO-8601 UTC timestamp (e.g. `2008-09-20T12:25:40.104192`) into a [`DateTime`].
+///
+/// # Arguments
+/// * `timestamp` - The timestamp, with an optional fractional second and an optional trailing `Z`
+///
+/// # Returns
+/// * `Option<DateTime>` - The datetime (in UTC), or `None` if the timestamp is malformed
+fn parse_iso8601(timestamp: &str) -> Option<DateTime> {
+    let timestamp = timestamp.strip_suffix('Z').unwrap_or(timestamp);
+    let (date, time) = timestamp.split_once('T')?;
+
+    // Date components
+    let mut date_parts = date.split('-');
+    let year = date_parts.next()?.parse::<i32>().ok()?;
+    let month = date_parts.next()?.parse::<i32>().ok()?;
+    let day = date_parts.next()?.parse::<i32>().ok()?;
+
+    // Time components
+    let mut time_parts = time.split(':');
+    let hour = time_parts.next()?.parse::<i32>().ok()?;
+    let minute = time_parts.next()?.parse::<i32>().ok()?;
+    let second = time_parts.next()?.parse::<f64>().ok()?;
+
+    // Reject trailing components and out of range values
+    if date_parts.next().is_some() || time_parts.next().is_some() {
+        return None;
+    }
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || !(0..24).contains(&hour) || !(0..60).contains(&minute) || !(0.0..61.0).contains(&second) {
+        return None;
+    }
+
+    return Some(DateTime { year: year, month: month, day: day, hour: hour, minute: minute, second: second, timezone: Timezone::UTC });
+}
+
+/// Format a TLE epoch as an ISO-8601 UTC timestamp (e.g. `2008-09-20T12:25:40.104192`).
+///
+/// # Arguments
+/// * `epoch_year` - The four digit epoch year
+/// * `epoch_day` - The epoch day of year, with the time of day as a fraction
+///
+/// # Returns
+/// * `Option<String>` - The timestamp at microsecond resolution, or `None` if the day is outside of the year
+fn format_iso8601(epoch_year: i32, epoch_day: f64) -> Option<String> {
+    // Find the calendar date from the whole day of year
+    let day_int = epoch_day.floor();
+    let date = dayofyr2utc(epoch_year, day_int).ok()?;
+
+    // Split the fractional day into whole microseconds, staying within the day
+    let microseconds = (((epoch_day - day_int) * 86400e6).round() as i64).min(86_400_000_000 - 1);
+    let hour = microseconds / 3_600_000_000;
+    let minute = (microseconds / 60_000_000) % 60;
+    let second = (microseconds / 1_000_000) % 60;
+    let microsecond = microseconds % 1_000_000;
+
+    return Some(format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}", date.year, date.month, date.day, hour, minute, second, microsecond));
+}
+
+// ----------
+// Unit Tests
+// ----------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tle::from_lines;
+
+    /// The OMM of the ISS as served by Celestrak
+    const ISS_OMM: &str = r#"[{
+        "OBJECT_NAME": "ISS (ZARYA)",
+        "OBJECT_ID": "1998-067A",
+        "EPOCH": "2008-09-20T12:25:40.104192",
+        "MEAN_MOTION": 15.72125391,
+        "ECCENTRICITY": 0.0006703,
+        "INCLINATION": 51.6416,
+        "RA_OF_ASC_NODE": 247.4627,
+        "ARG_OF_PERICENTER": 130.536,
+        "MEAN_ANOMALY": 325.0288,
+        "EPHEMERIS_TYPE": 0,
+        "CLASSIFICATION_TYPE": "U",
+        "NORAD_CAT_ID": 25544,
+        "ELEMENT_SET_NO": 292,
+        "REV_AT_EPOCH": 56353,
+        "BSTAR": -1.1606e-5,
+        "MEAN_MOTION_DOT": -2.182e-5,
+        "MEAN_MOTION_DDOT": 0
+    }]"#;
+
+    #[test]
+    fn test_from_omm_json_iss() {
+        // Parse the OMM and the equivalent TLE
+        let tle = from_omm_json(ISS_OMM).unwrap();
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let expected = from_lines(tle_line1, tle_line2, Some("ISS (ZARYA)")).unwrap();
+
+        // Compare every field
+        assert_eq!(tle.common_name, expected.common_name);
+        assert_eq!(tle.satellite_catalog_number, expected.satellite_catalog_number);
+        assert_eq!(tle.classification, expected.classification);
+        assert_eq!(tle.international_designator, expected.international_designator);
+        assert_eq!(tle.epoch_year, expected.epoch_year);
+        assert!((tle.epoch_day - expected.epoch_day).abs() < 1e-9, "Epoch day: expected {}, got {}", expected.epoch_day, tle.epoch_day);
+        assert!((tle.first_derivative_of_mean_motion - expected.first_derivative_of_mean_motion).abs() < 1e-15);
+        assert_eq!(tle.second_derivative_of_mean_motion, expected.second_derivative_of_mean_motion);
+        assert!((tle.bstar - expected.bstar).abs() < 1e-15);
+        assert_eq!(tle.ephemeris_type, expected.ephemeris_type);
+        assert_eq!(tle.element_set_number, expected.element_set_number);
+        assert_eq!(tle.inclination, expected.inclination);
+        assert_eq!(tle.right_ascension_of_ascending_node, expected.right_ascension_of_ascending_node);
+        assert_eq!(tle.eccentricity, expected.eccentricity);
+        assert_eq!(tle.argument_of_perigee, expected.argument_of_perigee);
+        assert_eq!(tle.mean_anomaly, expected.mean_anomaly);
+        assert_eq!(tle.mean_motion, expected.mean_motion);
+        assert_eq!(tle.revolution_number_at_epoch, expected.revolution_number_at_epoch);
+    }
+
+    #[test]
+    fn test_from_omm_json_strings() {
+        // Space-Track writes every value as a string
+        let omm = r#"{"EPOCH": "2008-09-20T12:25:40.104192Z", "MEAN_MOTION": "15.72125391", "ECCENTRICITY": "0.0006703",
+            "INCLINATION": "51.6416", "RA_OF_ASC_NODE": "247.4627", "ARG_OF_PERICENTER": "130.5360",
+            "MEAN_ANOMALY": "325.0288", "NORAD_CAT_ID": "25544", "BSTAR": "-0.000011606"}"#;
+        let tle = from_omm_json(omm).unwrap();
+        assert_eq!(tle.satellite_catalog_number, 25544);
+        assert_eq!(tle.mean_motion, 15.72125391);
+        assert_eq!(tle.bstar, -0.000011606);
+        assert_eq!(tle.classification, 'U');
+        assert!(tle.common_name.is_empty());
+    }
+
+    #[test]
+    fn test_from_omm_json_errors() {
+        // Not JSON, or not a single object
+        assert_eq!(from_omm_json("ISS (ZARYA)"), Err(TleError::InvalidJson));
+        assert_eq!(from_omm_json("[]"), Err(TleError::InvalidJson));
+        assert_eq!(from_omm_json("15.7"), Err(TleError::InvalidJson));
+
+        // Missing and malformed fields
+        let omm = ISS_OMM.replace("\"MEAN_MOTION\": 15.72125391,", "");
+        assert_eq!(from_omm_json(&omm), Err(TleError::FieldParse { field: "MEAN_MOTION", columns: None, raw: String::new() }));
+        let omm = ISS_OMM.replace("2008-09-20T12:25:40.104192", "2008-09-20 12:25:40");
+        assert_eq!(from_omm_json(&omm), Err(TleError::FieldParse { field: "EPOCH", columns: None, raw: "2008-09-20 12:25:40".to_string() }));
+        let omm = ISS_OMM.replace("1998-067A", "98067A");
+        assert_eq!(from_omm_json(&omm), Err(TleError::FieldParse { field: "OBJECT_ID", columns: None, raw: "98067A".to_string() }));
+        let omm = ISS_OMM.replace("1998-067A", "1998-06\u{e9}A");
+        assert_eq!(from_omm_json(&omm), Err(TleError::FieldParse { field: "OBJECT_ID", columns: None, raw: "1998-06\u{e9}A".to_string() }));
+    }
+
+    #[test]
+    fn test_to_omm_json_round_trip() {
+        // Parse a TLE, write it as OMM and read it back
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let expected = from_lines(tle_line1, tle_line2, Some("ISS (ZARYA)")).unwrap();
+        let omm = to_omm_json(&expected).unwrap();
+        let tle = from_omm_json(&omm).unwrap();
+
+        // The OMM uses the CCSDS units and field names
+        let document: Value = serde_json::from_str(&omm).unwrap();
+        assert_eq!(document["OBJECT_ID"], "1998-067A");
+        assert_eq!(document["EPOCH"], "2008-09-20T12:25:40.104192");
+        assert_eq!(document["MEAN_MOTION"], 15.72125391);
+        assert_eq!(document["ECCENTRICITY"], 0.0006703);
+        assert_eq!(document["MEAN_MOTION_DOT"], -2.182e-5);
+
+        // Every element survives the round trip
+        assert_eq!(tle.common_name, expected.common_name);
+        assert_eq!(tle.satellite_catalog_number, expected.satellite_catalog_number);
+        assert_eq!(tle.classification, expected.classification);
+        assert_eq!(tle.international_designator, expected.international_designator);
+        assert_eq!(tle.epoch_year, expected.epoch_year);
+        assert!((tle.epoch_day - expected.epoch_day).abs() < 1e-9, "Epoch day: expected {}, got {}", expected.epoch_day, tle.epoch_day);
+        assert_eq!(tle.first_derivative_of_mean_motion, expected.first_derivative_of_mean_motion);
+        assert_eq!(tle.second_derivative_of_mean_motion, expected.second_derivative_of_mean_motion);
+        assert_eq!(tle.bstar, expected.bstar);
+        assert_eq!(tle.ephemeris_type, expected.ephemeris_type);
+        assert_eq!(tle.element_set_number, expected.element_set_number);
+        assert_eq!(tle.inclination, expected.inclination);
+        assert_eq!(tle.right_ascension_of_ascending_node, expected.right_ascension_of_ascending_node);
+        assert_eq!(tle.eccentricity, expected.eccentricity);
+        assert_eq!(tle.argument_of_perigee, expected.argument_of_perigee);
+        assert_eq!(tle.mean_anomaly, expected.mean_anomaly);
+        assert_eq!(tle.mean_motion, expected.mean_motion);
+        assert_eq!(tle.revolution_number_at_epoch, expected.revolution_number_at_epoch);
+
+        // A blank designator is written as null and an invalid epoch is an error
+        let tle = Tle { international_designator: String::new(), ..expected.clone() };
+        let document: Value = serde_json::from_str(&to_omm_json(&tle).unwrap()).unwrap();
+        assert!(document["OBJECT_ID"].is_null());
+
+        // A designator without a piece survives the round trip
+        let tle = Tle { international_designator: "98067".to_string(), ..expected.clone() };
+        let omm = to_omm_json(&tle).unwrap();
+        let document: Value = serde_json::from_str(&omm).unwrap();
+        assert_eq!(document["OBJECT_ID"], "1998-067");
+        assert_eq!(from_omm_json(&omm).unwrap().international_designator, "98067");
+        let tle = Tle { epoch_day: 367., ..expected };
+        assert_eq!(to_omm_json(&tle), Err(TleError::OutOfRange { field: "epoch_day", value: 367. }));
+    }
+
+    #[test]
+    fn test_format_iso8601() {
+        assert_eq!(format_iso8601(2024, 60.5).unwrap(), "2024-02-29T12:00:00.000000");
+        assert_eq!(format_iso8601(2023, 1.99999999).unwrap(), "2023-01-01T23:59:59.999136");
+        assert!(format_iso8601(2023, 366.).is_none());
+    }
+
+    #[test]
+    fn test_parse_iso8601() {
+        let datetime = parse_iso8601("2024-02-29T23:59:30.25Z").unwrap();
+        assert_eq!((datetime.year, datetime.month, datetime.day, datetime.hour, datetime.minute), (2024, 2, 29, 23, 59));
+        assert_eq!(datetime.second, 30.25);
+        assert!(parse_iso8601("2024-13-01T00:00:00").is_none());
+        assert!(parse_iso8601("2024-01-01").is_none());
+        assert!(parse_iso8601("2024-01-01T00:00:00:00").is_none());
+    }
+}
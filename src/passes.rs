@@ -0,0 +1,388 @@
+// Module for predicting satellite passes over a ground observer
+
+// ------------------
+// External Libraries
+// ------------------
+
+// ------------------
+// Internal Libraries
+// ------------------
+use crate::common::StateVector;
+use crate::coordinates::{look_angles, teme_to_ecef};
+use crate::math::Deg;
+use crate::sgp4::{calc_theta_g, propagate, PropagationError, Sgp4};
+#[cfg(feature = "std")]
+use crate::sgp4::propagate_range;
+
+// -------
+// Structs
+// -------
+
+/// A ground observer at a fixed geodetic position
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Observer {
+    /// Geodetic latitude
+    pub lat: Deg,
+
+    /// Longitude
+    pub lon: Deg,
+
+    /// Altitude above the reference ellipsoid \[km\]
+    pub alt: f64,
+}
+
+/// A satellite pass above an observer's elevation mask
+///
+/// The acquisition (AOS) and loss (LOS) of signal are the times the satellite rises above and sets below the mask, and
+/// the time of closest approach (TCA) is the time of maximum elevation.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Pass {
+    /// Acquisition of signal \[min since epoch\]
+    pub aos_min: f64,
+
+    /// Time of closest approach (maximum elevation) \[min since epoch\]
+    pub tca_min: f64,
+
+    /// Loss of signal \[min since epoch\]
+    pub los_min: f64,
+
+    /// Maximum elevation during the pass \[deg\]
+    pub max_elevation_deg: f64,
+
+    /// The pass was already in progress at the start of the search, so `aos_min` is the search start
+    pub aos_open: bool,
+
+    /// The pass had not finished at the end of the search, so `los_min` is the search stop
+    pub los_open: bool,
+}
+
+// ---------
+// Constants
+// ---------
+
+/// The time resolution of the refined AOS, LOS and TCA times \[min\]
+#[cfg(feature = "std")]
+const PASS_TIME_TOLERANCE: f64 = 1e-3;
+
+// ---------
+// Functions
+// ---------
+
+/// Calculate the elevation of a satellite above an observer's horizon
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `observer` - The ground observer
+/// * `minutes_since_epoch` - The time since the TLE epoch \[min\]
+///
+/// # Returns
+/// * `Result<f64, PropagationError>` - The elevation \[deg\]
+///
+/// # Errors
+/// Returns the `PropagationError` of [`propagate`] if the SGP4 theory fails at the requested time.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::init_sgp4;
+/// use Rusty_SGP4::math::Deg;
+/// use Rusty_SGP4::passes::{calc_elevation, Observer};
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Calculate the elevation from Boulder, Colorado at the epoch
+/// let sgp4 = init_sgp4(&tle, None);
+/// let observer = Observer { lat: Deg(40.015), lon: Deg(-105.27), alt: 1.655 };
+/// let el = calc_elevation(&sgp4, &observer, 0.).unwrap();
+/// assert!((-90. ..=90.).contains(&el));
+/// ```
+pub fn calc_elevation(sgp4: &Sgp4, observer: &Observer, minutes_since_epoch: f64) -> Result<f64, PropagationError> {
+    let state = propagate(sgp4, minutes_since_epoch)?;
+    return calc_state_elevation(sgp4, observer, &state);
+}
+
+/// Calculate the elevation of a propagated satellite state above an observer's horizon
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `observer` - The ground observer
+/// * `state` - The TEME state of the satellite
+///
+/// # Returns
+/// * `Result<f64, PropagationError>` - The elevation \[deg\] at the time of the state
+///
+/// # Errors
+/// Returns `PropagationError::CoordinateFrameMismatch` if the state is not in TEME coordinates.
+fn calc_state_elevation(sgp4: &Sgp4, observer: &Observer, state: &StateVector) -> Result<f64, PropagationError> {
+    // Rotate the satellite into the Earth-fixed frame
+    let gmst = calc_theta_g(sgp4.jd0, sgp4.jdfrac0 + state.time_min / 1440.); // [rad]
+    let sat_ecef = teme_to_ecef(state, gmst, &sgp4.wgs)?;
+
+    // Calculate the elevation from the observer
+    let (_az, el, _range) = look_angles(sat_ecef.position, observer.lat, observer.lon, observer.alt, &sgp4.wgs);
+
+    return Ok(el.0);
+}
+
+/// Find the passes of a satellite above an observer's elevation mask
+///
+/// The elevation is sampled every `step_min` minutes (and at `stop_min`) to find the mask crossings, which are then
+/// refined by bisection. The time of closest approach is refined by a golden-section search around the highest sample.
+/// The step must be short compared to a pass (a minute for low Earth orbit) or short passes may be missed.
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `observer` - The ground observer
+/// * `start_min` - The start of the search since the TLE epoch \[min\]
+/// * `stop_min` - The end of the search since the TLE epoch \[min\]
+/// * `step_min` - The time between elevation samples \[min\]
+/// * `elevation_mask` - The minimum elevation for the satellite to be visible \[deg\]
+///
+/// # Returns
+/// * `Result<Vec<Pass>, PropagationError>` - The passes in chronological order. A pass in progress at `start_min` has
+///   `aos_open` set and one that has not finished at `stop_min` has `los_open` set.
+///
+/// # Errors
+/// Returns the `PropagationError` of [`propagate_range`] if the time range or step is invalid, or of [`propagate`] if
+/// the SGP4 theory fails during the search.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::init_sgp4;
+/// use Rusty_SGP4::math::Deg;
+/// use Rusty_SGP4::passes::{find_passes, Observer};
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Find the passes over Boulder, Colorado during the first day
+/// let sgp4 = init_sgp4(&tle, None);
+/// let observer = Observer { lat: Deg(40.015), lon: Deg(-105.27), alt: 1.655 };
+/// let passes = find_passes(&sgp4, &observer, 0., 1440., 1., 10.).unwrap();
+/// for pass in passes {
+///     assert!(pass.aos_min <= pass.tca_min && pass.tca_min <= pass.los_min);
+///     assert!(pass.max_elevation_deg >= 10.);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn find_passes(sgp4: &Sgp4, observer: &Observer, start_min: f64, stop_min: f64, step_min: f64, elevation_mask: f64) -> Result<Vec<Pass>, PropagationError> {
+    // Sample the search window, including its end
+    let states = propagate_range(sgp4, start_min, stop_min, step_min)?;
+    let mut times: Vec<f64> = states.iter().map(|(t, _)| *t).collect();
+    let mut elevations: Vec<f64> = states.iter().map(|(_, state)| calc_state_elevation(sgp4, observer, state)).collect::<Result<_, _>>()?;
+    if times.last().is_some_and(|&t| t < stop_min) {
+        times.push(stop_min);
+        elevations.push(calc_elevation(sgp4, observer, stop_min)?);
+    }
+
+    // The elevation above the mask, positive while the satellite is visible
+    let height = |t: f64| calc_elevation(sgp4, observer, t).map(|el| el - elevation_mask); // [deg]
+
+    let mut passes = Vec::new();
+    let mut current: Option<Pass> = None;
+    let mut best = 0; // index of the highest sample of the current pass
+    for i in 0..times.len() {
+        let visible = elevations[i] >= elevation_mask;
+        match (current.as_mut(), visible) {
+            // A pass is already in progress at the start of the search
+            (None, true) if i == 0 => {
+                current = Some(Pass { aos_min: times[0], aos_open: true, ..Pass::default() });
+                best = 0;
+            }
+
+            // The satellite rises, refine the AOS between the samples
+            (None, true) => {
+                let aos_min = bisect_crossing(&height, times[i - 1], times[i])?;
+                current = Some(Pass { aos_min: aos_min, ..Pass::default() });
+                best = i;
+            }
+
+            // The satellite stays up, track the highest sample
+            (Some(_), true) => {
+                if elevations[i] > elevations[best] {
+                    best = i;
+                }
+            }
+
+            // The satellite sets, refine the LOS between the samples and close the pass
+            (Some(pass), false) => {
+                pass.los_min = bisect_crossing(&height, times[i - 1], times[i])?;
+                refine_closest_approach(sgp4, observer, pass, &times, best)?;
+                passes.push(*pass);
+                current = None;
+            }
+
+            (None, false) => {}
+        }
+    }
+
+    // A pass that has not finished at the end of the search
+    if let Some(mut pass) = current {
+        pass.los_min = times[times.len() - 1];
+        pass.los_open = true;
+        refine_closest_approach(sgp4, observer, &mut pass, &times, best)?;
+        passes.push(pass);
+    }
+
+    return Ok(passes);
+}
+
+/// Find the time a function changes sign between two times by bisection
+///
+/// # Arguments
+/// * `f` - The function, with opposite signs at `t_lo` and `t_hi`
+/// * `t_lo` - The start of the bracket \[min\]
+/// * `t_hi` - The end of the bracket \[min\]
+///
+/// # Returns
+/// * `Result<f64, PropagationError>` - The crossing time, within `PASS_TIME_TOLERANCE` \[min\]
+#[cfg(feature = "std")]
+fn bisect_crossing(f: &dyn Fn(f64) -> Result<f64, PropagationError>, t_lo: f64, t_hi: f64) -> Result<f64, PropagationError> {
+    let mut t_lo = t_lo;
+    let mut t_hi = t_hi;
+    let positive_lo = f(t_lo)? >= 0.;
+    while t_hi - t_lo > PASS_TIME_TOLERANCE {
+        let t_mid = 0.5 * (t_lo + t_hi);
+        if (f(t_mid)? >= 0.) == positive_lo {
+            t_lo = t_mid;
+        } else {
+            t_hi = t_mid;
+        }
+    }
+    return Ok(0.5 * (t_lo + t_hi));
+}
+
+/// Refine the time of closest approach of a pass with a golden-section search around its highest sample
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `observer` - The ground observer
+/// * `pass` - The pass, with its AOS and LOS set, updated with the TCA and maximum elevation
+/// * `times` - The sample times \[min\]
+/// * `best` - The index of the highest sample of the pass
+///
+/// # Returns
+/// * `Result<(), PropagationError>` - The error of [`propagate`] if the SGP4 theory fails during the search
+#[cfg(feature = "std")]
+fn refine_closest_approach(sgp4: &Sgp4, observer: &Observer, pass: &mut Pass, times: &[f64], best: usize) -> Result<(), PropagationError> {
+    // Bracket the maximum by the neighboring samples, limited to the pass
+    let mut t_lo = times[best.saturating_sub(1)].max(pass.aos_min); // [min]
+    let mut t_hi = times[(best + 1).min(times.len() - 1)].min(pass.los_min); // [min]
+
+    // Shrink the bracket by the golden ratio, keeping the higher interior point
+    let inv_phi = (5_f64.sqrt() - 1.) / 2.;
+    let mut t_1 = t_hi - inv_phi * (t_hi - t_lo);
+    let mut t_2 = t_lo + inv_phi * (t_hi - t_lo);
+    let mut el_1 = calc_elevation(sgp4, observer, t_1)?;
+    let mut el_2 = calc_elevation(sgp4, observer, t_2)?;
+    while t_hi - t_lo > PASS_TIME_TOLERANCE {
+        if el_1 > el_2 {
+            t_hi = t_2;
+            t_2 = t_1;
+            el_2 = el_1;
+            t_1 = t_hi - inv_phi * (t_hi - t_lo);
+            el_1 = calc_elevation(sgp4, observer, t_1)?;
+        } else {
+            t_lo = t_1;
+            t_1 = t_2;
+            el_1 = el_2;
+            t_2 = t_lo + inv_phi * (t_hi - t_lo);
+            el_2 = calc_elevation(sgp4, observer, t_2)?;
+        }
+    }
+
+    // Keep the highest sample instead if the search did not improve on it, as at the edge of an open pass
+    pass.tca_min = 0.5 * (t_lo + t_hi);
+    pass.max_elevation_deg = calc_elevation(sgp4, observer, pass.tca_min)?;
+    let el_best = calc_elevation(sgp4, observer, times[best])?; // [deg]
+    if el_best > pass.max_elevation_deg {
+        pass.tca_min = times[best];
+        pass.max_elevation_deg = el_best;
+    }
+
+    return Ok(());
+}
+
+// ----------
+// Unit Tests
+// ----------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::WGS72;
+    use crate::coordinates::teme_to_geodetic;
+    use crate::sgp4::init_sgp4;
+    use crate::tle::from_lines;
+
+    /// Build an observer at the sub-satellite point of the ISS at a time since the epoch
+    fn iss_observer(sgp4: &Sgp4, minutes_since_epoch: f64) -> Observer {
+        let state = propagate(sgp4, minutes_since_epoch).unwrap();
+        let gmst = calc_theta_g(sgp4.jd0, sgp4.jdfrac0 + minutes_since_epoch / 1440.);
+        let (lat, lon, _alt) = teme_to_geodetic(state.position, gmst, &WGS72);
+        return Observer { lat: Deg(lat), lon: Deg(lon), alt: 0. };
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_find_passes_iss() {
+        // Define the ISS TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // A mid-latitude station that the ISS passes directly over 35 minutes after the epoch
+        let observer = iss_observer(&sgp4, 35.);
+        assert!(observer.lat.0.abs() > 30. && observer.lat.0.abs() < 52.);
+
+        // The overhead pass is found, with refined crossings and closest approach
+        let passes = find_passes(&sgp4, &observer, 15., 55., 1., 10.).unwrap();
+        assert_eq!(passes.len(), 1);
+        let pass = passes[0];
+        assert!(!pass.aos_open && !pass.los_open);
+        assert!((pass.tca_min - 35.).abs() < 2. / 60.);
+        assert!(pass.max_elevation_deg > 85.);
+        assert!(pass.aos_min > 30. && pass.los_min < 40.);
+        assert!((calc_elevation(&sgp4, &observer, pass.aos_min).unwrap() - 10.).abs() < 0.1);
+        assert!((calc_elevation(&sgp4, &observer, pass.los_min).unwrap() - 10.).abs() < 0.1);
+
+        // A search starting mid-pass reports an open AOS
+        let passes = find_passes(&sgp4, &observer, 35., 55., 1., 10.).unwrap();
+        assert_eq!(passes.len(), 1);
+        assert!(passes[0].aos_open && !passes[0].los_open);
+        assert_eq!(passes[0].aos_min, 35.);
+        assert!((passes[0].los_min - pass.los_min).abs() < 2. / 60.);
+
+        // A search ending mid-pass reports an open LOS at the stop time
+        let passes = find_passes(&sgp4, &observer, 15., 34.5, 1., 10.).unwrap();
+        assert_eq!(passes.len(), 1);
+        assert!(!passes[0].aos_open && passes[0].los_open);
+        assert_eq!(passes[0].los_min, 34.5);
+        assert!((passes[0].aos_min - pass.aos_min).abs() < 2. / 60.);
+        assert_eq!(passes[0].tca_min, 34.5);
+
+        // An invalid step is reported
+        assert!(find_passes(&sgp4, &observer, 15., 55., 0., 10.).is_err());
+    }
+
+    #[test]
+    fn test_calc_elevation_iss() {
+        // Define the ISS TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // The ISS is at the zenith of its sub-satellite point and below the horizon half an orbit later
+        let observer = iss_observer(&sgp4, 35.);
+        assert!((calc_elevation(&sgp4, &observer, 35.).unwrap() - 90.).abs() < 1e-6);
+        assert!(calc_elevation(&sgp4, &observer, 80.).unwrap() < 0.);
+    }
+}
@@ -3,14 +3,19 @@
 // ------------------
 // External Libraries
 // ------------------
-use std::f64::consts::PI;
+use core::f64::consts::PI;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
 
 // ------------------
 // Internal Libraries
 // ------------------
 use crate::tle::Tle;
-use crate::time::{dayofyr2utc, utc2jday, DateTime};
-use crate::common::{Wgs, WGS72, deg2rad, calc_period, StateVector, CoordinateFrame};
+use crate::time::{utc2jday, gmst, DateTime, DateError};
+use crate::common::{Wgs, WgsModel, WGS72, deg2rad, calc_period, StateVector, CoordinateFrame};
+use crate::math::Vector3;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 // -------
 // Structs
@@ -24,6 +29,7 @@ use crate::common::{Wgs, WGS72, deg2rad, calc_period, StateVector, CoordinateFra
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
+#[derive(Debug, Clone)]
 pub struct Sgp4 {
     /// WGS model
     pub wgs: Wgs,
@@ -78,7 +84,7 @@ pub struct Sgp4 {
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
-#[derive(Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct BrouwerMeanElements {
     /// Orbital inclination \[rad\]
     pub i: f64,
@@ -101,7 +107,7 @@ pub struct BrouwerMeanElements {
     /// Mean anomaly \[rad\]
     pub m: f64,
 
-    /// Mean motion \[revs/min\]
+    /// Mean motion \[rad/min\]
     pub n: f64,
 
     /// Semi-major axis \[Earth Radii\]
@@ -119,46 +125,49 @@ pub struct BrouwerMeanElements {
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
-#[derive(Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct AtmDragParams {
     /// Perigee height \[km\]
     pub hp: f64,
 
-    /// q0 parameter of power-law density function \[Earth Radii\]
+    /// q0 parameter of power-law density function, the radius of the top of the atmosphere (120 km) \[Earth Radii\]
     pub q0: f64,
 
-    /// s parameter of power-law density function \[Earth Radii\]
+    /// s parameter of power-law density function, lowered for perigee heights below 156 km \[Earth Radii\]
     pub s: f64,
 
-    /// Zeta constant \[1 / Earth Radii\]
+    /// Zeta constant, the inverse of the semi-major axis above s \[1 / Earth Radii\]
     pub zeta: f64,
 
-    /// Eta constant \[\]
+    /// Eta constant, the eccentricity scaled by the semi-major axis and zeta \[\]
     pub eta: f64,
 
-    /// C1 constant \[\]
+    /// C1 constant, the B* scaled secular drag coefficient of the semi-major axis and mean anomaly \[1 / min\]
     pub c1: f64,
 
-    /// C2 constant \[\]
+    /// C2 constant, C1 without the B* drag term \[Earth Radii / min\]
     pub c2: f64,
 
-    /// C3 constant \[\]
+    /// C3 constant, the drag coefficient of the argument of perigee and mean anomaly (zero when e <= 1e-4) \[Earth Radii / min\]
     pub c3: f64,
 
-    /// C4 constant \[\]
+    /// C4 constant, the secular drag coefficient of the eccentricity before scaling by B* \[Earth Radii / min\]
     pub c4: f64,
 
-    /// C5 constant \[\]
+    /// C5 constant, the periodic drag coefficient of the eccentricity before scaling by B* \[Earth Radii\]
     pub c5: f64,
 
-    /// D2 constant \[\]
+    /// D2 constant, the coefficient of the squared time of the secular drag of the semi-major axis \[1 / min^2\]
     pub d2: f64,
 
-    /// D3 constant \[\]
+    /// D3 constant, the coefficient of the cubed time of the secular drag of the semi-major axis \[1 / min^3\]
     pub d3: f64,
 
-    /// D4 constant \[\]
+    /// D4 constant, the coefficient of the fourth power of time of the secular drag of the semi-major axis \[1 / min^4\]
     pub d4: f64,
+
+    /// The coefficient of the squared time of the secular drag of the right ascension of the ascending node \[rad / min^2\]
+    pub raan_drag: f64,
 }
 
 /// Earth Zonal Harmonics
@@ -169,7 +178,7 @@ pub struct AtmDragParams {
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
-#[derive(Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct EarthZonalParams {
     /// Rate of change of mean anomaly \[rad / min\]
     pub m_dot: f64,
@@ -189,14 +198,20 @@ pub struct EarthZonalParams {
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
-#[derive(Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct ThirdBodyParams {
     /// Third body orbital inclination \[rad\]
     pub i: f64,
 
-    /// Third body mean motion \[revs/min\]
+    /// Third body mean motion \[rad/min\]
     pub n: f64,
 
+    /// Third body eccentricity \[\]
+    pub e: f64,
+
+    /// Third body mean anomaly at epoch \[rad\]
+    pub m0: f64,
+
     /// Third body argument of perigee \[rad\]
     pub omega: f64,
 
@@ -280,6 +295,42 @@ pub struct ThirdBodyParams {
 
     /// Rate of change of the right ascension of the ascending node \[rad / min\]
     pub raan_dot: f64,
+
+    /// e2 long-period periodic eccentricity coefficient
+    pub e2: f64,
+
+    /// e3 long-period periodic eccentricity coefficient
+    pub e3: f64,
+
+    /// i2 long-period periodic inclination coefficient
+    pub i2: f64,
+
+    /// i3 long-period periodic inclination coefficient
+    pub i3: f64,
+
+    /// l2 long-period periodic mean longitude coefficient
+    pub l2: f64,
+
+    /// l3 long-period periodic mean longitude coefficient
+    pub l3: f64,
+
+    /// l4 long-period periodic mean longitude coefficient
+    pub l4: f64,
+
+    /// gh2 long-period periodic argument of perigee coefficient
+    pub gh2: f64,
+
+    /// gh3 long-period periodic argument of perigee coefficient
+    pub gh3: f64,
+
+    /// gh4 long-period periodic argument of perigee coefficient
+    pub gh4: f64,
+
+    /// h2 long-period periodic right ascension of the ascending node coefficient
+    pub h2: f64,
+
+    /// h3 long-period periodic right ascension of the ascending node coefficient
+    pub h3: f64,
 }
 
 /// Half day resonance effects of Earth's gravity
@@ -290,7 +341,7 @@ pub struct ThirdBodyParams {
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
-#[derive(Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct HalfDayResonanceParams {
     /// Greenwich sidereal time at epoch \[rad\]
     pub theta_g: f64,
@@ -340,7 +391,7 @@ pub struct HalfDayResonanceParams {
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
-#[derive(Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct WholeDayResonanceParams {
     /// Greenwich sidereal time at epoch \[rad\]
     pub theta_g: f64,
@@ -374,6 +425,35 @@ pub struct WholeDayResonanceParams {
 // Enums
 // -----
 
+/// Errors that can occur while propagating with SGP4
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropagationError {
+    /// The time step is zero, negative or not a number
+    InvalidStep { step: f64 },
+    /// The start time is after the stop time, or either is not finite
+    InvalidTimeRange { start: f64, stop: f64 },
+    /// The time range holds more samples than can be propagated, carrying the number of samples
+    TooManySamples { samples: f64 },
+    /// The mean or perturbed eccentricity is outside of \[0, 1) (Spacetrack error codes 1 and 3)
+    EccentricityOutOfRange { eccentricity: f64 },
+    /// The mean motion is not positive (Spacetrack error code 2)
+    MeanMotionTooLow { mean_motion: f64 },
+    /// The semi-latus rectum is negative (Spacetrack error code 4)
+    NegativeSemiLatusRectum { semi_latus_rectum: f64 },
+    /// The satellite has decayed below the surface of the Earth (Spacetrack error code 6), radius in \[km\]
+    DecayBelowSurface { radius: f64 },
+    /// Kepler's equation did not converge within the iteration limit
+    ConvergenceFailure { iterations: usize },
+    /// The TLE epoch could not be converted to a date
+    InvalidEpoch { error: DateError },
+    /// The interpolation time is outside of the interval between the states
+    InterpolationOutOfRange { time: f64, start: f64, stop: f64 },
+    /// The output buffer does not hold exactly the number of values required
+    BufferLengthMismatch { expected: usize, actual: usize },
+    /// The state vector is not in the coordinate frame the transformation expects
+    CoordinateFrameMismatch { expected: CoordinateFrame, actual: CoordinateFrame },
+}
+
 // ---------
 // Constants
 // ---------
@@ -382,13 +462,60 @@ pub struct WholeDayResonanceParams {
 ///
 /// References
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
-const XPDOTP: f64 = 229.1831180523293;
-
-/// The rotational velocity of the earth in rad/min
-///
-/// References:
-/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
-const RPTIM: f64 =  4.37526908801129966e-3;
+pub(crate) const XPDOTP: f64 = 229.1831180523293;
+
+/// The largest number of steps in a sampled time range, about 34 years at one second
+const MAX_RANGE_STEPS: usize = 1 << 30;
+
+/// The largest number of samples allocated up front for a sampled time range, beyond which the samples are allocated
+/// as they are propagated
+#[cfg(feature = "std")]
+const MAX_PREALLOCATED_SAMPLES: usize = 1 << 16;
+
+/// The convergence tolerance of the Kepler's equation solver \[rad\]
+pub(crate) const KEPLER_TOLERANCE: f64 = 1e-12;
+
+/// The time from the TLE epoch beyond which SGP4 accuracy has degraded significantly \[days\]
+const EPOCH_ACCURACY_DAYS: f64 = 14.;
+
+// ---------------
+// Implementations
+// ---------------
+
+impl Sgp4 {
+    /// Returns the secular rates of the mean anomaly, argument of perigee and right ascension of the ascending node
+    ///
+    /// These are the secular effects of the Earth's zonal harmonics (J2 and J4) found at initialization, so they match
+    /// the `mdot`, `argpdot` and `nodedot` intermediate values of the reference implementation. The mean anomaly rate
+    /// includes the Brouwer mean motion. Atmospheric drag and, for deep space satellites, the lunar and solar rates in
+    /// `lunar_params` and `solar_params` are not included.
+    ///
+    /// # Returns
+    /// * `(m_dot, omega_dot, raan_dot)` - The rates of the mean anomaly, argument of perigee and right ascension of the
+    ///   ascending node \[rad / min\]
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    /// use Rusty_SGP4::sgp4::init_sgp4;
+    ///
+    /// // Define TLE
+    /// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let sgp4 = init_sgp4(&from_lines(line1, line2, None)?, None);
+    ///
+    /// // The orbit of the ISS regresses by about 5 degrees per day
+    /// let (_m_dot, _omega_dot, raan_dot) = sgp4.secular_rates();
+    /// assert!((raan_dot.to_degrees() * 1440. + 5.).abs() < 0.2);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    ///
+    /// # References
+    /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
+    pub fn secular_rates(&self) -> (f64, f64, f64) {
+        return (self.brouwer0.n + self.zonal_params.m_dot, self.zonal_params.omega_dot, self.zonal_params.raan_dot);
+    }
+}
 
 // ---------
 // Functions
@@ -404,52 +531,108 @@ const RPTIM: f64 =  4.37526908801129966e-3;
 /// - Solar and Lunar 3rd body effects
 /// - Resonance effects of Earth's gravity
 ///
+/// The returned [`Sgp4`] owns a copy of the TLE and every time-independent coefficient, so it can be initialized once
+/// and passed by reference to [`propagate`] for any number of times.
+///
+/// Element sets whose ephemeris type is not SGP4 or SDP4 (see [`crate::tle::EphemerisType`]) are still initialized,
+/// with a warning under the `log` feature. Check [`Tle::has_sgp4_ephemeris`] to reject them without it.
+///
+/// Without a WGS model, WGS-72 is used whatever the ephemeris type. Element sets are fitted by the space surveillance
+/// network with WGS-72 constants, and the reference implementation of Vallado et al uses them too, so the mean
+/// elements are only consistent with WGS-72. WGS-84 remains available by passing `Some(&WGS84)`, but it propagates
+/// the same elements with a slightly different gravity field and drifts away from the published states.
+///
 /// # Arguments
 /// * `tle` - The Two-Line Element parameters
-/// * `wgs` - Optional, specify World Geodetic System (WGS) parameters (defaults to WGS-72, the standard for TLEs)
+/// * `wgs` - Optional, specify World Geodetic System (WGS) parameters such as `WGS72OLD`, `WGS72` or `WGS84` (defaults to WGS-72, the standard for TLEs)
 ///
 /// # Returns
 /// * [`Sgp4`] - The time-independent parameters for the SGP4 propagator
 ///
+/// # Panics
+/// Panics if the TLE epoch is not a valid date, which [`try_init_sgp4`] reports as an error instead.
+///
 /// # Examples
 /// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::common::WGS72;
+/// use Rusty_SGP4::sgp4::init_sgp4;
+///
 /// // Define TLE
-/// let tle = Tle::default();
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None)?;
 /// 
 /// // Define WGS model
 /// let wgs = WGS72;
 ///
 /// // Initialize the SGP4 propagator
 /// let sgp4 = init_sgp4(&tle, Some(&wgs));
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
 /// ```
 /// References
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
 pub fn init_sgp4(tle: &Tle, wgs: Option<&Wgs>) -> Sgp4 {
+    return try_init_sgp4(tle, wgs).expect("The TLE epoch is not a valid date");
+}
+
+/// Build an [`Sgp4`] struct for state propagation from a [`Tle`] struct, reporting an invalid epoch as an error
+///
+/// This is [`init_sgp4`] for element sets that have not been validated. [`crate::tle::from_lines`] accepts any epoch
+/// day, so an element set with an epoch day of 0 or past the end of its year parses but has no Julian date.
+///
+/// # Arguments
+/// * `tle` - The Two-Line Element parameters
+/// * `wgs` - Optional, specify World Geodetic System (WGS) parameters (defaults to WGS-72, the standard for TLEs)
+///
+/// # Returns
+/// * `Result<Sgp4, PropagationError>` - The time-independent parameters for the SGP4 propagator
+///
+/// # Errors
+/// Returns `PropagationError::InvalidEpoch` if the TLE epoch day is less than 1 or exceeds the number of days in the
+/// epoch year.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::{try_init_sgp4, PropagationError};
+///
+/// // Define a TLE with an epoch on day 464 of 2008
+/// let line1 = "1 25544U 98067A   08464.51782528 -.00002182  00000-0 -11606-4 0  2929";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // The epoch is reported rather than panicking
+/// assert!(matches!(try_init_sgp4(&tle, None), Err(PropagationError::InvalidEpoch { .. })));
+/// ```
+pub fn try_init_sgp4(tle: &Tle, wgs: Option<&Wgs>) -> Result<Sgp4, PropagationError> {
     // Use WGS72 or custom WGS models if provided
     let wgs_sgp4 = if let Some(wgs_passed) = wgs { *wgs_passed } else { WGS72 };
 
+    // Report element sets generated for a different orbit model
+    #[cfg(feature = "log")]
+    if !tle.has_sgp4_ephemeris() {
+        log::warn!("Initializing SGP4 from an element set of ephemeris type {:?}, the states may be inaccurate", tle.ephemeris_kind());
+    }
+
     // Extract TLE contents in proper units
     let i0 = deg2rad(tle.inclination); // [rad]
-    let n0_kozai = tle.mean_motion * XPDOTP; // [rad/min]
+    let n0_kozai = tle.mean_motion / XPDOTP; // [rad/min]
     let e0 = tle.eccentricity; // []
     let omega0 = deg2rad(tle.argument_of_perigee); // [rad]
     let raan0 = deg2rad(tle.right_ascension_of_ascending_node); // [rad]
     let m0 = deg2rad(tle.mean_anomaly); // [rad]
 
     // Extract TLE epoch in Julian day format
-    let datetime0 = dayofyr2utc(tle.epoch_year, tle.epoch_day).unwrap();
-    let (jd0, jdfrac0) = utc2jday(&datetime0).unwrap();
+    let datetime0 = tle.epoch_datetime().map_err(|error| PropagationError::InvalidEpoch { error: error })?;
+    let (jd0, jdfrac0) = utc2jday(&datetime0).map_err(|error| PropagationError::InvalidEpoch { error: error })?;
 
     // Recover Brouwer mean motion from Kozai mean motion (mean motion in TLE)
     let theta0 = i0.cos();
     let beta0 = (1. - e0.powi(2)).sqrt();
-    let a1 = (wgs_sgp4.ke / n0_kozai).powf(2./3.);
-    let delta1 = (3./2.) * (wgs_sgp4.k2 / a1.powf(2.)) * (3. * i0.cos().powf(2.) - 1.) / (1. - e0.powf(2.)).powf(3./2.);
-    let a2 = a1 * (1. - (1./3.) * delta1 - delta1.powf(2.) - (134./81.) * delta1.powf(3.));
-    let delta0 = (3./2.) * (wgs_sgp4.k2 / a2.powf(2.)) * (3. * i0.cos().powf(2.) - 1.) / (1. - e0.powf(2.)).powf(3./2.);
-    let n0 = n0_kozai / (1. + delta0); // [rad/min]
+    let n0 = unkozai_mean_motion(n0_kozai, e0, i0, &wgs_sgp4); // [rad/min]
     let a0 = (wgs_sgp4.ke / n0).powf(2./3.); // [Earth radii]
     let a0_km = a0 * wgs_sgp4.r_earth_eq; // [km]
     let period0 = calc_period(a0_km, wgs_sgp4.mu); // [min]
@@ -476,7 +659,7 @@ pub fn init_sgp4(tle: &Tle, wgs: Option<&Wgs>) -> Sgp4 {
 
     // Check for deep space satellite
     let mut deep_space = false;
-    if period0 >= 225. {
+    if 2. * PI / n0 >= 225. {
         deep_space = true;
     }
 
@@ -488,12 +671,12 @@ pub fn init_sgp4(tle: &Tle, wgs: Option<&Wgs>) -> Sgp4 {
     let mut half_day_resonance = false;
     let mut whole_day_resonance_params = WholeDayResonanceParams::default();
     let mut half_day_resonance_params = HalfDayResonanceParams::default();
-    if period0 >= 1200. && period0 <= 1800. {
+    if deep_space && n0 > 0.0034906585 && n0 < 0.0052359877 {
         whole_day_resonance = true;
-        whole_day_resonance_params = init_earth_gravity_resonance_wholeday(jd0, jdfrac0, &brouwer0, &zonal_params, &lunar_params, &solar_params);
-    } else if period0 >= 680. && period0 <= 760. {
+        whole_day_resonance_params = init_earth_gravity_resonance_wholeday(&wgs_sgp4, jd0, jdfrac0, &brouwer0, &zonal_params, &lunar_params, &solar_params);
+    } else if deep_space && (8.26e-3..=9.24e-3).contains(&n0) && e0 >= 0.5 {
         half_day_resonance = true;
-        half_day_resonance_params = init_earth_gravity_resonance_halfday(jd0, jdfrac0, &brouwer0, &zonal_params, &lunar_params, &solar_params);
+        half_day_resonance_params = init_earth_gravity_resonance_halfday(&wgs_sgp4, jd0, jdfrac0, &brouwer0, &zonal_params, &lunar_params, &solar_params);
     }
 
     // Construct SGP4 propagator
@@ -514,7 +697,74 @@ pub fn init_sgp4(tle: &Tle, wgs: Option<&Wgs>) -> Sgp4 {
         half_day_resonance_params: half_day_resonance_params,
     };
 
-    return sgp4;
+    return Ok(sgp4);
+}
+
+/// Build an [`Sgp4`] struct for state propagation from a [`Tle`] struct and a predefined WGS model
+///
+/// This is [`init_sgp4`] with the WGS model selected by name rather than by its constants.
+///
+/// # Arguments
+/// * `tle` - The Two-Line Element parameters
+/// * `model` - The WGS model, `WgsModel::Wgs72` being the standard for TLEs
+///
+/// # Returns
+/// * [`Sgp4`] - The time-independent parameters for the SGP4 propagator
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::common::{WgsModel, WGS84};
+/// use Rusty_SGP4::sgp4::init_sgp4_with_model;
+///
+/// // Define TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None)?;
+///
+/// // Initialize the SGP4 propagator with WGS-84
+/// let sgp4 = init_sgp4_with_model(&tle, WgsModel::Wgs84);
+/// assert_eq!(sgp4.wgs, WGS84);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+pub fn init_sgp4_with_model(tle: &Tle, model: WgsModel) -> Sgp4 {
+    return init_sgp4(tle, Some(&model.constants()));
+}
+
+/// Recover the Brouwer mean motion from the Kozai mean motion reported in a TLE
+///
+/// TLEs report the mean motion in the Kozai convention, while the SGP4 theory is developed with the Brouwer ("un-Kozai'd")
+/// mean motion. The two differ by the secular J2 perturbation of the semi-major axis, which is removed iteratively.
+///
+/// # Arguments
+/// * `n_kozai` - The Kozai mean motion \[rad/min\]
+/// * `ecc` - The eccentricity \[\]
+/// * `inc_rad` - The inclination \[rad\]
+/// * `wgs` - The WGS model
+///
+/// # Returns
+/// * `f64` - The Brouwer mean motion \[rad/min\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::WGS72;
+/// use Rusty_SGP4::sgp4::unkozai_mean_motion;
+///
+/// // Recover the Brouwer mean motion of the ISS, converting the TLE mean motion from rev/day to rad/min
+/// let n_kozai = 15.72125391 * 2. * core::f64::consts::PI / 1440.;
+/// let n = unkozai_mean_motion(n_kozai, 0.0006703, 51.6416_f64.to_radians(), &WGS72);
+/// assert!((n - 0.0685891416).abs() < 1e-10);
+/// ```
+///
+/// References
+/// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
+pub fn unkozai_mean_motion(n_kozai: f64, ecc: f64, inc_rad: f64, wgs: &Wgs) -> f64 {
+    let a1 = (wgs.ke / n_kozai).powf(2./3.);
+    let delta1 = (3./2.) * (wgs.k2 / a1.powf(2.)) * (3. * inc_rad.cos().powf(2.) - 1.) / (1. - ecc.powf(2.)).powf(3./2.);
+    let a2 = a1 * (1. - (1./3.) * delta1 - delta1.powf(2.) - (134./81.) * delta1.powf(3.));
+    let delta0 = (3./2.) * (wgs.k2 / a2.powf(2.)) * (3. * inc_rad.cos().powf(2.) - 1.) / (1. - ecc.powf(2.)).powf(3./2.);
+
+    return n_kozai / (1. + delta0);
 }
 
 /// Initialize the atmospheric drag effects
@@ -529,11 +779,22 @@ pub fn init_sgp4(tle: &Tle, wgs: Option<&Wgs>) -> Sgp4 {
 ///
 /// # Examples
 /// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::common::WGS72;
+/// use Rusty_SGP4::sgp4::{init_sgp4, init_atm_effects};
+///
 /// // Define WGS model
 /// let wgs = WGS72;
 ///
+/// // Define TLE and recover its Brouwer mean elements
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None)?;
+/// let brouwer0 = init_sgp4(&tle, Some(&wgs)).brouwer0;
+///
 /// // Initialize the atmospheric drag effects
 /// let atm_params = init_atm_effects(&wgs, &tle, &brouwer0);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
 /// ```
 ///
 /// References
@@ -549,7 +810,7 @@ pub fn init_atm_effects(wgs: &Wgs, tle: &Tle, brouwer0: &BrouwerMeanElements) ->
     let rp = brouwer0.a * (1. - brouwer0.e); // Radius of perigee [Earth Radii]
     let hp = (rp - 1.) * wgs.r_earth_eq; // Perigee height [km]
     
-    let mut s = 0.; // [Earth radii]
+    let s: f64; // [Earth radii]
     if hp >= 156. {
         s = (78. + wgs.r_earth_eq) / wgs.r_earth_eq;
     } else if hp >= 98.{
@@ -561,23 +822,27 @@ pub fn init_atm_effects(wgs: &Wgs, tle: &Tle, brouwer0: &BrouwerMeanElements) ->
     // Calculate atmospheric drag parameters
     let zeta = 1. / (brouwer0.a - s);
     let eta = brouwer0.a * brouwer0.e * zeta;
+    let psi = (1. - eta.powi(2)).abs(); // Absolute value guards against eta > 1 for highly eccentric orbits
     
-    let c2_1 = (q0 - s).powi(4) * zeta.powi(4) * brouwer0.n * (1. - eta.powi(2)).powf(-7./2.);
+    let c2_1 = (q0 - s).powi(4) * zeta.powi(4) * brouwer0.n * psi.powf(-7./2.);
     let c2_2 = brouwer0.a * (1. + (3./2.) * eta.powi(2) + 4. * brouwer0.e * eta + brouwer0.e * eta.powi(3));
-    let c2_3 = (3./2.) * (wgs.k2 * zeta / (1. - eta.powi(2))) * (-(1./2.) + (3./2.) * brouwer0.theta.powi(2)) * (8. + 24. * eta.powi(2) + 3. * eta.powi(4));
+    let c2_3 = (3./2.) * (wgs.k2 * zeta / psi) * (-(1./2.) + (3./2.) * brouwer0.theta.powi(2)) * (8. + 24. * eta.powi(2) + 3. * eta.powi(4));
     let c2 = c2_1 * (c2_2 + c2_3);
     
     let c1 = tle.bstar * c2;
-    let c3 = ((q0 - s).powf(4.) * zeta.powf(5.) * a30 * brouwer0.n * brouwer0.i.sin()) / (wgs.k2 * brouwer0.e);
+    let mut c3 = 0.;
+    if brouwer0.e > 1e-4 {
+        c3 = ((q0 - s).powi(4) * zeta.powi(5) * a30 * brouwer0.n * brouwer0.i.sin()) / (wgs.k2 * brouwer0.e);
+    }
     
-    let c4_1 = 2. * brouwer0.n * (q0 - s).powi(4) * zeta.powi(4) * brouwer0.a * brouwer0.beta.powi(2) * (1. - eta.powi(2)).powf(-7./2.);
+    let c4_1 = 2. * brouwer0.n * (q0 - s).powi(4) * zeta.powi(4) * brouwer0.a * brouwer0.beta.powi(2) * psi.powf(-7./2.);
     let c4_2 = 2. * eta * (1. + brouwer0.e*eta) + 0.5 * brouwer0.e + 0.5 * eta.powi(3);
-    let c4_3 = 2. * wgs.k2 * zeta / (brouwer0.a * (1. - eta.powi(2)));
+    let c4_3 = 2. * wgs.k2 * zeta / (brouwer0.a * psi);
     let c4_4 = 3. * (1. - 3. * brouwer0.theta.powi(2)) * (1. + 3./2. * eta.powi(2) - 2. * brouwer0.e * eta - 0.5 * brouwer0.e * eta.powi(3));
     let c4_5 = 3./4. * (1. - brouwer0.theta.powi(2)) * (2. * eta.powi(2) - brouwer0.e * eta - brouwer0.e * eta.powi(3)) * (2. * brouwer0.omega).cos();
     let c4 = c4_1 * (c4_2 - c4_3 * (c4_4 + c4_5));
     
-    let c5_1 = 2. * (q0 - s).powi(4) * zeta.powi(4) * brouwer0.a * brouwer0.beta.powi(2) * (1. - eta.powi(2)).powf(-7./2.);
+    let c5_1 = 2. * (q0 - s).powi(4) * zeta.powi(4) * brouwer0.a * brouwer0.beta.powi(2) * psi.powf(-7./2.);
     let c5_2 = 1. + 11./4. * eta * (eta + brouwer0.e) + brouwer0.e * eta.powi(3);
     let c5 = c5_1 * c5_2;
     
@@ -585,6 +850,8 @@ pub fn init_atm_effects(wgs: &Wgs, tle: &Tle, brouwer0: &BrouwerMeanElements) ->
     let d3 = 4./3. * brouwer0.a * zeta.powi(2) * (17. * brouwer0.a + s) * c1.powi(3);
     let d4 = 2./3. * brouwer0.a.powi(2) * zeta.powi(3) * (221. * brouwer0.a + 31. * s) * c1.powi(4);
 
+    let raan_drag = -(21./2.) * (brouwer0.n * wgs.k2 * brouwer0.theta / (brouwer0.a.powi(2) * brouwer0.beta.powi(2))) * c1;
+
     // Store atmospheric drag parameters
     let atm_params = AtmDragParams {
         hp: hp,
@@ -600,6 +867,7 @@ pub fn init_atm_effects(wgs: &Wgs, tle: &Tle, brouwer0: &BrouwerMeanElements) ->
         d2: d2,
         d3: d3,
         d4: d4,
+        raan_drag: raan_drag,
     };
 
     return atm_params;
@@ -616,6 +884,9 @@ pub fn init_atm_effects(wgs: &Wgs, tle: &Tle, brouwer0: &BrouwerMeanElements) ->
 ///
 /// # Examples
 /// ```rust
+/// use Rusty_SGP4::common::WGS72;
+/// use Rusty_SGP4::sgp4::{init_zonal_effects, BrouwerMeanElements};
+///
 /// // Define Brouwer mean elements at epoch
 /// let brouwer0 = BrouwerMeanElements::default();
 ///
@@ -666,9 +937,15 @@ pub fn init_zonal_effects(wgs: &Wgs, brouwer0: &BrouwerMeanElements) -> EarthZon
 ///
 /// # Examples
 /// ```rust
+/// use Rusty_SGP4::sgp4::{init_lunar_solar_effects, BrouwerMeanElements};
+///
 /// // Define Brouwer mean elements at epoch
 /// let brouwer0 = BrouwerMeanElements::default();
 ///
+/// // Define Julian date at epoch
+/// let jd0 = 2451545.0;
+/// let jdfrac0 = 0.0;
+///
 /// // Initialize the Lunar and Solar third body effects
 /// let (lunar_params, solar_params) = init_lunar_solar_effects(true, jd0, jdfrac0, &brouwer0);
 /// ```
@@ -683,46 +960,14 @@ pub fn init_lunar_solar_effects(deep_space: bool, jd0: f64, jdfrac0: f64, brouwe
         return (ThirdBodyParams::default(), ThirdBodyParams::default());
     }
 
-    // Obliquity of the ecliptic plane \[rad\]
-    let eps = deg2rad(23.4441);
-    let sin_eps = eps.sin();
-    let cos_eps = eps.cos();
-
-    // Lunar/Solar element epochs (12/31/1899 12:00:00 UTC) \[Julian date\]
+    // Find the difference in time between the Solar / Lunar epoch (12/31/1899 12:00:00 UTC) and the TLE epoch [days]
     let epoch_sm = 2415020.0;
-    
-    // Lunar constants
-    // Moon's inclination with respect to the ecliptic plane \[rad\]
-    let i_me = deg2rad(5.145396374);
-    let sin_i_me = i_me.sin();
-    let cos_i_me = i_me.cos();
-
-    // Lunar eccentricity
-    let e_m = 0.05490;
-
-    // Lunar mean motion \[rad/min\]
-    let n_m = 1.583521770e-4;
-
-    // Lunar perturbation coefficient \[rad/min\]
-    let c_m = 4.796806521e-7;
-
-    // Lunar right ascension of the ascending node (RAAN) with respect to the ecliptic plane at epoch \[rad\]
-    let raan_me0 = 4.5236020;
-
-    // Lunar right ascension of the ascending node (RAAN) with respect to the ecliptic plane time rate of change at epoch \[rad/day\]
-    let raan_me0_dot = -9.2422029e-4;
-
-    // Lunar longitude of perigee with respect to the ecliptic plane at epoch \[rad\]
-    let u_me0 = 5.8351514;
-
-    // Lunar longitude of perigee with respect to the ecliptic plane time rate of change at epoch \[rad/day\]
-    let u_me0_dot = 0.0019443680;
+    let delta_t = jd0 + jdfrac0 - epoch_sm;
 
-    // Solar constants
-    // Solar inclination \[rad\]
-    let i_s = deg2rad(23.4441);
-    let sin_i_s = i_s.sin();
-    let cos_i_s = i_s.cos();
+    // Solar constants, expressed with respect to the Earth's equator as in the reference implementation
+    // Solar inclination (obliquity of the ecliptic) sine and cosine
+    let sin_i_s = 0.39785416;
+    let cos_i_s = 0.91744867;
 
     // Solar eccentricity
     let e_s = 0.01675;
@@ -733,55 +978,60 @@ pub fn init_lunar_solar_effects(deep_space: bool, jd0: f64, jdfrac0: f64, brouwe
     // Solar right ascension of the ascending node (RAAN) \[rad\]
     let raan_s = 0.0;
 
-    // Solar argument of periapsis \[rad\]
-    let omega_s = deg2rad(281.2208);
+    // Solar argument of periapsis sine and cosine
+    let sin_omega_s = -0.98088458;
+    let cos_omega_s = 0.1945905;
 
     // Solar perturbation coefficient \[rad/min\]
-    let c_s = 2.98647972e-6;
+    let c_s = 2.9864797e-6;
 
-    // Solar mean anomaly at epoch \[rad\]
-    let m_s0 = 6.2565837;
+    // Calculate the Solar mean anomaly at TLE epoch \[rad\]
+    let m_s = (6.2565837 + 0.017201977 * delta_t) % (2.0 * PI);
+
+    // Lunar constants
+    // Lunar eccentricity
+    let e_m = 0.05490;
 
-    // Solar mean anomaly time rate of change at epoch \[rad/day\]
-    let m_s0_dot = 0.017201977;
+    // Lunar mean motion \[rad/min\]
+    let n_m = 1.5835218e-4;
 
-    // Find the difference in time between the Solar / Lunar epoch and the TLE epoch
-    let delta_t = jd0 + jdfrac0 - epoch_sm;
+    // Lunar perturbation coefficient \[rad/min\]
+    let c_m = 4.7968065e-7;
 
-    // Calculate the Lunar RAAN wrt to the ecliptic plane at TLE epoch
-    let raan_me = (raan_me0 + raan_me0_dot * delta_t).rem_euclid(2.0 * PI);
+    // Calculate the Lunar RAAN wrt to the ecliptic plane at TLE epoch \[rad\]
+    let raan_me = (4.5236020 - 9.2422029e-4 * delta_t) % (2.0 * PI);
     let sin_raan_me = raan_me.sin();
     let cos_raan_me = raan_me.cos();
 
-    // Calculate the Lunar inclination at TLE epoch (this formula is fine because lunar inclination never is negative)
-    let i_m = (cos_eps * cos_i_me - sin_eps * sin_i_me * cos_raan_me).clamp(-1.0, 1.0).acos();
-    let sin_i_m = i_m.sin();
-    let cos_i_m = i_m.cos();
-
-    // Calculate the Lunar longitude of perigee referred to the ecliptic
-    let gamma_m = u_me0 + u_me0_dot * delta_t;
+    // Calculate the Lunar inclination at TLE epoch (cos(eps) * cos(i_me) and sin(eps) * sin(i_me) folded into constants)
+    let cos_i_m = 0.91375164 - 0.03568096 * cos_raan_me;
+    let sin_i_m = (1. - cos_i_m.powi(2)).sqrt();
 
     // Calculate the Lunar RAAN \[rad\]
-    let sin_raan_m = (sin_i_me * sin_raan_me) / sin_i_m;
-    let cos_raan_m = (cos_i_me * sin_eps + cos_eps * sin_i_me * cos_raan_me) / sin_i_m;
+    let sin_raan_m = 0.089683511 * sin_raan_me / sin_i_m;
+    let cos_raan_m = (1. - sin_raan_m.powi(2)).sqrt();
     let raan_m = sin_raan_m.atan2(cos_raan_m);
 
+    // Calculate the Lunar longitude of perigee referred to the ecliptic \[rad\]
+    let gamma_m = 5.8351514 + 0.0019443680 * delta_t;
+
     // Calculate the Lunar phase shift \[rad\]
-    let sin_delta = (sin_eps * sin_raan_me) / sin_i_m;
-    let cos_delta = cos_raan_m * cos_raan_me + sin_raan_m * sin_raan_me * cos_eps;
+    let sin_delta = 0.39785416 * sin_raan_me / sin_i_m;
+    let cos_delta = cos_raan_m * cos_raan_me + 0.91744867 * sin_raan_m * sin_raan_me;
     let delta = sin_delta.atan2(cos_delta);
 
     // Calculate the Lunar argument of periapsis \[rad\]
-    let omega_m = gamma_m - raan_me + delta;
+    let omega_m = gamma_m + delta - raan_me;
+    let (sin_omega_m, cos_omega_m) = omega_m.sin_cos();
 
-    // Calculate the Solar mean anomaly \[rad\]
-    let m_s = (m_s0 + m_s0_dot * delta_t).rem_euclid(2.0 * PI);
+    // Calculate the Lunar mean anomaly at TLE epoch \[rad\]
+    let m_m = (4.7199672 + 0.22997150 * delta_t - gamma_m) % (2.0 * PI);
 
     // Calculate the Lunar secular rates
-    let lunar_params = calc_lunar_solar_secular_rates(i_m, n_m, omega_m, raan_m, c_m, brouwer0);
+    let lunar_params = calc_lunar_solar_secular_rates(sin_i_m, cos_i_m, n_m, e_m, sin_omega_m, cos_omega_m, raan_m, m_m, c_m, brouwer0);
 
     // Calculate the Solar secular rates
-    let solar_params = calc_lunar_solar_secular_rates(i_s, n_s, omega_s, raan_s, c_s, brouwer0);
+    let solar_params = calc_lunar_solar_secular_rates(sin_i_s, cos_i_s, n_s, e_s, sin_omega_s, cos_omega_s, raan_s, m_s, c_s, brouwer0);
 
     return (lunar_params, solar_params);
 }
@@ -789,39 +1039,66 @@ pub fn init_lunar_solar_effects(deep_space: bool, jd0: f64, jdfrac0: f64, brouwe
 /// Calculate the secular rates of a third body's orbital elements
 ///
 /// # Arguments
-/// * `i_x` - The third body orbital inclination \[rad\]
+/// * `sin_i_x` - The sine of the third body orbital inclination \[\]
+/// * `cos_i_x` - The cosine of the third body orbital inclination \[\]
 /// * `n_x` - The third body mean motion \[rad/min\]
-/// * `omega_x` - The third body argument of perigee \[rad\]
+/// * `e_x` - The third body eccentricity \[\]
+/// * `sin_omega_x` - The sine of the third body argument of perigee \[\]
+/// * `cos_omega_x` - The cosine of the third body argument of perigee \[\]
 /// * `raan_x` - The third body right ascension of the ascending node (RAAN) \[rad\]
+/// * `m_x` - The third body mean anomaly at epoch \[rad\]
 /// * `c_x` - The third body perturbation coefficient \[rad/min\]
 /// * `brouwer0` - The Brouwer mean elements at epoch
 ///
+/// The inclination and argument of perigee are passed as sines and cosines because the reference implementation
+/// defines the Solar values as truncated constants which are not exactly normalized.
+///
 /// # Returns
 /// * `ThirdBodyParams` - The third body parameters
 ///
 /// # Examples
 /// ```rust
+/// use Rusty_SGP4::common::deg2rad;
+/// use Rusty_SGP4::sgp4::{calc_lunar_solar_secular_rates, BrouwerMeanElements};
+///
 /// // Define Brouwer mean elements at epoch
 /// let brouwer0 = BrouwerMeanElements::default();
 ///
+/// // Define the Lunar elements
+/// let (sin_i_m, cos_i_m) = deg2rad(5.145396374).sin_cos();
+/// let n_m = 1.583521770e-4;
+/// let e_m = 0.05490;
+/// let (sin_omega_m, cos_omega_m) = (0.0, 1.0);
+/// let raan_m = 0.0;
+/// let m_m = 0.0;
+/// let c_m = 4.796806521e-7;
+///
 /// // Calculate the Lunar secular rates
-/// let lunar_params = calc_lunar_solar_secular_rates(i_m, n_m, omega_m, raan_m, c_m, &brouwer0);
+/// let lunar_params = calc_lunar_solar_secular_rates(sin_i_m, cos_i_m, n_m, e_m, sin_omega_m, cos_omega_m, raan_m, m_m, c_m, &brouwer0);
 /// ```
 ///
 /// References
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
-pub fn calc_lunar_solar_secular_rates(i_x: f64, n_x: f64, omega_x: f64, raan_x: f64, c_x: f64, brouwer0: &BrouwerMeanElements) -> ThirdBodyParams {
+#[allow(clippy::too_many_arguments)]
+pub fn calc_lunar_solar_secular_rates(
+    sin_i_x: f64,
+    cos_i_x: f64,
+    n_x: f64,
+    e_x: f64,
+    sin_omega_x: f64,
+    cos_omega_x: f64,
+    raan_x: f64,
+    m_x: f64,
+    c_x: f64,
+    brouwer0: &BrouwerMeanElements
+) -> ThirdBodyParams {
     // Precompute common quantities
     let cos_raan_diff = (brouwer0.raan - raan_x).cos();
     let sin_raan_diff = (brouwer0.raan - raan_x).sin();
-    let cos_omega_x = omega_x.cos();
-    let sin_omega_x = omega_x.sin();
     let cos_omega0 = brouwer0.omega.cos();
     let sin_omega0 = brouwer0.omega.sin();
-    let cos_i_x = i_x.cos();
-    let sin_i_x = i_x.sin();
     let cos_i0 = brouwer0.i.cos();
     let sin_i0 = brouwer0.i.sin();
 
@@ -866,21 +1143,46 @@ pub fn calc_lunar_solar_secular_rates(i_x: f64, n_x: f64, omega_x: f64, raan_x:
     
     let m_x_dot = (-c_x * n_x / brouwer0.n) * (z1 + z3 - 14. - 6. * brouwer0.e.powi(2));
     
+    // The node rate is singular for near equatorial orbits, so it is only applied between 3 and 177 degrees inclination
+    let non_equatorial = (5.2359877e-2..=PI - 5.2359877e-2).contains(&brouwer0.i);
     let mut raan_x_dot = 0.;
-    if brouwer0.i >= deg2rad(3.) {
+    if non_equatorial {
         raan_x_dot = c_x * n_x / (2. * brouwer0.n * brouwer0.beta * sin_i0) * (z21 + z23);
     }
 
     let mut omega_x_dot = c_x * n_x * brouwer0.beta / brouwer0.n * (z31 + z33 - 6.);
-    if brouwer0.i >= deg2rad(3.) {
+    if non_equatorial {
         omega_x_dot = omega_x_dot - raan_x_dot * cos_i0;
     }
 
+    // Calculate the long-period periodic coefficients
+    let s3 = c_x / brouwer0.n;
+    let s2 = -0.5 * s3 / brouwer0.beta;
+    let s4 = s3 * brouwer0.beta;
+    let s1 = -15. * brouwer0.e * s4;
+    let s6 = x2 * x3 + x1 * x4;
+    let s7 = x2 * x4 - x1 * x3;
+
+    let e2 = 2. * s1 * s6;
+    let e3 = 2. * s1 * s7;
+    let i2 = 2. * s2 * z12;
+    let i3 = 2. * s2 * (z13 - z11);
+    let l2 = -2. * s3 * z2;
+    let l3 = -2. * s3 * (z3 - z1);
+    let l4 = -2. * s3 * (-21. - 9. * brouwer0.e.powi(2)) * e_x;
+    let gh2 = 2. * s4 * z32;
+    let gh3 = 2. * s4 * (z33 - z31);
+    let gh4 = -18. * s4 * e_x;
+    let h2 = -2. * s2 * z22;
+    let h3 = -2. * s2 * (z23 - z21);
+
     // Store the 3rd body parameters
     let third_body_params = ThirdBodyParams {
-        i: i_x,
+        i: sin_i_x.atan2(cos_i_x),
         n: n_x,
-        omega: omega_x,
+        e: e_x,
+        m0: m_x,
+        omega: sin_omega_x.atan2(cos_omega_x),
         raan: raan_x,
         c: c_x,
         x1: x1,
@@ -908,6 +1210,18 @@ pub fn calc_lunar_solar_secular_rates(i_x: f64, n_x: f64, omega_x: f64, raan_x:
         m_dot: m_x_dot,
         raan_dot: raan_x_dot,
         omega_dot: omega_x_dot,
+        e2: e2,
+        e3: e3,
+        i2: i2,
+        i3: i3,
+        l2: l2,
+        l3: l3,
+        l4: l4,
+        gh2: gh2,
+        gh3: gh3,
+        gh4: gh4,
+        h2: h2,
+        h3: h3,
     };
 
     // Return 3rd body parameters
@@ -917,6 +1231,7 @@ pub fn calc_lunar_solar_secular_rates(i_x: f64, n_x: f64, omega_x: f64, raan_x:
 /// Initialize the half day resonance effects of Earth's gravity
 ///
 /// # Arguments
+/// * `wgs` - The WGS model defining the Earth's rotation rate
 /// * `jd0` - The Julian date at epoch \[days\]
 /// * `jdfrac0` - The fractional Julian date at epoch \[days\]
 /// * `brouwer0` - The Brouwer mean elements at epoch
@@ -929,6 +1244,9 @@ pub fn calc_lunar_solar_secular_rates(i_x: f64, n_x: f64, omega_x: f64, raan_x:
 ///
 /// # Examples
 /// ```rust
+/// use Rusty_SGP4::common::WGS72;
+/// use Rusty_SGP4::sgp4::{init_earth_gravity_resonance_halfday, BrouwerMeanElements, EarthZonalParams, ThirdBodyParams};
+///
 /// // Define Brouwer mean elements at epoch
 /// let brouwer0 = BrouwerMeanElements::default();
 ///
@@ -948,7 +1266,7 @@ pub fn calc_lunar_solar_secular_rates(i_x: f64, n_x: f64, omega_x: f64, raan_x:
 /// let jdfrac0 = 0.0;
 ///
 /// // Initialize half day resonance effects
-/// let half_day_resonance_params = init_earth_gravity_resonance_halfday(jd0, jdfrac0, &brouwer0, &zonal_params, &lunar_params, &solar_params);
+/// let half_day_resonance_params = init_earth_gravity_resonance_halfday(&WGS72, jd0, jdfrac0, &brouwer0, &zonal_params, &lunar_params, &solar_params);
 /// ```
 ///
 /// References
@@ -956,6 +1274,7 @@ pub fn calc_lunar_solar_secular_rates(i_x: f64, n_x: f64, omega_x: f64, raan_x:
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
 pub fn init_earth_gravity_resonance_halfday(
+    wgs: &Wgs,
     jd0: f64, 
     jdfrac0: f64, 
     brouwer0: &BrouwerMeanElements, 
@@ -979,10 +1298,10 @@ pub fn init_earth_gravity_resonance_halfday(
     let f221 = (3./2.) * sin_i0.powi(2);
     let f321 = (15./8.) * sin_i0 * (1. - 2. * cos_i0 - 3. * cos_i0.powi(2));
     let f322 = (-15./8.) * sin_i0 * (1. + 2. * cos_i0 - 3. * cos_i0.powi(2));
-    let f441 = (105./4.) * sin_i0.powi(2) * (1. + cos_i0).powi(2);
-    let f442 = (315./8.) * sin_i0.powi(4);
-    let f522 = (315./32.) * (sin_i0.powi(3) - 2. * sin_i0.powi(3) * cos_i0 - 5. * sin_i0.powi(3) * cos_i0.powi(2) + sin_i0 * ((-2./3.) + (4./3.) * cos_i0 + 2. * cos_i0.powi(2)));
-    let f523 = (105./16.) * sin_i0 * (1. + 2. * cos_i0 - 3. * cos_i0.powi(2) - (3./2.) * sin_i0.powi(2) * (1. + 2. * cos_i0 - 5. * cos_i0.powi(2)));
+    let f441 = 35. * sin_i0.powi(2) * f220;
+    let f442 = 39.375 * sin_i0.powi(4);
+    let f522 = 9.84375 * sin_i0 * (sin_i0.powi(2) * (1. - 2. * cos_i0 - 5. * cos_i0.powi(2)) + 0.33333333 * (-2. + 4. * cos_i0 + 6. * cos_i0.powi(2)));
+    let f523 = sin_i0 * (4.92187512 * sin_i0.powi(2) * (-2. - 4. * cos_i0 + 10. * cos_i0.powi(2)) + 6.56250012 * (1. + 2. * cos_i0 - 3. * cos_i0.powi(2)));
     let f542 = (945./32.) * sin_i0 * (2. - 8. * cos_i0 + cos_i0.powi(2) * (-12. + 8. * cos_i0 + 10. * cos_i0.powi(2)));
     let f543 = (945./32.) * sin_i0 * (cos_i0.powi(2) * (12. + 8. * cos_i0 - 10. * cos_i0.powi(2)) - 2. - 8. * cos_i0);
 
@@ -1000,7 +1319,7 @@ pub fn init_earth_gravity_resonance_halfday(
     if brouwer0.e <= 0.65 {
         g211 = 3.616 - 13.247 * brouwer0.e + 16.29 * brouwer0.e.powi(2);
         g310 = -19.302 + 117.39 * brouwer0.e - 228.419 * brouwer0.e.powi(2) + 156.591 * brouwer0.e.powi(3);
-        g322 = -18.9068 + 109.7927 * brouwer0.e - 214.6334 * brouwer0.e.powi(2) + 146.5816 * brouwer0.e.powi(2);
+        g322 = -18.9068 + 109.7927 * brouwer0.e - 214.6334 * brouwer0.e.powi(2) + 146.5816 * brouwer0.e.powi(3);
         g410 = -41.122 + 242.694 * brouwer0.e - 471.094 * brouwer0.e.powi(2) + 313.953 * brouwer0.e.powi(3);
         g422 = -146.407 + 841.88 * brouwer0.e - 1629.014 * brouwer0.e.powi(2) + 1083.435 * brouwer0.e.powi(3);
         g520 = -532.114 + 3017.977 * brouwer0.e - 5740.032 * brouwer0.e.powi(2) + 3708.276 * brouwer0.e.powi(3);
@@ -1010,7 +1329,7 @@ pub fn init_earth_gravity_resonance_halfday(
         g322 = -342.585 + 1554.908 * brouwer0.e - 2366.899 * brouwer0.e.powi(2) + 1215.972 * brouwer0.e.powi(3);
         g410 = -1052.797 + 4758.686 * brouwer0.e - 7193.992 * brouwer0.e.powi(2) + 3651.957 * brouwer0.e.powi(3);
         g422 = -3581.69 + 16178.11 * brouwer0.e - 24462.77 * brouwer0.e.powi(2) + 12422.52 * brouwer0.e.powi(3);
-        if brouwer0.e < 0.715 {
+        if brouwer0.e <= 0.715 {
             g520 = 1464.74 - 4664.75 * brouwer0.e + 3763.64 * brouwer0.e.powi(2);
         } else {
             g520 = -5149.66 + 29936.92 * brouwer0.e - 54087.36 * brouwer0.e.powi(2) + 31324.56 * brouwer0.e.powi(3);
@@ -1033,15 +1352,15 @@ pub fn init_earth_gravity_resonance_halfday(
     let d3222 = 3. * brouwer0.n.powi(2) / brouwer0.a.powi(3) * (c32s32 * f322 * g322);
     let d5220 = 3. * brouwer0.n.powi(2) / brouwer0.a.powi(5) * (c52s52 * f522 * g520);
     let d5232 = 3. * brouwer0.n.powi(2) / brouwer0.a.powi(5) * (c52s52 * f523 * g532);
-    let d4422 = 3. * brouwer0.n.powi(2) / brouwer0.a.powi(4) * (c44s44 * f442 * g422);
-    let d5421 = 3. * brouwer0.n.powi(2) / brouwer0.a.powi(5) * (c54s54 * f542 * g521);
-    let d5433 = 3. * brouwer0.n.powi(2) / brouwer0.a.powi(5) * (c54s54 * f543 * g533); // Typo in Hoots et al 2004
-    let d4410 = 3. * brouwer0.n.powi(2) / brouwer0.a.powi(4) * (c44s44 * f441 * g410);
+    let d4422 = 6. * brouwer0.n.powi(2) / brouwer0.a.powi(4) * (c44s44 * f442 * g422);
+    let d5421 = 6. * brouwer0.n.powi(2) / brouwer0.a.powi(5) * (c54s54 * f542 * g521);
+    let d5433 = 6. * brouwer0.n.powi(2) / brouwer0.a.powi(5) * (c54s54 * f543 * g533); // Typo in Hoots et al 2004
+    let d4410 = 6. * brouwer0.n.powi(2) / brouwer0.a.powi(4) * (c44s44 * f441 * g410);
 
     // Calculate the initial value for the auxilary variable lam0
     let theta_g = calc_theta_g(jd0, jdfrac0);
-    let lam0 = brouwer0.m + 2 * brouwer0.raan - 2 * theta_g;
-    let lam0_dot = zonal_params.m_dot + (lunar_params.m_dot + solar_params.m_dot) + 2 * zonal_params.raan_dot + 2 * (lunar_params.raan_dot + solar_params.raan_dot) - 2 * RPTIM;
+    let lam0 = (brouwer0.m + 2. * brouwer0.raan - 2. * theta_g) % (2. * PI);
+    let lam0_dot = zonal_params.m_dot + (lunar_params.m_dot + solar_params.m_dot) + 2. * zonal_params.raan_dot + 2. * (lunar_params.raan_dot + solar_params.raan_dot) - 2. * wgs.omega_earth;
 
     // Store resonance parameters
     let half_day_resonance_params = HalfDayResonanceParams {
@@ -1066,6 +1385,7 @@ pub fn init_earth_gravity_resonance_halfday(
 /// Initialize the whole day resonance effects of Earth's gravity
 ///
 /// # Arguments
+/// * `wgs` - The WGS model defining the Earth's rotation rate
 /// * `jd0` - The Julian date at epoch \[days\]
 /// * `jdfrac0` - The fractional Julian date at epoch \[days\]
 /// * `brouwer0` - The Brouwer mean elements at epoch
@@ -1078,6 +1398,9 @@ pub fn init_earth_gravity_resonance_halfday(
 ///
 /// # Examples
 /// ```rust
+/// use Rusty_SGP4::common::WGS72;
+/// use Rusty_SGP4::sgp4::{init_earth_gravity_resonance_wholeday, BrouwerMeanElements, EarthZonalParams, ThirdBodyParams};
+///
 /// // Define Brouwer mean elements at epoch
 /// let brouwer0 = BrouwerMeanElements::default();
 ///
@@ -1097,7 +1420,7 @@ pub fn init_earth_gravity_resonance_halfday(
 /// let jdfrac0 = 0.0;
 ///
 /// // Initialize whole day resonance effects
-/// let whole_day_resonance_params = init_earth_gravity_resonance_wholeday(jd0, jdfrac0, &brouwer0, &zonal_params, &lunar_params, &solar_params);
+/// let whole_day_resonance_params = init_earth_gravity_resonance_wholeday(&WGS72, jd0, jdfrac0, &brouwer0, &zonal_params, &lunar_params, &solar_params);
 /// ```
 ///
 /// References
@@ -1105,6 +1428,7 @@ pub fn init_earth_gravity_resonance_halfday(
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
 pub fn init_earth_gravity_resonance_wholeday(
+    wgs: &Wgs,
     jd0: f64, 
     jdfrac0: f64, 
     brouwer0: &BrouwerMeanElements, 
@@ -1132,7 +1456,7 @@ pub fn init_earth_gravity_resonance_wholeday(
     // Calculate functions of eccentricity
     let g200 = 1. - (5./2.) * brouwer0.e.powi(2) + (13. / 16.) * brouwer0.e.powi(4);
     let g310 = 1. + 2. * brouwer0.e.powi(2);
-    let g300 = 1. - 6. * brouwer0.e.powi(2) + (423. / 64.) * brouwer0.e.powi(4);
+    let g300 = 1. - 6. * brouwer0.e.powi(2) + 6.60937 * brouwer0.e.powi(4);
 
     // Calculate coefficients of the resonance terms
     let delta1 = (3. * brouwer0.n.powi(2) / brouwer0.a.powi(3)) * f311 * g310 * q31;
@@ -1141,9 +1465,9 @@ pub fn init_earth_gravity_resonance_wholeday(
 
     // Calculate the initial value for the auxilary variable lam0
     let theta_g = calc_theta_g(jd0, jdfrac0);
-    let lam0 = brouwer0.m + brouwer0.raan + brouwer0.omega - theta_g;
+    let lam0 = (brouwer0.m + brouwer0.raan + brouwer0.omega - theta_g) % (2. * PI);
     let lam0_dot_1 = zonal_params.m_dot + (lunar_params.m_dot + solar_params.m_dot) + zonal_params.raan_dot + (lunar_params.raan_dot + solar_params.raan_dot);
-    let lam0_dot_2 = zonal_params.omega_dot + (lunar_params.omega_dot + solar_params.omega_dot) - RPTIM;
+    let lam0_dot_2 = zonal_params.omega_dot + (lunar_params.omega_dot + solar_params.omega_dot) - wgs.omega_earth;
     let lam0_dot = lam0_dot_1 + lam0_dot_2;
 
     // Store resonance parameters
@@ -1165,34 +1489,29 @@ pub fn init_earth_gravity_resonance_wholeday(
 /// Calculate the longitude of Greenwich
 ///
 /// # Arguments
-/// * `datetime` - The datetime to calculate the longitude of Greenwich for
+/// * `jd0` - The Julian date to calculate the longitude of Greenwich for \[days\]
+/// * `jdfrac0` - The fractional Julian date to calculate the longitude of Greenwich for \[days\]
 ///
 /// # Returns
 /// * `theta_g` - The longitude of Greenwich in radians
 ///
 /// # Examples
 /// ```rust
-/// // Define SGP4 parameters
-/// let sgp4 = Sgp4::default();
+/// use Rusty_SGP4::sgp4::calc_theta_g;
 ///
-/// // Define datetime to calculate the longitude of Greenwich for
-/// let datetime = DateTime::default();
+/// // Define the Julian date (J2000.0)
+/// let jd0 = 2451545.0;
+/// let jdfrac0 = 0.0;
 ///
 /// // Calculate the longitude of Greenwich
-/// let theta_g = calc_theta_g(sgp4, datetime);
+/// let theta_g = calc_theta_g(jd0, jdfrac0);
 /// ```
 ///
 /// References
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 pub fn calc_theta_g(jd0: f64, jdfrac0: f64) -> f64 {
-    // Calculate the Julian centuries since J2000.0
-    let tut1 = (jd0 + jdfrac0 - 2451545.0) / 36525.0; // [centuries]
-
-    // Calculate the Greenwich sidereal time in seconds
-    let temp = -6.2e-6 * tut1.powi(3) + 0.093104 * tut1.powi(2) + (876600.0 * 3600.0 + 8640184.812866) * tut1 + 67310.54841;  // [seconds]
-
     // Calculate the Greenwich sidereal time in radians
-    let theta_g = (deg2rad(temp / 240.0)).rem_euclid(2.0 * PI); // [radians] 360/86400 = 1/240 degrees per second
+    let theta_g = gmst(jd0 + jdfrac0); // [radians]
 
     // Return the Greenwich sidereal time in radians
     return theta_g;
@@ -1211,125 +1530,1002 @@ pub fn calc_theta_g(jd0: f64, jdfrac0: f64) -> f64 {
 ///
 /// # Examples
 /// ```rust
-/// // Define SGP4 parameters
-/// let sgp4 = Sgp4::default();
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::time::{DateTime, Timezone};
+/// use Rusty_SGP4::sgp4::{init_sgp4, sgp4_prop};
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Initialize the SGP4 propagator
+/// let sgp4 = init_sgp4(&tle, None);
 ///
 /// // Define datetime to propagate to
-/// let datetime = DateTime::default();
+/// let datetime = DateTime { year: 2008, month: 9, day: 21, hour: 0, minute: 0, second: 0., timezone: Timezone::UTC };
 ///
 /// // Propagate state vector
-/// let state_vector = sgp4_prop(sgp4, datetime);
+/// let state_vector = sgp4_prop(&sgp4, &datetime).unwrap();
 /// ```
 ///
 /// References
 /// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
-pub fn sgp4_prop(sgp4: Sgp4, datetime: DateTime) -> StateVector{
+pub fn sgp4_prop(sgp4: &Sgp4, datetime: &DateTime) -> Result<StateVector, PropagationError> {
     // Convert datetime to Julian day format
     let (jd_prop, jdfrac_prop) = utc2jday(datetime).unwrap();
 
     // Get minutes since epoch
-    let delta_t = (jd_prop + jdfrac_prop - (sgp4.jd0 + sgp4.jdfrac0)) * 1440.;
+    let delta_t = ((jd_prop - sgp4.jd0) + (jdfrac_prop - sgp4.jdfrac0)) * 1440.;
+
+    // Propagate the state vector
+    return propagate(sgp4, delta_t);
+}
+
+/// Calculate the time since the TLE epoch of a Julian date, the time argument of [`propagate`]
+///
+/// The whole and fractional days of the epoch are differenced separately to keep the precision of the fraction. Times
+/// before the epoch are negative, and SGP4 propagates backward in time as well as forward.
+///
+/// # Arguments
+/// * `tle` - The Two-Line Element parameters
+/// * `jd` - The Julian date in UTC \[days\]
+///
+/// # Returns
+/// * `Result<f64, PropagationError>` - The time since the TLE epoch \[min\]
+///
+/// # Errors
+/// Returns `PropagationError::InvalidEpoch` if the TLE epoch day is less than 1 or exceeds the number of days in the
+/// epoch year.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::minutes_since_epoch;
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Midnight before the epoch (2008-09-20 00:00 UTC) is 12:25:40 earlier
+/// let minutes = minutes_since_epoch(&tle, 2454729.5)?;
+/// assert!((minutes + 745.67).abs() < 0.01);
+/// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+/// ```
+pub fn minutes_since_epoch(tle: &Tle, jd: f64) -> Result<f64, PropagationError> {
+    let epoch = tle.epoch_datetime().map_err(|error| PropagationError::InvalidEpoch { error: error })?;
+    let (jd0, jdfrac0) = utc2jday(&epoch).map_err(|error| PropagationError::InvalidEpoch { error: error })?;
+
+    return Ok(((jd - jd0) - jdfrac0) * 1440.);
+}
+
+/// Calculate the time since the TLE epoch of a [`chrono::DateTime`] in UTC, the time argument of [`propagate`]
+///
+/// The difference is taken between `when` and [`Tle::epoch_chrono`] to the nanosecond. Times before the epoch are
+/// negative.
+///
+/// # Arguments
+/// * `tle` - The Two-Line Element parameters
+/// * `when` - The time
+///
+/// # Returns
+/// * `Result<f64, PropagationError>` - The time since the TLE epoch \[min\]
+///
+/// # Errors
+/// Returns `PropagationError::InvalidEpoch` if the TLE epoch day is less than 1 or exceeds the number of days in the
+/// epoch year.
+///
+/// # Examples
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::minutes_since_epoch_chrono;
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Midnight before the epoch is 12:25:40 earlier
+/// let minutes = minutes_since_epoch_chrono(&tle, Utc.with_ymd_and_hms(2008, 9, 20, 0, 0, 0).unwrap())?;
+/// assert!((minutes + 745.67).abs() < 0.01);
+/// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+/// ```
+#[cfg(feature = "chrono")]
+pub fn minutes_since_epoch_chrono(tle: &Tle, when: chrono::DateTime<chrono::Utc>) -> Result<f64, PropagationError> {
+    let epoch = tle.epoch_chrono().map_err(|error| PropagationError::InvalidEpoch { error: error })?;
+    let delta = when - epoch;
+
+    return Ok(delta.num_seconds() as f64 / 60. + delta.subsec_nanos() as f64 / 60e9);
+}
+
+/// Check whether a time is far enough from the TLE epoch that SGP4 accuracy has degraded significantly
+///
+/// SGP4 errors grow by a few kilometers per day away from the epoch, so element sets are usually replaced within a
+/// couple of weeks. This is the check behind the warning of [`propagate_at`], available without the `log` feature.
+///
+/// # Arguments
+/// * `minutes_since_epoch` - The time since the TLE epoch \[min\]
+///
+/// # Returns
+/// * `bool` - True if the time is more than 14 days before or after the epoch, or is not finite
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::sgp4::has_stale_epoch;
+///
+/// // A week from the epoch is still accurate, a month is not
+/// assert!(!has_stale_epoch(7. * 1440.));
+/// assert!(has_stale_epoch(-30. * 1440.));
+/// ```
+pub fn has_stale_epoch(minutes_since_epoch: f64) -> bool {
+    return minutes_since_epoch.is_nan() || minutes_since_epoch.abs() > EPOCH_ACCURACY_DAYS * 1440.;
+}
+
+/// Propagate a satellite's state vector to a [`chrono::DateTime`] in UTC
+///
+/// The minutes since the TLE epoch are computed from the difference between `when` and [`Tle::epoch_chrono`], so
+/// propagating to the epoch itself is identical to `propagate(sgp4, 0.)`. SGP4 accuracy degrades quickly away from
+/// the epoch, so with the `log` feature a warning is reported when `when` is more than 14 days from the epoch (see
+/// [`has_stale_epoch`], which callers can check with [`minutes_since_epoch_chrono`] without the `log` feature).
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `when` - The time to propagate to
+///
+/// # Returns
+/// * `Result<StateVector, PropagationError>` - The propagated position \[km\] and velocity \[km / s\] in TEME coordinates
+///
+/// # Errors
+///   - `PropagationError::InvalidEpoch` if the TLE epoch day is less than 1 or exceeds the number of days in the epoch year
+///   - The `PropagationError` of [`propagate`] if the SGP4 theory fails at `when`
+///
+/// # Examples
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::{init_sgp4, propagate_at};
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Initialize the SGP4 propagator
+/// let sgp4 = init_sgp4(&tle, None);
+///
+/// // Propagate state vector to midnight after the epoch
+/// let state_vector = propagate_at(&sgp4, Utc.with_ymd_and_hms(2008, 9, 21, 0, 0, 0).unwrap())?;
+/// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+/// ```
+#[cfg(feature = "chrono")]
+pub fn propagate_at(sgp4: &Sgp4, when: chrono::DateTime<chrono::Utc>) -> Result<StateVector, PropagationError> {
+    // Get minutes since epoch
+    let delta_t = minutes_since_epoch_chrono(&sgp4.tle, when)?; // [min]
+
+    // Report propagation far from the epoch
+    #[cfg(feature = "log")]
+    if has_stale_epoch(delta_t) {
+        log::warn!("Propagating {:.1} days from the TLE epoch, SGP4 accuracy is degraded", delta_t / 1440.);
+    }
+
+    // Propagate the state vector
+    return propagate(sgp4, delta_t);
+}
+
+/// Propagate a satellite's state vector to a list of [`chrono::DateTime`] in UTC
+///
+/// Each time is propagated independently with [`propagate_at`], so the times can be irregular and in any order, and
+/// a failure at one time does not prevent propagation to the others.
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `times` - The times to propagate to
+///
+/// # Returns
+/// * `Vec<Result<StateVector, PropagationError>>` - The propagated position \[km\] and velocity \[km / s\] in TEME
+///   coordinates, or the error of [`propagate_at`], in the same order as `times`
+///
+/// # Examples
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::{init_sgp4, propagate_times};
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Initialize the SGP4 propagator
+/// let sgp4 = init_sgp4(&tle, None);
+///
+/// // Propagate state vectors to two observation times
+/// let times = [Utc.with_ymd_and_hms(2008, 9, 21, 0, 0, 0).unwrap(), Utc.with_ymd_and_hms(2008, 9, 20, 18, 30, 0).unwrap()];
+/// let states = propagate_times(&sgp4, &times);
+/// assert_eq!(states.len(), 2);
+/// assert!(states.iter().all(|state| state.is_ok()));
+/// ```
+#[cfg(feature = "chrono")]
+pub fn propagate_times(sgp4: &Sgp4, times: &[chrono::DateTime<chrono::Utc>]) -> Vec<Result<StateVector, PropagationError>> {
+    return times.iter().map(|when| propagate_at(sgp4, *when)).collect();
+}
+
+/// Propagate a satellite's state vector over a range of times since the TLE epoch
+///
+/// The states are sampled every `step_min` minutes from `start_min` up to and including `stop_min` (when it falls on
+/// a step). Each sample time is computed from its index to avoid accumulating rounding error over long ranges.
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `start_min` - The first time since the TLE epoch \[min\]
+/// * `stop_min` - The last time since the TLE epoch \[min\]
+/// * `step_min` - The time between samples \[min\]
+///
+/// # Returns
+/// * `Result<Vec<(f64, StateVector)>, PropagationError>` - The (minutes since epoch, state vector) pairs in TEME coordinates
+///
+/// # Errors
+/// Returns `PropagationError::InvalidStep` if the step is not positive.
+/// Returns `PropagationError::InvalidTimeRange` if the start or stop time is not finite, or the start time is after
+/// the stop time.
+/// Returns `PropagationError::TooManySamples` if the range holds more than 2^30 steps.
+/// Returns the first `PropagationError` of [`propagate`] if the SGP4 theory fails at any sample.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::{init_sgp4, propagate_range};
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Initialize the SGP4 propagator
+/// let sgp4 = init_sgp4(&tle, None);
+///
+/// // Propagate one orbit in one minute steps
+/// let states = propagate_range(&sgp4, 0., 92., 1.).unwrap();
+/// assert_eq!(states.len(), 93);
+/// ```
+#[cfg(feature = "std")]
+pub fn propagate_range(sgp4: &Sgp4, start_min: f64, stop_min: f64, step_min: f64) -> Result<Vec<(f64, StateVector)>, PropagationError> {
+    // Validate the time range and calculate the number of samples
+    let n_steps = count_range_steps(start_min, stop_min, step_min)?;
+
+    // Propagate each sample
+    let mut states = Vec::with_capacity(n_steps.min(MAX_PREALLOCATED_SAMPLES) + 1);
+    for i in 0..=n_steps {
+        let minutes_since_epoch = start_min + i as f64 * step_min;
+        states.push((minutes_since_epoch, propagate(sgp4, minutes_since_epoch)?));
+    }
+
+    return Ok(states);
+}
+
+/// Validate a sampled time range and count the whole steps from its start to its stop
+///
+/// The count tolerates rounding when the range is a whole number of steps, so a range of 92 minutes in steps of 1
+/// minute has 92 steps and 93 samples.
+///
+/// # Arguments
+/// * `start_min` - The first time since the TLE epoch \[min\]
+/// * `stop_min` - The last time since the TLE epoch \[min\]
+/// * `step_min` - The time between samples \[min\]
+///
+/// # Returns
+/// * `Result<usize, PropagationError>` - The number of whole steps in the range
+///
+/// # Errors
+/// Returns `PropagationError::InvalidStep` if the step is not positive.
+/// Returns `PropagationError::InvalidTimeRange` if the start or stop time is not finite, or the start time is after
+/// the stop time.
+/// Returns `PropagationError::TooManySamples` if the range holds more than 2^30 steps.
+pub(crate) fn count_range_steps(start_min: f64, stop_min: f64, step_min: f64) -> Result<usize, PropagationError> {
+    if step_min.is_nan() || step_min <= 0. {
+        return Err(PropagationError::InvalidStep { step: step_min });
+    }
+    if !start_min.is_finite() || !stop_min.is_finite() || start_min > stop_min {
+        return Err(PropagationError::InvalidTimeRange { start: start_min, stop: stop_min });
+    }
+
+    // The step count is checked as a float, since the span can exceed the range of usize
+    let n_steps = ((stop_min - start_min) / step_min + 1e-9).floor();
+    if n_steps > MAX_RANGE_STEPS as f64 {
+        return Err(PropagationError::TooManySamples { samples: n_steps + 1. });
+    }
+
+    return Ok(n_steps as usize);
+}
+
+/// Propagate a satellite to a list of times since the TLE epoch, writing the states into a flat buffer
+///
+/// Each time fills 6 consecutive values of `out`, the position (x, y, z) \[km\] followed by the velocity
+/// (vx, vy, vz) \[km / s\] in TEME coordinates, so the buffer is a row-major `times.len()` by 6 array. Nothing is
+/// allocated, which suits bindings to C or numpy that own the memory.
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `times_min` - The times since the TLE epoch \[min\]
+/// * `out` - The buffer to write the states into, of length `6 * times_min.len()`
+///
+/// # Returns
+/// * `Result<(), PropagationError>` - Nothing, the states are written into `out`
+///
+/// # Errors
+/// Returns `PropagationError::BufferLengthMismatch` if `out` does not hold exactly 6 values per time, before any
+/// propagation.
+/// Returns the first `PropagationError` of [`propagate`] if the SGP4 theory fails at any time, leaving the rows of the
+/// earlier times written.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::{init_sgp4, propagate_grid_flat};
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+///
+/// // Propagate three times into a 3 by 6 array
+/// let times = [0., 30., 60.];
+/// let mut out = [0.; 18];
+/// propagate_grid_flat(&sgp4, &times, &mut out).unwrap();
+/// assert!(out[12..15].iter().map(|x| x * x).sum::<f64>().sqrt() > 6500.);
+/// ```
+pub fn propagate_grid_flat(sgp4: &Sgp4, times_min: &[f64], out: &mut [f64]) -> Result<(), PropagationError> {
+    // Validate the buffer length
+    if out.len() != 6 * times_min.len() {
+        return Err(PropagationError::BufferLengthMismatch { expected: 6 * times_min.len(), actual: out.len() });
+    }
+
+    // Propagate each time into its row
+    for (minutes_since_epoch, row) in times_min.iter().zip(out.chunks_exact_mut(6)) {
+        let state = propagate(sgp4, *minutes_since_epoch)?;
+        row.copy_from_slice(&[state.position.x, state.position.y, state.position.z, state.velocity.x, state.velocity.y, state.velocity.z]);
+    }
+
+    return Ok(());
+}
+
+/// Propagate a catalog of satellites to the same time since each TLE epoch, in parallel
+///
+/// Each satellite is propagated independently with [`propagate`] on the rayon global thread pool, so the work scales
+/// with the number of cores. The states are returned in the order of the catalog and are identical to propagating
+/// each satellite serially.
+///
+/// # Arguments
+/// * `catalog` - The SGP4 parameters of each satellite
+/// * `minutes_since_epoch` - The time since each TLE epoch \[min\]
+///
+/// # Returns
+/// * `Vec<Result<StateVector, PropagationError>>` - The propagated position \[km\] and velocity \[km / s\] of each
+///   satellite in TEME coordinates, or the error of [`propagate`] for that satellite
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_file;
+/// use Rusty_SGP4::sgp4::{init_sgp4, propagate_catalog_par};
+///
+/// // Initialize the SGP4 propagator of every satellite in the file
+/// let catalog: Vec<_> = from_file("assets/test.tle").iter().map(|tle| init_sgp4(tle, None)).collect();
+///
+/// // Propagate the catalog one hour past the epochs
+/// let states = propagate_catalog_par(&catalog, 60.);
+/// assert_eq!(states.len(), catalog.len());
+/// ```
+#[cfg(feature = "rayon")]
+pub fn propagate_catalog_par(catalog: &[Sgp4], minutes_since_epoch: f64) -> Vec<Result<StateVector, PropagationError>> {
+    return catalog.par_iter().map(|sgp4| propagate(sgp4, minutes_since_epoch)).collect();
+}
+
+/// Interpolate between two propagated states with a cubic Hermite polynomial
+///
+/// The polynomial matches the positions and velocities of both states, so its error over a short interval is much
+/// smaller than linear interpolation of the positions. The velocity is the derivative of the interpolated position.
+/// The states are the (minutes since epoch, state vector) pairs of [`propagate_range`], and the interpolated state is
+/// in the coordinate frame of the first state.
+///
+/// # Arguments
+/// * `s0` - The state at the start of the interval
+/// * `s1` - The state at the end of the interval
+/// * `t` - The time to interpolate to since the TLE epoch \[min\]
+///
+/// # Returns
+/// * `Result<StateVector, PropagationError>` - The interpolated position \[km\] and velocity \[km / s\] at `t`, in the
+///   coordinate frame of the start state
+///
+/// # Errors
+/// Returns `PropagationError::InvalidTimeRange` if the start state is not before the end state.
+/// Returns `PropagationError::InterpolationOutOfRange` if `t` is outside of the interval between the states.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::{hermite_interpolate, init_sgp4, propagate};
+///
+/// // Define the TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Propagate the ends of a one minute interval and interpolate halfway
+/// let sgp4 = init_sgp4(&tle, None);
+/// let s0 = (10., propagate(&sgp4, 10.).unwrap());
+/// let s1 = (11., propagate(&sgp4, 11.).unwrap());
+/// let state = hermite_interpolate(&s0, &s1, 10.5).unwrap();
+/// assert!((state.position - propagate(&sgp4, 10.5).unwrap().position).magnitude() < 1e-3);
+/// ```
+pub fn hermite_interpolate(s0: &(f64, StateVector), s1: &(f64, StateVector), t: f64) -> Result<StateVector, PropagationError> {
+    let (t0, state0) = s0;
+    let (t1, state1) = s1;
+
+    // Validate the interval and the interpolation time
+    if t0.is_nan() || t1.is_nan() || t0 >= t1 {
+        return Err(PropagationError::InvalidTimeRange { start: *t0, stop: *t1 });
+    }
+    if t.is_nan() || t < *t0 || t > *t1 {
+        return Err(PropagationError::InterpolationOutOfRange { time: t, start: *t0, stop: *t1 });
+    }
+
+    // Normalize the time over the interval, scaling the velocities to the interval length
+    let h = (t1 - t0) * 60.; // [s]
+    let tau = (t - t0) / (t1 - t0);
+    let v0 = state0.velocity * h; // [km]
+    let v1 = state1.velocity * h; // [km]
+
+    // Evaluate the Hermite basis functions and their derivatives
+    let tau2 = tau.powi(2);
+    let tau3 = tau.powi(3);
+    let h00 = 2. * tau3 - 3. * tau2 + 1.;
+    let h10 = tau3 - 2. * tau2 + tau;
+    let h01 = -2. * tau3 + 3. * tau2;
+    let h11 = tau3 - tau2;
+    let dh00 = 6. * tau2 - 6. * tau;
+    let dh10 = 3. * tau2 - 4. * tau + 1.;
+    let dh01 = -6. * tau2 + 6. * tau;
+    let dh11 = 3. * tau2 - 2. * tau;
+
+    let state = StateVector {
+        time_min: t,
+        position: state0.position * h00 + v0 * h10 + state1.position * h01 + v1 * h11,
+        velocity: (state0.position * dh00 + v0 * dh10 + state1.position * dh01 + v1 * dh11) * (1. / h),
+        coordinate_frame: state0.coordinate_frame,
+    };
+
+    return Ok(state);
+}
+
+/// Propagate a satellite's state vector a number of minutes from the TLE epoch
+///
+/// This function evaluates the SGP4 theory at a time offset from the TLE epoch, accounting for Earth zonal gravity,
+/// atmospheric drag and the long and short-period periodic effects of Earth's gravity.
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `minutes_since_epoch` - The time since the TLE epoch \[min\]
+///
+/// # Returns
+/// * `Result<StateVector, PropagationError>` - The propagated position \[km\] and velocity \[km / s\] in TEME coordinates,
+///   at `minutes_since_epoch`
+///
+/// # Errors
+/// The error conditions match those of Spacetrack Report #3 as revised by Vallado et al:
+///   - `PropagationError::MeanMotionTooLow` if the mean motion is not positive
+///   - `PropagationError::EccentricityOutOfRange` if the mean or perturbed eccentricity is outside of \[0, 1)
+///   - `PropagationError::NegativeSemiLatusRectum` if the semi-latus rectum is negative
+///   - `PropagationError::DecayBelowSurface` if the radius is less than the Earth's equatorial radius
+///   - `PropagationError::ConvergenceFailure` if Kepler's equation does not converge
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::{init_sgp4, propagate};
+///
+/// // Define the TLE
+/// let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+/// let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Initialize the SGP4 propagator
+/// let sgp4 = init_sgp4(&tle, None);
+///
+/// // Propagate the state vector 6 hours past epoch
+/// let state_vector = propagate(&sgp4, 360.).unwrap();
+/// assert!((state_vector.position.x - -7154.03120202).abs() < 1e-6);
+/// ```
+///
+/// References
+/// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+/// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
+pub fn propagate(sgp4: &Sgp4, minutes_since_epoch: f64) -> Result<StateVector, PropagationError> {
+    // Evaluate the theory in canonical units
+    let (position, velocity) = propagate_canonical(sgp4, minutes_since_epoch)?;
+
+    // Scale to kilometers and seconds
+    let state_vector = StateVector {
+        time_min: minutes_since_epoch,
+        position: position * sgp4.wgs.r_earth_eq,
+        velocity: velocity * (sgp4.wgs.r_earth_eq / 60.),
+        coordinate_frame: CoordinateFrame::TEME,
+    };
+
+    return Ok(state_vector);
+}
+
+/// Propagate a satellite's state vector a number of minutes from the TLE epoch, in canonical units
+///
+/// This is [`propagate`] before the conversion to kilometers and seconds, for comparison with implementations that
+/// work in Earth radii, like the reference C++ code of Vallado et al. The position is in Earth radii and the
+/// velocity in Earth radii per minute, so the state of [`propagate`] is recovered by multiplying the position by
+/// `wgs.r_earth_eq` \[km / Earth radii\] and the velocity by `wgs.r_earth_eq / 60` \[km/s per Earth radii / min\].
+/// The internal velocity of the theory is in Earth radii per canonical time unit (`wgs.tumin` minutes), which is
+/// converted to Earth radii per minute by multiplying by `wgs.ke`.
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `minutes_since_epoch` - The time since the TLE epoch \[min\]
+///
+/// # Returns
+/// * `Result<(Vector3, Vector3), PropagationError>` - The propagated position \[Earth radii\] and velocity
+///   \[Earth radii / min\] in TEME coordinates
+///
+/// # Errors
+/// Returns the same errors as [`propagate`], with the radius of `PropagationError::DecayBelowSurface` in \[km\].
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::{init_sgp4, propagate, propagate_canonical};
+///
+/// // Define the TLE
+/// let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+/// let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Propagate the state vector 6 hours past epoch in Earth radii
+/// let sgp4 = init_sgp4(&tle, None);
+/// let (position, _velocity) = propagate_canonical(&sgp4, 360.).unwrap();
+/// assert!((position.x * sgp4.wgs.r_earth_eq - -7154.03120202).abs() < 1e-6);
+/// ```
+///
+/// References
+/// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
+pub fn propagate_canonical(sgp4: &Sgp4, minutes_since_epoch: f64) -> Result<(Vector3, Vector3), PropagationError> {
+    // Extract the propagator parameters
+    let wgs = &sgp4.wgs;
+    let brouwer0 = &sgp4.brouwer0;
+    let atm_params = &sgp4.atm_params;
+    let bstar = sgp4.tle.bstar;
+    let delta_t = minutes_since_epoch;
 
     // Create mutable variables for the orbital elements
-    let mut m = 0.;
-    let mut omega = 0.;
-    let mut raan = 0.;
-    let mut e = 0.;
-    let mut i = 0.;
-    let mut n = 0.;
+    let mut e = brouwer0.e;
+    let mut i = brouwer0.i;
+    let mut n = brouwer0.n;
 
     // Account for Earth zonal gravity and partial atmospheric drag effects
-    let m_df = sgp4.brouwer0.m + sgp4.brouwer0.n * delta_t + sgp4.zonal_params.m_dot * delta_t;
-    let omega_df = sgp4.brouwer0.omega + sgp4.zonal_params.omega_dot * delta_t;
-    let raan_df = sgp4.brouwer0.raan + sgp4.zonal_params.raan_dot * delta_t;
+    let m_df = brouwer0.m + brouwer0.n * delta_t + sgp4.zonal_params.m_dot * delta_t;
+    let omega_df = brouwer0.omega + sgp4.zonal_params.omega_dot * delta_t;
+    let raan_df = brouwer0.raan + sgp4.zonal_params.raan_dot * delta_t;
 
     // Neglect delta_omega and delta_m if deep space or perigee height is less than 220 km
+    let simplified = sgp4.deep_space || atm_params.hp < 220.;
     let mut delta_omega = 0.;
     let mut delta_m = 0.;
-    if sgp4.deep_space || sgp4.atm_params.hp < 220. {
-        delta_omega = 0.;
-        delta_m = 0.;
-    } else {
-        delta_omega = sgp4.atm_params.bstar * sgp4.atm_params.c3 * sgp4.brouwer0.omega.cos() * delta_t;
-        delta_m = (-2./3.) * (sgp4.atm_params.q0 - sgp4.atm_params.s).powi(4) * sgp4.tle.bstar * sgp4.atm_params.zeta.powi(4) * (1. / (sgp4.brouwer0.e * sgp4.atm_params.eta)) * ((1 + sgp4.atm_params.eta*m_df.cos()).powi(3) - (1 + sgp4.atm_params.eta*sgp4.brouwer0.m.cos()).powi(3));
+    if !simplified {
+        delta_omega = bstar * atm_params.c3 * brouwer0.omega.cos() * delta_t;
+        if brouwer0.e > 1e-4 {
+            delta_m = (-2./3.) * (atm_params.q0 - atm_params.s).powi(4) * bstar * atm_params.zeta.powi(4) * (1. / (brouwer0.e * atm_params.eta)) * ((1. + atm_params.eta * m_df.cos()).powi(3) - (1. + atm_params.eta * brouwer0.m.cos()).powi(3));
+        }
     }
 
-    m = m_df + delta_omega + delta_m;
-    omega = omega_df - delta_omega - delta_m;
-    raan = raan_df - (21./2.) * (sgp4.brouwer0.n * sgp4.atm_params.k2 * sgp4.brouwer0.theta / (sgp4.brouwer0.a.powi(2) * sgp4.brouwer0.beta.powi(2))) * sgp4.atm_params.c1 * delta_t.powi(2);
+    let mut m = m_df + delta_omega + delta_m;
+    let mut omega = omega_df - delta_omega - delta_m;
+    let mut raan = raan_df + atm_params.raan_drag * delta_t.powi(2);
+
+    // Account for remaining atmospheric drag effects
+    let c1 = atm_params.c1;
+    let mut tempa = 1. - c1 * delta_t;
+    let mut tempe = bstar * atm_params.c4 * delta_t;
+    let mut templ = (3./2.) * c1 * delta_t.powi(2);
+    if !simplified {
+        let d2 = atm_params.d2;
+        let d3 = atm_params.d3;
+        let d4 = atm_params.d4;
+        tempa = tempa - d2 * delta_t.powi(2) - d3 * delta_t.powi(3) - d4 * delta_t.powi(4);
+        tempe = tempe + bstar * atm_params.c5 * (m.sin() - brouwer0.m.sin());
+        templ = templ + (d2 + 2. * c1.powi(2)) * delta_t.powi(3)
+            + delta_t.powi(4) * ((1./4.) * (3. * d3 + c1 * (12. * d2 + 10. * c1.powi(2)))
+            + delta_t * (1./5.) * (3. * d4 + 12. * c1 * d3 + 6. * d2.powi(2) + 15. * c1.powi(2) * (2. * d2 + c1.powi(2))));
+    }
 
     // Account for Lunar and Solar third body effects
     if sgp4.deep_space {
         m = m + (sgp4.lunar_params.m_dot + sgp4.solar_params.m_dot) * delta_t;
         omega = omega + (sgp4.lunar_params.omega_dot + sgp4.solar_params.omega_dot) * delta_t;
         raan = raan + (sgp4.lunar_params.raan_dot + sgp4.solar_params.raan_dot) * delta_t;
-        e = sgp4.brouwer0.e + (sgp4.lunar_params.e_dot + sgp4.solar_params.e_dot) * delta_t;
-        i = sgp4.brouwer0.i + (sgp4.lunar_params.i_dot + sgp4.solar_params.i_dot) * delta_t;
+        e = brouwer0.e + (sgp4.lunar_params.e_dot + sgp4.solar_params.e_dot) * delta_t;
+        i = brouwer0.i + (sgp4.lunar_params.i_dot + sgp4.solar_params.i_dot) * delta_t;
     }
 
     // Account for the whole and half day resonance effects of Earth's gravity
     if sgp4.half_day_resonance {
-        // Calculate the auxilary variable and mean motion at the end of the time step using Euler-Maclaurin integration
+        // Integrate the auxilary variable and mean motion from epoch in 720 minute steps towards the requested time
+        let step = if delta_t >= 0. { 720. } else { -720. }; // [minutes]
+        let mut atime = 0.; // [minutes]
         let mut lami = sgp4.half_day_resonance_params.lam0;
-        let mut ni = sgp4.brouwer0.n;
-        let mut lami_dot = 0.;
-        let mut ni_dot = 0.;
-        let mut lami_ddot = 0.;
-        let mut ni_ddot = 0.;
-        let em_steps = (delta_t / 720.).ceil() as i32;
-        let t_em = delta_t - (em_steps * 720.);
-
-        // Propagate the auxilary variable and mean motion using Euler-Maclaurin integration
-        for _ in 0..em_steps {
-            let omegai = sgp4.brouwer0.omega + (sgp4.zonal_params.omega_dot + (sgp4.solar_params.omega_dot + sgp4.lunar_params.omega_dot)) * (em_steps + 1) * 720.;
-            (lami, ni, lami_dot, ni_dot, lami_ddot, ni_ddot) = half_day_euler_maclaurin_step(lami, ni, omegai, &sgp4.half_day_resonance_params);
-        }
-
-        // Calculate the auxilary variable and mean motion at the end of the time step using Euler integration
-        lami = lami + (lami_dot * t_em) + (0.5 * lami_ddot * t_em.powi(2));
+        let mut ni = brouwer0.n;
+        let (lami_dot, ni_dot, ni_ddot) = loop {
+            // Calculate the argument of perigee at the integrator time from the zonal secular rate
+            let omegai = brouwer0.omega + sgp4.zonal_params.omega_dot * atime;
+            let (lami_update, ni_update, lami_dot, ni_dot, _lami_ddot, ni_ddot) = half_day_euler_maclaurin_step(lami, ni, omegai, step, &sgp4.half_day_resonance_params);
+
+            // Stop once the requested time is within one step of the integrator time
+            if (delta_t - atime).abs() < 720. {
+                break (lami_dot, ni_dot, ni_ddot);
+            }
+            lami = lami_update;
+            ni = ni_update;
+            atime = atime + step;
+        };
+
+        // Calculate the auxilary variable and mean motion at the requested time using a Taylor series
+        let t_em = delta_t - atime;
+        lami = lami + (lami_dot * t_em) + (0.5 * ni_dot * t_em.powi(2));
         ni = ni + (ni_dot * t_em) + (0.5 * ni_ddot * t_em.powi(2));
 
         // Update the mean anomaly and mean motion
-        let theta_t = (sgp4.half_day_resonance_params.theta_g + RPTIM * delta_t).rem_euclid(2.0 * PI);
+        let theta_t = (sgp4.half_day_resonance_params.theta_g + sgp4.wgs.omega_earth * delta_t) % (2.0 * PI);
         n = ni;
-        m = lami - 2 * raan + 2 * theta_t;
+        m = lami - 2. * raan + 2. * theta_t;
     } else if sgp4.whole_day_resonance {
-        // Calculate the auxilary variable and mean motion at the end of the time step using Euler-Maclaurin integration
+        // Integrate the auxilary variable and mean motion from epoch in 720 minute steps towards the requested time
+        let step = if delta_t >= 0. { 720. } else { -720. }; // [minutes]
+        let mut atime = 0.; // [minutes]
         let mut lami = sgp4.whole_day_resonance_params.lam0;
-        let mut ni = sgp4.brouwer0.n;
-        let mut lami_dot = 0.;
-        let mut ni_dot = 0.;
-        let mut lami_ddot = 0.;
-        let mut ni_ddot = 0.;
-        let em_steps = (delta_t / 720.).ceil() as i32;
-        let t_em = delta_t - (em_steps * 720.);
-
-        // Propagate the auxilary variable and mean motion using Euler-Maclaurin integration
-        for _ in 0..em_steps {
-            (lami, ni, lami_dot, ni_dot, lami_ddot, ni_ddot) = whole_day_euler_maclaurin_step(lami, ni, &sgp4.whole_day_resonance_params);
-        }
-
-        // Calculate the auxilary variable and mean motion at the end of the time step using Euler integration
-        lami = lami + (lami_dot * t_em) + (0.5 * lami_ddot * t_em.powi(2));
+        let mut ni = brouwer0.n;
+        let (lami_dot, ni_dot, ni_ddot) = loop {
+            let (lami_update, ni_update, lami_dot, ni_dot, _lami_ddot, ni_ddot) = whole_day_euler_maclaurin_step(lami, ni, step, &sgp4.whole_day_resonance_params);
+
+            // Stop once the requested time is within one step of the integrator time
+            if (delta_t - atime).abs() < 720. {
+                break (lami_dot, ni_dot, ni_ddot);
+            }
+            lami = lami_update;
+            ni = ni_update;
+            atime = atime + step;
+        };
+
+        // Calculate the auxilary variable and mean motion at the requested time using a Taylor series
+        let t_em = delta_t - atime;
+        lami = lami + (lami_dot * t_em) + (0.5 * ni_dot * t_em.powi(2));
         ni = ni + (ni_dot * t_em) + (0.5 * ni_ddot * t_em.powi(2));
 
         // Update the mean anomaly and mean motion
-        let theta_t = (sgp4.whole_day_resonance_params.theta_g + RPTIM * delta_t).rem_euclid(2.0 * PI);
+        let theta_t = (sgp4.whole_day_resonance_params.theta_g + sgp4.wgs.omega_earth * delta_t) % (2.0 * PI);
         n = ni;
         m = lami - raan - omega + theta_t;
     }
 
-    // Account for remaining atmospheric drag effects
+    // Apply the secular drag terms to the semi-major axis, eccentricity and mean anomaly
+    if n <= 0. {
+        return Err(PropagationError::MeanMotionTooLow { mean_motion: n });
+    }
+    let a = (wgs.ke / n).powf(2./3.) * tempa.powi(2); // [Earth radii]
+    n = wgs.ke / a.powf(3./2.); // [rad/min]
+    e = e - tempe;
+    if !(-0.001..1.).contains(&e) {
+        return Err(PropagationError::EccentricityOutOfRange { eccentricity: e });
+    }
+    if e < 1e-6 {
+        e = 1e-6;
+    }
+    m = m + brouwer0.n * templ;
+
+    // Wrap the angles to a single revolution
+    let xl_m = (m + omega + raan) % (2. * PI);
+    raan = raan % (2. * PI);
+    omega = omega % (2. * PI);
+    m = (xl_m - omega - raan) % (2. * PI);
 
     // Account for long-period periodic effects of lunar and solar gravity
+    if sgp4.deep_space {
+        let (pe, pinc, pl, pgh, ph) = calc_lunar_solar_periodics(delta_t, &sgp4.lunar_params, &sgp4.solar_params);
+        i = i + pinc;
+        e = e + pe;
+        if !(0. ..=1.).contains(&e) {
+            return Err(PropagationError::EccentricityOutOfRange { eccentricity: e });
+        }
+        let (sin_ip, cos_ip) = i.sin_cos();
+        if i >= 0.2 {
+            // Apply the periodics directly
+            let ph_i = ph / sin_ip;
+            omega = omega + pgh - cos_ip * ph_i;
+            raan = raan + ph_i;
+            m = m + pl;
+        } else {
+            // Apply the periodics with the Lyddane modification to avoid the singularity at zero inclination
+            let (sin_raan, cos_raan) = raan.sin_cos();
+            let alfdp = sin_ip * sin_raan + ph * cos_raan + pinc * cos_ip * sin_raan;
+            let betdp = sin_ip * cos_raan - ph * sin_raan + pinc * cos_ip * cos_raan;
+            raan = raan % (2. * PI);
+            if raan < 0. {
+                raan = raan + 2. * PI;
+            }
+            let xls = m + omega + cos_ip * raan + pl + pgh - pinc * raan * sin_ip;
+            let raan_old = raan;
+            raan = alfdp.atan2(betdp);
+            if raan < 0. {
+                raan = raan + 2. * PI;
+            }
+            if (raan_old - raan).abs() > PI {
+                if raan < raan_old {
+                    raan = raan + 2. * PI;
+                } else {
+                    raan = raan - 2. * PI;
+                }
+            }
+            m = m + pl;
+            omega = xls - m - cos_ip * raan;
+        }
+
+        // Keep the inclination positive
+        if i < 0. {
+            i = -i;
+            raan = raan + PI;
+            omega = omega - PI;
+        }
+    }
 
     // Account for long-period periodic effects of Earth's gravity
+    let sin_i = i.sin();
+    let cos_i = i.cos();
+    let j3_j2 = wgs.j3 / wgs.j2;
+    let aycof = -0.5 * j3_j2 * sin_i;
+    let xlcof = if (1. + cos_i).abs() > 1.5e-12 {
+        -0.25 * j3_j2 * sin_i * (3. + 5. * cos_i) / (1. + cos_i)
+    } else {
+        -0.25 * j3_j2 * sin_i * (3. + 5. * cos_i) / 1.5e-12
+    };
+    let axn = e * omega.cos();
+    let temp = 1. / (a * (1. - e.powi(2)));
+    let ayn = e * omega.sin() + temp * aycof;
+    let xl = m + omega + raan + temp * xlcof * axn;
+
+    // Solve Kepler's equation for the eccentric longitude using Newton-Raphson iteration
+    let u = (xl - raan) % (2. * PI);
+    let mut eo1 = u;
+    let mut sin_eo1 = eo1.sin();
+    let mut cos_eo1 = eo1.cos();
+    let mut tem5: f64 = 9999.9;
+    let mut ktr = 1;
+    while tem5.abs() >= KEPLER_TOLERANCE && ktr <= 10 {
+        sin_eo1 = eo1.sin();
+        cos_eo1 = eo1.cos();
+        tem5 = 1. - cos_eo1 * axn - sin_eo1 * ayn;
+        tem5 = (u - ayn * cos_eo1 + axn * sin_eo1 - eo1) / tem5;
+        tem5 = tem5.clamp(-0.95, 0.95);
+        eo1 = eo1 + tem5;
+        ktr = ktr + 1;
+    }
+    if !tem5.is_finite() || tem5.abs() >= KEPLER_TOLERANCE {
+        return Err(PropagationError::ConvergenceFailure { iterations: ktr - 1 });
+    }
 
     // Account for short-period periodic effects of Earth's gravity
+    let ecose = axn * cos_eo1 + ayn * sin_eo1;
+    let esine = axn * sin_eo1 - ayn * cos_eo1;
+    let el2 = axn.powi(2) + ayn.powi(2);
+    let pl = a * (1. - el2);
+    if pl < 0. {
+        return Err(PropagationError::NegativeSemiLatusRectum { semi_latus_rectum: pl });
+    }
+    let rl = a * (1. - ecose);
+    let rdotl = a.sqrt() * esine / rl;
+    let rvdotl = pl.sqrt() / rl;
+    let betal = (1. - el2).sqrt();
+    let temp = esine / (1. + betal);
+    let sin_u = a / rl * (sin_eo1 - ayn - axn * temp);
+    let cos_u = a / rl * (cos_eo1 - axn + ayn * temp);
+    let su = sin_u.atan2(cos_u);
+    let sin_2u = 2. * cos_u * sin_u;
+    let cos_2u = 1. - 2. * sin_u.powi(2);
+    let temp1 = wgs.k2 / pl;
+    let temp2 = temp1 / pl;
+
+    let con41 = 3. * cos_i.powi(2) - 1.;
+    let x1mth2 = 1. - cos_i.powi(2);
+    let x7thm1 = 7. * cos_i.powi(2) - 1.;
+
+    let r_k = rl * (1. - (3./2.) * temp2 * betal * con41) + 0.5 * temp1 * x1mth2 * cos_2u; // [Earth radii]
+    let u_k = su - 0.25 * temp2 * x7thm1 * sin_2u;
+    let raan_k = raan + (3./2.) * temp2 * cos_i * sin_2u;
+    let i_k = i + (3./2.) * temp2 * cos_i * sin_i * cos_2u;
+    let r_dot_k = rdotl - n * temp1 * x1mth2 * sin_2u / wgs.ke; // [Earth radii / tumin]
+    let rf_dot_k = rvdotl + n * temp1 * (x1mth2 * cos_2u + (3./2.) * con41) / wgs.ke; // [Earth radii / tumin]
+
+    // Calculate the orientation unit vectors
+    let (sin_u_k, cos_u_k) = u_k.sin_cos();
+    let (sin_raan_k, cos_raan_k) = raan_k.sin_cos();
+    let (sin_i_k, cos_i_k) = i_k.sin_cos();
+    let m_x = -sin_raan_k * cos_i_k;
+    let m_y = cos_raan_k * cos_i_k;
+    let u = Vector3::new(m_x * sin_u_k + cos_raan_k * cos_u_k, m_y * sin_u_k + sin_raan_k * cos_u_k, sin_i_k * sin_u_k);
+    let v = Vector3::new(m_x * cos_u_k - cos_raan_k * sin_u_k, m_y * cos_u_k - sin_raan_k * sin_u_k, sin_i_k * cos_u_k);
 
     // Return position and velocity vectors in the TEME frame
+    if r_k < 1. {
+        return Err(PropagationError::DecayBelowSurface { radius: r_k * wgs.r_earth_eq });
+    }
+    let position = u * r_k; // [Earth radii]
+    let velocity = (u * r_dot_k + v * rf_dot_k) * wgs.ke; // [Earth radii / min]
+
+    return Ok((position, velocity));
+}
+
+/// Initialize SGP4 from a TLE and propagate its state vector a number of minutes from the TLE epoch in one call
+///
+/// This is [`try_init_sgp4`] followed by [`propagate`]. The initialization is repeated on every call, so a TLE that is
+/// propagated to many times should be initialized once with [`init_sgp4`] and the [`Sgp4`] struct reused instead.
+///
+/// # Arguments
+/// * `tle` - The Two-Line Element parameters
+/// * `wgs` - Optional, specify World Geodetic System (WGS) parameters (defaults to WGS-72, the standard for TLEs)
+/// * `minutes_since_epoch` - The time since the TLE epoch \[min\]
+///
+/// # Returns
+/// * `Result<StateVector, PropagationError>` - The propagated position \[km\] and velocity \[km / s\] in TEME coordinates
+///
+/// # Errors
+/// Returns `PropagationError::InvalidEpoch` if the TLE epoch is not a valid date.
+/// Returns the `PropagationError` of [`propagate`] if the SGP4 theory fails at the requested time.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::propagate_from_tle;
+///
+/// // Define the TLE
+/// let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+/// let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+/// let tle = from_lines(line1, line2, None).unwrap();
+///
+/// // Propagate the state vector 6 hours past epoch
+/// let state_vector = propagate_from_tle(&tle, None, 360.).unwrap();
+/// assert!((state_vector.position.x - -7154.03120202).abs() < 1e-6);
+/// ```
+pub fn propagate_from_tle(tle: &Tle, wgs: Option<&Wgs>, minutes_since_epoch: f64) -> Result<StateVector, PropagationError> {
+    let sgp4 = try_init_sgp4(tle, wgs)?;
+    return propagate(&sgp4, minutes_since_epoch);
+}
+
+/// Solve Kepler's equation, M = E - e sin(E), for the eccentric anomaly with a convergence limit
+///
+/// Newton-Raphson iteration starts from E = M + e sin(M) and is safeguarded by the bracket \[M - e, M + e\], which
+/// always contains the root. Whenever a Newton step leaves the bracket or is not finite, the bracket is bisected
+/// instead, so the solver converges for any eccentricity below 1, including the e ~ 0.7-0.9 of Molniya and transfer
+/// orbits. The tolerance and the iteration limit are set by the caller and non-convergence is reported as an error,
+/// while [`mean_to_eccentric_anomaly`](crate::common::mean_to_eccentric_anomaly) calls this solver with the defaults.
+///
+/// # Arguments
+/// * `mean_anom_rad` - The mean anomaly \[rad\]
+/// * `ecc` - The orbital eccentricity in \[0, 1) \[\]
+/// * `tol` - The convergence tolerance on the eccentric anomaly update \[rad\]
+/// * `max_iter` - The maximum number of iterations
+///
+/// # Returns
+/// * `Result<f64, PropagationError>` - The eccentric anomaly, in the same revolution as the mean anomaly \[rad\]
+///
+/// # Errors
+/// Returns `PropagationError::EccentricityOutOfRange` if the eccentricity is outside of \[0, 1).
+/// Returns `PropagationError::ConvergenceFailure` if the update is not below `tol` within `max_iter` iterations.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::sgp4::solve_kepler;
+///
+/// // Example 2-1 from Vallado
+/// let ecc_anom = solve_kepler(235.4_f64.to_radians(), 0.4, 1e-12, 50)?;
+/// assert!((ecc_anom - 3.848661745).abs() < 1e-9);
+/// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn solve_kepler(mean_anom_rad: f64, ecc: f64, tol: f64, max_iter: usize) -> Result<f64, PropagationError> {
+    if !(0. ..1.).contains(&ecc) {
+        return Err(PropagationError::EccentricityOutOfRange { eccentricity: ecc });
+    }
+
+    // Wrap the mean anomaly to [0, 2pi), keeping the number of whole revolutions
+    let revolutions = (mean_anom_rad / (2. * PI)).floor();
+    let m = mean_anom_rad - revolutions * 2. * PI; // [rad]
+
+    // The root lies within e of the mean anomaly, since |E - M| = e |sin(E)|
+    let mut lower = m - ecc; // [rad]
+    let mut upper = m + ecc; // [rad]
+    let mut ecc_anom = m + ecc * m.sin(); // [rad]
+
+    for _ in 0..max_iter {
+        // Shrink the bracket with the sign of the residual
+        let residual = ecc_anom - ecc * ecc_anom.sin() - m;
+        if residual < 0. {
+            lower = ecc_anom;
+        } else {
+            upper = ecc_anom;
+        }
+
+        // Take the Newton step, or bisect if it leaves the bracket
+        let newton = ecc_anom - residual / (1. - ecc * ecc_anom.cos()); // [rad]
+        let next = if newton.is_finite() && newton >= lower && newton <= upper { newton } else { 0.5 * (lower + upper) }; // [rad]
+        let delta = next - ecc_anom;
+        ecc_anom = next;
+        if delta.abs() < tol {
+            return Ok(ecc_anom + revolutions * 2. * PI);
+        }
+    }
+
+    return Err(PropagationError::ConvergenceFailure { iterations: max_iter });
+}
+
+/// Calculate the long-period periodic perturbations of the Lunar and Solar third body effects
+///
+/// # Arguments
+/// * `delta_t` - The time since epoch \[minutes\]
+/// * `lunar_params` - The Lunar third body parameters
+/// * `solar_params` - The Solar third body parameters
+///
+/// # Returns
+/// * `(pe, pinc, pl, pgh, ph)` - The perturbations in eccentricity \[\], inclination \[rad\], mean anomaly \[rad\],
+///   argument of perigee \[rad\] and right ascension of the ascending node \[rad\], the last two before dividing out
+///   the inclination
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::sgp4::{calc_lunar_solar_periodics, ThirdBodyParams};
+///
+/// // Define Lunar and Solar third body parameters
+/// let lunar_params = ThirdBodyParams::default();
+/// let solar_params = ThirdBodyParams::default();
+///
+/// // Calculate the periodic perturbations 6 hours past epoch
+/// let (pe, pinc, pl, pgh, ph) = calc_lunar_solar_periodics(360., &lunar_params, &solar_params);
+/// assert_eq!((pe, pinc, pl, pgh, ph), (0., 0., 0., 0., 0.));
+/// ```
+///
+/// References
+/// - [Revisiting Spacetrack Report #3: Rev 3 by Vallado et al](https://celestrak.org/publications/AIAA/2006-6753/AIAA-2006-6753-Rev3.pdf)
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn calc_lunar_solar_periodics(delta_t: f64, lunar_params: &ThirdBodyParams, solar_params: &ThirdBodyParams) -> (f64, f64, f64, f64, f64) {
+    let mut pe = 0.;
+    let mut pinc = 0.;
+    let mut pl = 0.;
+    let mut pgh = 0.;
+    let mut ph = 0.;
+    for params in [solar_params, lunar_params] {
+        // Calculate the third body true anomaly, approximated to first order in eccentricity
+        let zm = params.m0 + params.n * delta_t; // [rad]
+        let zf = zm + 2. * params.e * zm.sin(); // [rad]
+        let (sin_zf, cos_zf) = zf.sin_cos();
+        let f2 = 0.5 * sin_zf.powi(2) - 0.25;
+        let f3 = -0.5 * sin_zf * cos_zf;
+
+        // Accumulate the perturbations
+        pe = pe + params.e2 * f2 + params.e3 * f3;
+        pinc = pinc + params.i2 * f2 + params.i3 * f3;
+        pl = pl + params.l2 * f2 + params.l3 * f3 + params.l4 * sin_zf;
+        pgh = pgh + params.gh2 * f2 + params.gh3 * f3 + params.gh4 * sin_zf;
+        ph = ph + params.h2 * f2 + params.h3 * f3;
+    }
+
+    return (pe, pinc, pl, pgh, ph);
 }
 
 /// Half day Euler-Maclaurin integration step
@@ -1338,38 +2534,47 @@ pub fn sgp4_prop(sgp4: Sgp4, datetime: DateTime) -> StateVector{
 /// * `lami` - The auxilary variable at time i
 /// * `ni` - The mean motion at time i
 /// * `omegai` - The argument of perigee at time i
+/// * `delta_t` - The integration step, 720 minutes forward or backward \[minutes\]
 /// * `half_day_resonance_params` - The half day resonance parameters
 ///
 /// # Returns
 /// * `lami_update` - The auxilary variable at time i+1
 /// * `ni_update` - The mean motion at time i+1
-/// * `lami_dot` - The rate of change of the auxilary variable at time i+1
-/// * `ni_dot` - The rate of change of the mean motion at time i+1
-/// * `lami_ddot` - The 2nd derivative of the auxilary variable at time i+1
-/// * `ni_ddot` - The 2nd derivative of the mean motion at time i+1
+/// * `lami_dot` - The rate of change of the auxilary variable at time i
+/// * `ni_dot` - The rate of change of the mean motion at time i
+/// * `lami_ddot` - The 2nd derivative of the auxilary variable at time i
+/// * `ni_ddot` - The 2nd derivative of the mean motion at time i
 ///
 /// # Examples
 /// ```rust
+/// use Rusty_SGP4::sgp4::{half_day_euler_maclaurin_step, HalfDayResonanceParams};
+///
 /// // Define auxilary variable at time i
 /// let lami = 0.0;
 ///
 /// // Define mean motion at time i
 /// let ni = 0.0;
 ///
+/// // Define argument of perigee at time i
+/// let omegai = 0.0;
+///
+/// // Define the integration step
+/// let delta_t = 720.0;
+///
 /// // Define half day resonance parameters
 /// let half_day_resonance_params = HalfDayResonanceParams::default();
 ///
 /// // Calculate the auxilary variable and mean motion at time i+1
-/// let (lami_update, ni_update, lami_dot, ni_dot, lami_ddot, ni_ddot) = half_day_euler_maclaurin_step(lami, ni, &half_day_resonance_params);
+/// let (lami_update, ni_update, lami_dot, ni_dot, lami_ddot, ni_ddot) = half_day_euler_maclaurin_step(lami, ni, omegai, delta_t, &half_day_resonance_params);
 /// ```
 ///
 /// References
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
-pub fn half_day_euler_maclaurin_step(lami: f64, ni: f64, omegai: f64, half_day_resonance_params: &HalfDayResonanceParams) -> (f64, f64, f64, f64, f64, f64) {
-    // Precompute the steps
-    let delta_t = 720.; // [minutes]
-    let delta_t_squared = 518400.; // [minutes^2]
+#[allow(clippy::eq_op)]
+pub fn half_day_euler_maclaurin_step(lami: f64, ni: f64, omegai: f64, delta_t: f64, half_day_resonance_params: &HalfDayResonanceParams) -> (f64, f64, f64, f64, f64, f64) {
+    // Precompute the squared step
+    let delta_t_squared = delta_t.powi(2); // [minutes^2]
 
     // Define constants
     let g22 = 5.7686396;
@@ -1422,38 +2627,43 @@ pub fn half_day_euler_maclaurin_step(lami: f64, ni: f64, omegai: f64, half_day_r
 /// # Arguments
 /// * `lami` - The auxilary variable at time i
 /// * `ni` - The mean motion at time i
+/// * `delta_t` - The integration step, 720 minutes forward or backward \[minutes\]
 /// * `whole_day_resonance_params` - The whole day resonance parameters
 ///
 /// # Returns
 /// * `lami_update` - The auxilary variable at time i+1
 /// * `ni_update` - The mean motion at time i+1
-/// * `lami_dot` - The rate of change of the auxilary variable at time i+1
-/// * `ni_dot` - The rate of change of the mean motion at time i+1
-/// * `lami_ddot` - The 2nd derivative of the auxilary variable at time i+1
-/// * `ni_ddot` - The 2nd derivative of the mean motion at time i+1
+/// * `lami_dot` - The rate of change of the auxilary variable at time i
+/// * `ni_dot` - The rate of change of the mean motion at time i
+/// * `lami_ddot` - The 2nd derivative of the auxilary variable at time i
+/// * `ni_ddot` - The 2nd derivative of the mean motion at time i
 ///
 /// # Examples
 /// ```rust
+/// use Rusty_SGP4::sgp4::{whole_day_euler_maclaurin_step, WholeDayResonanceParams};
+///
 /// // Define auxilary variable at time i
 /// let lami = 0.0;
 ///
 /// // Define mean motion at time i
 /// let ni = 0.0;
 ///
+/// // Define the integration step
+/// let delta_t = 720.0;
+///
 /// // Define whole day resonance parameters
 /// let whole_day_resonance_params = WholeDayResonanceParams::default();
 ///
 /// // Calculate the auxilary variable and mean motion at time i+1
-/// let (lami_update, ni_update, lami_dot, ni_dot, lami_ddot, ni_ddot) = whole_day_euler_maclaurin_step(lami, ni, &whole_day_resonance_params);
+/// let (lami_update, ni_update, lami_dot, ni_dot, lami_ddot, ni_ddot) = whole_day_euler_maclaurin_step(lami, ni, delta_t, &whole_day_resonance_params);
 /// ```
 ///
 /// References
 /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
 /// - [History of Analytical Orbit Modeling in the U.S. Space Surveillance System by Hoots et al](https://arc.aiaa.org/doi/abs/10.2514/1.9161?casa_token=pVowNFT6MOkAAAAA%3A_DFsBbZwGC2QcMWxPhJN2k3suNrcP5YzV7NVBYSvwMxGy19RzX-AvUnyO9JT5Cku0cDYPfpIQm4&journalCode=jgcd)
-pub fn whole_day_euler_maclaurin_step(lami: f64, ni: f64, whole_day_resonance_params: &WholeDayResonanceParams) -> (f64, f64, f64, f64, f64, f64) {
-    // Precompute the steps
-    let delta_t = 720.; // [minutes]
-    let delta_t_squared = 518400.; // [minutes^2]
+pub fn whole_day_euler_maclaurin_step(lami: f64, ni: f64, delta_t: f64, whole_day_resonance_params: &WholeDayResonanceParams) -> (f64, f64, f64, f64, f64, f64) {
+    // Precompute the squared step
+    let delta_t_squared = delta_t.powi(2); // [minutes^2]
 
     // Calculate the rate of change of the auxilary variable
     let lami_dot = ni + whole_day_resonance_params.lam0_dot;
@@ -1486,4 +2696,580 @@ pub fn whole_day_euler_maclaurin_step(lami: f64, ni: f64, whole_day_resonance_pa
 #[cfg(test)]
 mod tests {
     use super::*;
-}
\ No newline at end of file
+    use crate::tle::from_lines;
+    use crate::common::{WGS72OLD, WGS84};
+
+    /// Assert a propagated state matches a verification vector to 1e-6 km and 1e-9 km/s
+    fn assert_state(sgp4: &Sgp4, minutes_since_epoch: f64, r_expect: [f64; 3], v_expect: [f64; 3]) {
+        let state = propagate(sgp4, minutes_since_epoch).unwrap();
+        let r = [state.position.x, state.position.y, state.position.z];
+        let v = [state.velocity.x, state.velocity.y, state.velocity.z];
+        for k in 0..3 {
+            assert!((r[k] - r_expect[k]).abs() < 1e-6, "Position test failed at t = {}: expected {:?}, got {:?}", minutes_since_epoch, r_expect, r);
+            assert!((v[k] - v_expect[k]).abs() < 1e-9, "Velocity test failed at t = {}: expected {:?}, got {:?}", minutes_since_epoch, v_expect, v);
+        }
+        assert_eq!(state.coordinate_frame, CoordinateFrame::TEME);
+    }
+
+    #[test]
+    fn test_propagate_00005() {
+        // Define the TLE (AIAA-2006-6753 verification case, eccentric near-Earth orbit)
+        let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+        assert!(!sgp4.deep_space);
+
+        // Compare against the published state vectors
+        assert_state(&sgp4, 0., [7022.46529266, -1400.08296755, 0.03995155], [1.893841015, 6.405893759, 4.534807250]);
+        assert_state(&sgp4, 360., [-7154.03120202, -3783.17682504, -3536.19412294], [4.741887409, -4.151817765, -2.093935425]);
+        assert_state(&sgp4, 1440., [-938.55923943, -6268.18748831, -4294.02924751], [7.536105209, -0.427127707, 0.989878080]);
+        assert_state(&sgp4, 2880., [-8650.73082219, -1914.93811525, -3007.03603443], [3.067165127, -4.828384068, -2.515322836]);
+        assert_state(&sgp4, 4320., [-9060.47373569, 4658.70952502, 813.68673153], [-2.232832783, -4.110453490, -3.157345433]);
+    }
+
+    #[test]
+    fn test_propagate_06251() {
+        // Define the TLE (AIAA-2006-6753 verification case, low perigee with high drag)
+        let line1 = "1 06251U 62025E   06176.82412014  .00008885  00000-0  12808-3 0  3985";
+        let line2 = "2 06251  58.0579  54.0425 0030035 139.1568 221.1854 15.56387291  6774";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+
+        // Compare against the published state vectors
+        assert_state(&sgp4, 0., [3988.31022699, 5498.96657235, 0.90055879], [-3.290032738, 2.357652820, 6.496623475]);
+        assert_state(&sgp4, 720., [3692.60030028, -976.24265255, -5623.36447493], [3.897257243, 6.415554948, 1.429112190]);
+        assert_state(&sgp4, 1440., [-2777.14682335, -5663.16031708, -2462.54889123], [4.915493146, 0.123328992, -5.896495091]);
+        assert_state(&sgp4, 2880., [1159.27802897, 5056.60175495, 4353.49418579], [-5.968060341, -2.314790406, 4.230722669]);
+    }
+
+    #[test]
+    fn test_propagate_28057() {
+        // Define the TLE (AIAA-2006-6753 verification case, near-circular sun-synchronous orbit)
+        let line1 = "1 28057U 03049A   06177.78615833  .00000060  00000-0  35940-4 0  1836";
+        let line2 = "2 28057  98.4283 247.6961 0000884  88.1964 271.9322 14.35478080140550";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+
+        // Compare against the published state vectors
+        assert_state(&sgp4, 0., [-2715.28237486, -6619.26436889, -0.01341443], [-1.008587273, 0.422782003, 7.385272942]);
+        assert_state(&sgp4, 720., [-2090.79884266, -2723.22832193, 6266.13356576], [1.992640665, 6.337529519, 3.411803080]);
+        assert_state(&sgp4, 1440., [688.16056594, 4124.87618964, 5794.55994449], [2.810973665, 5.479585563, -4.224866316]);
+        assert_state(&sgp4, 2880., [1788.42334580, 1990.50530957, -6640.59337725], [-2.074169091, -6.683381288, -2.562777776]);
+    }
+
+    #[test]
+    fn test_propagate_88888() {
+        // Define the TLE (Spacetrack Report #3 test case)
+        let line1 = "1 88888U          80275.98708465  .00073094  13844-3  66816-4 0    87";
+        let line2 = "2 88888  72.8435 115.9689 0086731  52.6988 110.5714 16.05824518  1058";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+
+        // Compare against the published state vectors
+        assert_state(&sgp4, 0., [2328.96975262, -5995.22051338, 1719.97297192], [2.912073281, -0.983417956, -7.090816210]);
+        assert_state(&sgp4, 360., [2456.10706533, -6071.93855503, 1222.89768554], [2.679390040, -0.448290811, -7.228792155]);
+        assert_state(&sgp4, 720., [2567.56229695, -6112.50383922, 713.96374435], [2.440245751, 0.098109002, -7.319959258]);
+        assert_state(&sgp4, 1440., [2742.55398832, -6079.67009123, -326.39012649], [1.948497651, 1.211072678, -7.356193131]);
+    }
+
+    #[test]
+    fn test_propagate_08195() {
+        // Define the TLE (AIAA-2006-6753 verification case, Molniya orbit with half day resonance)
+        let line1 = "1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813";
+        let line2 = "2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+        assert!(sgp4.deep_space);
+        assert!(sgp4.half_day_resonance && !sgp4.whole_day_resonance);
+
+        // Compare against the published state vectors
+        assert_state(&sgp4, 0., [2349.89483350, -14785.93811562, 0.02119378], [2.721488096, -3.256811655, 4.498416672]);
+        assert_state(&sgp4, 360., [19089.29762968, 3107.89495018, 39958.14661370], [-0.410308034, 1.640332277, -0.306873818]);
+        assert_state(&sgp4, 1320., [3148.86165643, 18323.19841703, 12305.75195578], [-1.895271701, -0.678343847, -4.086577951]);
+        assert_state(&sgp4, 2880., [3417.20931587, -16038.79510665, 1894.74934058], [2.585515864, -2.596818146, 4.456882556]);
+    }
+
+    #[test]
+    fn test_propagate_28626() {
+        // Define the TLE (AIAA-2006-6753 verification case, near equatorial geosynchronous orbit with whole day resonance)
+        let line1 = "1 28626U 05008A   06176.46683397 -.00000205  00000-0  10000-3 0  2190";
+        let line2 = "2 28626   0.0019 286.9433 0000335  13.7918  55.6504  1.00270176  4891";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+        assert!(sgp4.deep_space);
+        assert!(sgp4.whole_day_resonance && !sgp4.half_day_resonance);
+
+        // Compare against the published state vectors
+        assert_state(&sgp4, 0., [42080.71852213, -2646.86387436, 0.81851294], [0.193105177, 3.068688251, 0.000438449]);
+        assert_state(&sgp4, 360., [2467.44290178, 42093.60909959, 5.15062987], [-3.069341800, 0.179976276, -0.000031739]);
+        assert_state(&sgp4, 840., [-37580.31858370, -19120.40485693, -2.02755702], [1.394367848, -2.740341612, -0.000248591]);
+        assert_state(&sgp4, 1440., [42119.96263499, -1925.77567263, -0.19827433], [0.140521206, 3.071541613, 0.000179561]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_propagate_range() {
+        let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+
+        // The samples include both ends, carry their time and match single propagations
+        let states = propagate_range(&sgp4, 0., 4320., 360.).unwrap();
+        assert_eq!(states.len(), 13);
+        for (i, (minutes, state)) in states.iter().enumerate() {
+            assert_eq!(*minutes, i as f64 * 360.);
+            assert_eq!(state.time_min, *minutes);
+            assert_eq!(*state, propagate(&sgp4, *minutes).unwrap());
+        }
+
+        // A stop time between steps is not included
+        let states = propagate_range(&sgp4, -10., 10.5, 5.).unwrap();
+        let minutes: Vec<f64> = states.iter().map(|(t, _)| *t).collect();
+        assert_eq!(minutes, vec![-10., -5., 0., 5., 10.]);
+
+        // A single sample when start equals stop
+        assert_eq!(propagate_range(&sgp4, 30., 30., 1.).unwrap().len(), 1);
+
+        // Invalid inputs
+        assert_eq!(propagate_range(&sgp4, 0., 10., 0.).unwrap_err(), PropagationError::InvalidStep { step: 0. });
+        assert_eq!(propagate_range(&sgp4, 0., 10., -1.).unwrap_err(), PropagationError::InvalidStep { step: -1. });
+        assert!(propagate_range(&sgp4, 0., 10., f64::NAN).is_err());
+        assert_eq!(propagate_range(&sgp4, 10., 0., 1.).unwrap_err(), PropagationError::InvalidTimeRange { start: 10., stop: 0. });
+
+        // Infinite and unbounded ranges are rejected before allocating the samples
+        assert_eq!(propagate_range(&sgp4, 0., f64::INFINITY, 1.).unwrap_err(), PropagationError::InvalidTimeRange { start: 0., stop: f64::INFINITY });
+        assert!(matches!(propagate_range(&sgp4, f64::NEG_INFINITY, 0., 1.), Err(PropagationError::InvalidTimeRange { .. })));
+        assert!(matches!(propagate_range(&sgp4, -f64::MAX, f64::MAX, 1.), Err(PropagationError::TooManySamples { .. })));
+        assert_eq!(propagate_range(&sgp4, 0., 1e12, 1.).unwrap_err(), PropagationError::TooManySamples { samples: 1e12 + 1. });
+    }
+
+    #[test]
+    fn test_sgp4_prop_at_epoch() {
+        // Define the TLE
+        let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // Propagating to the epoch datetime matches propagating zero minutes
+        let datetime = tle.epoch_datetime().unwrap();
+        let state = sgp4_prop(&sgp4, &datetime).unwrap();
+        let r_expect = Vector3::new(7022.46529266, -1400.08296755, 0.03995155);
+        assert!((state.position - r_expect).magnitude() < 1e-6);
+    }
+
+    #[test]
+    fn test_propagate_decay() {
+        // Define a TLE with a perigee height of 10 km, at apogee at the epoch
+        let line1 = "1 99999U 24001A   24001.00000000  .00000000  00000-0  10000-4 0  9994";
+        let line2 = "2 99999  51.6000 100.0000 0600000  90.0000 180.0000 15.50000000    16";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+
+        // The satellite is above the surface at the epoch
+        assert!(propagate(&sgp4, 0.).is_ok());
+
+        // The satellite decays below the surface on the way down to perigee, half an orbit later
+        let decay_min = (0..93).find(|&t| propagate(&sgp4, t as f64).is_err()).unwrap() as f64;
+        assert!(decay_min > 30. && decay_min < 93.);
+        match propagate(&sgp4, decay_min) {
+            Err(PropagationError::DecayBelowSurface { radius }) => assert!(radius < sgp4.wgs.r_earth_eq),
+            result => panic!("Expected DecayBelowSurface, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_propagate_eccentricity_out_of_range() {
+        // Define a TLE with heavy drag, which drives the eccentricity negative within days
+        let line1 = "1 99999U 24001A   24001.00000000  .01000000  00000-0  10000-2 0  9993";
+        let line2 = "2 99999  51.6000 100.0000 0010000  90.0000 270.0000 16.20000000    19";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+        assert!(propagate(&sgp4, 0.).is_ok());
+        match propagate(&sgp4, 20. * 1440.) {
+            Err(PropagationError::EccentricityOutOfRange { eccentricity }) => assert!(eccentricity < -0.001),
+            result => panic!("Expected EccentricityOutOfRange, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_init_sgp4_iss_coefficients() {
+        // Define the ISS TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+
+        // Compare the initialization against the intermediate values of the Spacetrack Report #3 formulas
+        let expected = [
+            ("a0", sgp4.brouwer0.a, 1.0553980074361273),
+            ("hp", sgp4.atm_params.hp, 348.82386516732697),
+            ("zeta", sgp4.atm_params.zeta, 23.164917378305077),
+            ("eta", sgp4.atm_params.eta, 0.01638763358342846),
+            ("c1", sgp4.atm_params.c1, -4.6056192328927423e-10),
+        ];
+        for (name, value, reference) in expected {
+            assert!(((value - reference) / reference).abs() < 1e-10, "{} test failed: expected {}, got {}", name, reference, value);
+        }
+        assert!(!sgp4.deep_space);
+
+        // The debug output names the coefficients
+        #[cfg(feature = "std")]
+        let debug = format!("{:?}", sgp4.atm_params);
+        #[cfg(feature = "std")]
+        assert!(debug.contains("c1: ") && debug.contains("d4: "));
+
+        // A cloned propagator propagates identically
+        let clone = sgp4.clone();
+        assert_eq!(propagate(&clone, 60.), propagate(&sgp4, 60.));
+    }
+
+    #[test]
+    fn test_sgp4_secular_rates() {
+        // Define the ISS TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+
+        // Compare against the mdot, argpdot and nodedot intermediate values of the AIAA-2006-6753 initialization
+        let (m_dot, omega_dot, raan_dot) = sgp4.secular_rates();
+        let expected = [
+            ("m_dot", m_dot, 0.06859691902030202),
+            ("omega_dot", omega_dot, 4.618977689129567e-05),
+            ("raan_dot", raan_dot, -6.209009692116444e-05),
+        ];
+        for (name, value, reference) in expected {
+            assert!(((value - reference) / reference).abs() < 1e-10, "{} test failed: expected {}, got {}", name, reference, value);
+        }
+    }
+
+    #[test]
+    fn test_propagate_reuses_initialization() {
+        // Initialize once, after which the TLE is no longer needed
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+        let tle_copy = tle.clone();
+        drop(tle);
+
+        // The cached RAAN drag coefficient matches its definition
+        let brouwer0 = &sgp4.brouwer0;
+        let raan_drag = -(21./2.) * (brouwer0.n * WGS72.k2 * brouwer0.theta / (brouwer0.a.powi(2) * brouwer0.beta.powi(2))) * sgp4.atm_params.c1;
+        assert_eq!(sgp4.atm_params.raan_drag, raan_drag);
+
+        // Propagating 1000 times from the same initialization matches initializing from the TLE each time
+        for i in 0..1000 {
+            let minutes_since_epoch = i as f64 * 1.44;
+            let state = propagate(&sgp4, minutes_since_epoch).unwrap();
+            if i % 100 == 0 {
+                assert_eq!(state, propagate_from_tle(&tle_copy, None, minutes_since_epoch).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_init_sgp4_with_model() {
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+
+        // Each model propagates the same as passing its constants
+        for (model, wgs) in [(WgsModel::Wgs72, WGS72), (WgsModel::Wgs72Old, WGS72OLD), (WgsModel::Wgs84, WGS84)] {
+            let sgp4 = init_sgp4_with_model(&tle, model);
+            assert_eq!(sgp4.wgs, wgs);
+            assert_eq!(propagate(&sgp4, 60.), propagate(&init_sgp4(&tle, Some(&wgs)), 60.));
+        }
+
+        // The default model is the default of init_sgp4
+        assert_eq!(propagate(&init_sgp4_with_model(&tle, WgsModel::default()), 60.), propagate(&init_sgp4(&tle, None), 60.));
+    }
+
+    #[test]
+    fn test_unkozai_mean_motion() {
+        // The Brouwer mean motion of the ISS from the AIAA-2006-6753 initialization
+        let n_kozai = 15.72125391 / XPDOTP; // [rad/min]
+        let n = unkozai_mean_motion(n_kozai, 0.0006703, deg2rad(51.6416), &WGS72);
+        assert!((n - 0.06858914158608223).abs() < 1e-12, "Brouwer mean motion: expected 0.0685891416, got {}", n);
+
+        // The initialized propagator uses the same mean motion
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+        assert_eq!(sgp4.brouwer0.n, n);
+
+        // The J2 correction vanishes at the critical inclination and changes sign across it
+        let critical = (1_f64 / 3.).sqrt().acos();
+        assert!((unkozai_mean_motion(n_kozai, 0.01, critical, &WGS72) - n_kozai).abs() < 1e-15);
+        assert!(unkozai_mean_motion(n_kozai, 0.01, 0., &WGS72) < n_kozai);
+        assert!(unkozai_mean_motion(n_kozai, 0.01, deg2rad(90.), &WGS72) > n_kozai);
+    }
+
+    #[test]
+    fn test_propagate_from_tle() {
+        // Define the TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+
+        // The one-shot propagation matches the two-step path, with the default and a custom WGS model
+        let sgp4 = init_sgp4(&tle, None);
+        let sgp4_wgs84 = init_sgp4(&tle, Some(&WGS84));
+        for minutes in [-60., 0., 90., 1440.] {
+            assert_eq!(propagate_from_tle(&tle, None, minutes), propagate(&sgp4, minutes));
+            assert_eq!(propagate_from_tle(&tle, Some(&WGS84), minutes), propagate(&sgp4_wgs84, minutes));
+        }
+
+        // An epoch past the end of the year parses, and is reported rather than panicking
+        let line1 = "1 25544U 98067A   08464.21782528 -.00002182  00000-0 -11606-4 0  2926";
+        let tle = from_lines(line1, line2, None).unwrap();
+        assert!(matches!(propagate_from_tle(&tle, None, 0.), Err(PropagationError::InvalidEpoch { .. })));
+    }
+
+    #[test]
+    fn test_init_sgp4_default_wgs() {
+        // Define the TLE (AIAA-2006-6753 verification case, eccentric near-Earth orbit)
+        let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let tle = from_lines(line1, line2, None).unwrap();
+
+        // The default model is WGS-72, which reproduces the published vectors
+        let sgp4 = init_sgp4(&tle, None);
+        assert_eq!(sgp4.wgs, WGS72);
+        assert_state(&sgp4, 360., [-7154.03120202, -3783.17682504, -3536.19412294], [4.741887409, -4.151817765, -2.093935425]);
+        assert_state(&sgp4, 1440., [-938.55923943, -6268.18748831, -4294.02924751], [7.536105209, -0.427127707, 0.989878080]);
+
+        // WGS-84 misses them by far more than the verification tolerance
+        let sgp4_wgs84 = init_sgp4(&tle, Some(&WGS84));
+        let r_expect = Vector3::new(-938.55923943, -6268.18748831, -4294.02924751);
+        let miss = (propagate(&sgp4_wgs84, 1440.).unwrap().position - r_expect).magnitude();
+        assert!(miss > 1e-3, "WGS-84 miss: {} km", miss);
+    }
+
+    #[test]
+    fn test_propagate_grid_flat() {
+        // Define the TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+
+        // Each row matches the state of propagate
+        let times = [-30., 0., 45.5, 1440.];
+        let mut out = [f64::NAN; 24];
+        propagate_grid_flat(&sgp4, &times, &mut out).unwrap();
+        for (minutes, row) in times.iter().zip(out.chunks(6)) {
+            let state = propagate(&sgp4, *minutes).unwrap();
+            assert_eq!(row, [state.position.x, state.position.y, state.position.z, state.velocity.x, state.velocity.y, state.velocity.z]);
+        }
+
+        // No times fills an empty buffer
+        assert_eq!(propagate_grid_flat(&sgp4, &[], &mut []), Ok(()));
+
+        // A buffer of the wrong length is rejected untouched
+        let mut short = [0.; 23];
+        assert_eq!(propagate_grid_flat(&sgp4, &times, &mut short), Err(PropagationError::BufferLengthMismatch { expected: 24, actual: 23 }));
+        assert!(short.iter().all(|x| *x == 0.));
+        let mut long = [0.; 25];
+        assert_eq!(propagate_grid_flat(&sgp4, &times, &mut long), Err(PropagationError::BufferLengthMismatch { expected: 24, actual: 25 }));
+    }
+
+    #[test]
+    fn test_propagate_canonical() {
+        // Define the TLE (AIAA-2006-6753 verification case, high eccentricity)
+        let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let tle = from_lines(line1, line2, None).unwrap();
+
+        // The canonical state scales to the km and km/s state, for each WGS model
+        for wgs in [WGS72, WGS72OLD, WGS84] {
+            let sgp4 = init_sgp4(&tle, Some(&wgs));
+            for minutes in [0., 360., 1440., -720.] {
+                let (position, velocity) = propagate_canonical(&sgp4, minutes).unwrap();
+                let state = propagate(&sgp4, minutes).unwrap();
+                assert_eq!(position * wgs.r_earth_eq, state.position);
+                assert_eq!(velocity * (wgs.r_earth_eq / 60.), state.velocity);
+
+                // The radius is below two Earth radii and the speed a few hundredths of an Earth radius per minute
+                assert!(position.magnitude() > 1. && position.magnitude() < 2.);
+                assert!(velocity.magnitude() > 0.03 && velocity.magnitude() < 0.1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_kepler() {
+        // Kepler's equation holds across eccentricities and revolutions, up to Molniya-like orbits
+        for ecc in [0., 0.5, 0.9] {
+            for mean_anom in [-7., -0.5, 0., 1e-3, 0.3, 3., PI, 5.9, 13.] {
+                let ecc_anom = solve_kepler(mean_anom, ecc, 1e-12, 50).unwrap();
+                assert!((ecc_anom - ecc * ecc_anom.sin() - mean_anom).abs() < 1e-10, "Kepler's equation failed for M = {}, e = {}", mean_anom, ecc);
+            }
+        }
+
+        // A circular orbit returns the mean anomaly
+        assert_eq!(solve_kepler(1.2, 0., 1e-12, 50), Ok(1.2));
+
+        // Near-parabolic orbits close to perigee still converge
+        let ecc_anom = solve_kepler(1e-4, 0.999, 1e-12, 100).unwrap();
+        assert!((ecc_anom - 0.999 * ecc_anom.sin() - 1e-4).abs() < 1e-12);
+
+        // Non-convergence and invalid eccentricities are reported
+        assert_eq!(solve_kepler(3., 0.9, 1e-12, 1), Err(PropagationError::ConvergenceFailure { iterations: 1 }));
+        assert!(matches!(solve_kepler(f64::NAN, 0.5, 1e-12, 50), Err(PropagationError::ConvergenceFailure { iterations: 50 })));
+        assert!(matches!(solve_kepler(1., 1., 1e-12, 50), Err(PropagationError::EccentricityOutOfRange { .. })));
+        assert!(matches!(solve_kepler(1., -0.1, 1e-12, 50), Err(PropagationError::EccentricityOutOfRange { .. })));
+    }
+
+    #[test]
+    fn test_hermite_interpolate() {
+        // Define the TLE
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+        let s0 = (30., propagate(&sgp4, 30.).unwrap());
+        let s1 = (31., propagate(&sgp4, 31.).unwrap());
+
+        // The endpoints reproduce the inputs
+        for (t, expected) in [s0, s1] {
+            let state = hermite_interpolate(&s0, &s1, t).unwrap();
+            assert!((state.position - expected.position).magnitude() < 1e-9);
+            assert!((state.velocity - expected.velocity).magnitude() < 1e-12);
+        }
+
+        // The midpoint agrees with a true propagation to within a meter and a decimeter per second
+        let state = hermite_interpolate(&s0, &s1, 30.5).unwrap();
+        let expected = propagate(&sgp4, 30.5).unwrap();
+        assert!((state.position - expected.position).magnitude() < 1e-3, "Position error {} km", (state.position - expected.position).magnitude());
+        assert!((state.velocity - expected.velocity).magnitude() < 1e-4, "Velocity error {} km/s", (state.velocity - expected.velocity).magnitude());
+        assert_eq!(state.coordinate_frame, CoordinateFrame::TEME);
+        assert_eq!(state.time_min, 30.5);
+        assert_eq!(expected.time_min, 30.5);
+
+        // Times outside of the interval and reversed intervals are errors
+        assert_eq!(hermite_interpolate(&s0, &s1, 31.5), Err(PropagationError::InterpolationOutOfRange { time: 31.5, start: 30., stop: 31. }));
+        assert_eq!(hermite_interpolate(&s1, &s0, 30.5), Err(PropagationError::InvalidTimeRange { start: 31., stop: 30. }));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_propagate_at() {
+        // Define the TLE
+        let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // Propagating to the epoch matches propagating zero minutes
+        let epoch = tle.epoch_chrono().unwrap();
+        assert_eq!(propagate_at(&sgp4, epoch), propagate(&sgp4, 0.));
+
+        // Propagating to 6 hours past epoch matches the verification vector
+        let state = propagate_at(&sgp4, epoch + chrono::Duration::hours(6)).unwrap();
+        let r_expect = Vector3::new(-7154.03120202, -3783.17682504, -3536.19412294);
+        assert!((state.position - r_expect).magnitude() < 1e-6);
+
+        // Propagating far from the epoch is reported without the log feature
+        let minutes = minutes_since_epoch_chrono(&tle, epoch + chrono::Duration::days(15)).unwrap();
+        assert!(has_stale_epoch(minutes));
+        assert!(!has_stale_epoch(minutes_since_epoch_chrono(&tle, epoch - chrono::Duration::days(14)).unwrap()));
+    }
+
+    #[test]
+    fn test_has_stale_epoch() {
+        // The accuracy limit is 14 days either side of the epoch
+        assert!(!has_stale_epoch(0.));
+        assert!(!has_stale_epoch(14. * 1440.));
+        assert!(!has_stale_epoch(-14. * 1440.));
+        assert!(has_stale_epoch(14. * 1440. + 1.));
+        assert!(has_stale_epoch(-14. * 1440. - 1.));
+
+        // A time that is not finite is never accurate
+        assert!(has_stale_epoch(f64::INFINITY));
+        assert!(has_stale_epoch(f64::NAN));
+    }
+
+    #[test]
+    fn test_try_init_sgp4_invalid_epoch() {
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+
+        // A valid epoch initializes as with init_sgp4
+        let sgp4 = try_init_sgp4(&tle, None).unwrap();
+        assert_eq!((sgp4.jd0, sgp4.jdfrac0), (init_sgp4(&tle, None).jd0, init_sgp4(&tle, None).jdfrac0));
+
+        // Epoch days before the start or past the end of the year are reported instead of panicking
+        for epoch_day in [0., 0.5, 367., 400.] {
+            let tle_bad = Tle { epoch_day: epoch_day, ..tle.clone() };
+            assert!(matches!(try_init_sgp4(&tle_bad, None), Err(PropagationError::InvalidEpoch { .. })), "Epoch day {}", epoch_day);
+        }
+    }
+
+    #[test]
+    fn test_minutes_since_epoch() {
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+
+        // The epoch is zero, to the resolution of the summed Julian date
+        let jd_epoch = tle.epoch_julian_date().unwrap();
+        assert!(minutes_since_epoch(&tle, jd_epoch).unwrap().abs() < 1e-6);
+
+        // Times before the epoch are negative, and whole days are exact multiples of 1440 minutes
+        let minutes = minutes_since_epoch(&tle, 2454729.5).unwrap();
+        assert!((minutes - -(0.51782528 * 1440.)).abs() < 1e-6);
+        assert!((minutes_since_epoch(&tle, 2454730.5).unwrap() - (minutes + 1440.)).abs() < 1e-6);
+        assert!((minutes_since_epoch(&tle, 2454728.5).unwrap() - (minutes - 1440.)).abs() < 1e-6);
+
+        // An epoch outside of the year is reported
+        let tle = Tle { epoch_day: 367., ..tle };
+        assert!(matches!(minutes_since_epoch(&tle, 2454729.5), Err(PropagationError::InvalidEpoch { .. })));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_minutes_since_epoch_chrono() {
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+
+        // The epoch is zero, and the chrono and Julian date forms agree
+        let epoch = tle.epoch_chrono().unwrap();
+        assert_eq!(minutes_since_epoch_chrono(&tle, epoch), Ok(0.));
+        assert_eq!(minutes_since_epoch_chrono(&tle, epoch - chrono::Duration::minutes(90)), Ok(-90.));
+        let midnight = minutes_since_epoch_chrono(&tle, epoch.date_naive().and_hms_opt(0, 0, 0).unwrap().and_utc()).unwrap();
+        assert!((midnight - minutes_since_epoch(&tle, 2454729.5).unwrap()).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_propagate_times() {
+        // Define the TLE
+        let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // Propagate to three out-of-order times
+        let epoch = tle.epoch_chrono().unwrap();
+        let times = [epoch + chrono::Duration::hours(6), epoch - chrono::Duration::minutes(45), epoch + chrono::Duration::seconds(90)];
+        let states = propagate_times(&sgp4, &times);
+
+        // Each state matches propagating to its time individually
+        assert_eq!(states.len(), times.len());
+        for (state, when) in states.iter().zip(times) {
+            assert_eq!(*state, propagate_at(&sgp4, when));
+            assert!(state.is_ok());
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_propagate_catalog_par() {
+        // Initialize every satellite in the test file
+        let catalog: Vec<Sgp4> = crate::tle::from_file("assets/test.tle").iter().map(|tle| init_sgp4(tle, None)).collect();
+        assert!(catalog.len() > 10);
+
+        // The parallel states match the serial states element for element
+        for minutes in [0., 360., 1440.] {
+            let states = propagate_catalog_par(&catalog, minutes);
+            let serial: Vec<Result<StateVector, PropagationError>> = catalog.iter().map(|sgp4| propagate(sgp4, minutes)).collect();
+            assert_eq!(states, serial);
+        }
+
+        // An empty catalog has no states
+        assert!(propagate_catalog_par(&[], 0.).is_empty());
+    }
+}
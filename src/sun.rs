@@ -0,0 +1,493 @@
+// Module for the position of the Sun and the shadow of the Earth
+
+// ------------------
+// External Libraries
+// ------------------
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
+
+// ------------------
+// Internal Libraries
+// ------------------
+use crate::common::Wgs;
+use crate::math::{normalize_deg_pm_180, Deg, Rad, Vector3};
+use crate::time::gmst;
+#[cfg(feature = "std")]
+use crate::sgp4::{count_range_steps, propagate, PropagationError, Sgp4};
+
+// -----
+// Enums
+// -----
+
+/// A crossing of the boundary of the Earth's shadow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EclipseKind {
+    /// The satellite passes from sunlight into the shadow
+    Entry,
+    /// The satellite passes from the shadow into sunlight
+    Exit,
+}
+
+// ---------
+// Constants
+// ---------
+
+/// The astronomical unit \[km\]
+///
+/// References
+/// - [IAU 2012 Resolution B2](https://www.iau.org/static/resolutions/IAU2012_English.pdf)
+const AU: f64 = 149597870.7;
+
+/// The time resolution of the refined shadow crossings \[min\]
+#[cfg(feature = "std")]
+const ECLIPSE_TIME_TOLERANCE: f64 = 1e-6;
+
+// ---------
+// Functions
+// ---------
+
+/// Calculate the position of the Sun with a low-precision solar ephemeris
+///
+/// The Sun's ecliptic longitude and distance are found from series in its mean anomaly and rotated into the mean
+/// equator and equinox of date by the obliquity of the ecliptic. The position is accurate to about 0.01 degrees
+/// between 1950 and 2050, which is ample for shadow and lighting predictions.
+///
+/// # Arguments
+/// * `jd` - The Julian date \[days\]
+///
+/// # Returns
+/// * `Vector3` - The geocentric position of the Sun in the mean equator and equinox of date \[km\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::sun::sun_position_eci;
+///
+/// // The Sun is about one astronomical unit from the Earth
+/// let sun = sun_position_eci(2453827.5);
+/// assert!((sun.magnitude() / 149597870.7 - 1.).abs() < 0.02);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn sun_position_eci(jd: f64) -> Vector3 {
+    // Julian centuries from J2000
+    let t = (jd - 2451545.) / 36525.;
+
+    // Mean longitude and mean anomaly of the Sun
+    let mean_lon = 280.460 + 36000.771 * t; // [deg]
+    let mean_anom = (357.5291092 + 35999.05034 * t).to_radians(); // [rad]
+
+    // Ecliptic longitude, distance and obliquity of the ecliptic
+    let ecl_lon = (mean_lon + 1.914666471 * mean_anom.sin() + 0.019994643 * (2. * mean_anom).sin()).to_radians(); // [rad]
+    let r = 1.000140612 - 0.016708617 * mean_anom.cos() - 0.000139589 * (2. * mean_anom).cos(); // [AU]
+    let obliquity = (23.439291 - 0.0130042 * t).to_radians(); // [rad]
+
+    // Rotate from the ecliptic to the equator
+    let (sin_lon, cos_lon) = ecl_lon.sin_cos();
+    let (sin_obl, cos_obl) = obliquity.sin_cos();
+    return Vector3::new(cos_lon, cos_obl * sin_lon, sin_obl * sin_lon) * (r * AU);
+}
+
+/// Calculate the sub-solar point, where the Sun is at the zenith
+///
+/// The Sun's declination is the latitude of the point, and its right ascension less the Greenwich mean sidereal time
+/// is the longitude. The Sun is found with [`sun_position_eci`], and the Julian date is used as UT1 for the sidereal
+/// time, so the point is accurate to about 0.01 degrees. The latitude is geocentric, which is adequate for shading a
+/// map.
+///
+/// # Arguments
+/// * `jd` - The Julian date \[days\]
+///
+/// # Returns
+/// * `(lat, lon)` - The latitude in \[-23.5, 23.5\] degrees and longitude in \[-180, 180) degrees of the sub-solar point
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::sun::subsolar_point;
+/// use Rusty_SGP4::time::julian_date;
+///
+/// // Near the June solstice the Sun is overhead on the Tropic of Cancer
+/// let (lat, _lon) = subsolar_point(julian_date(2024, 6, 20, 20, 51, 0.));
+/// assert!((lat.0 - 23.44).abs() < 0.01);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn subsolar_point(jd: f64) -> (Deg, Deg) {
+    // Calculate the declination and right ascension of the Sun
+    let sun = sun_position_eci(jd); // [km]
+    let declination = (sun.z / sun.magnitude()).asin(); // [rad]
+    let right_ascension = sun.y.atan2(sun.x); // [rad]
+
+    // Rotate the right ascension into a longitude and wrap to [-180, 180)
+    let lon = normalize_deg_pm_180(Deg::from(Rad(right_ascension - gmst(jd))).0); // [deg]
+
+    return (Deg::from(Rad(declination)), Deg(lon));
+}
+
+/// Check if a point on the Earth's surface is in daylight
+///
+/// The point is in daylight when the center of the Sun is above its horizon, using a spherical Earth. Refraction and
+/// the solar disk are neglected, so sunrise and sunset are a few minutes off, which is invisible on a map. Evaluated
+/// at the sub-satellite point, it tells whether the ground below a satellite is lit.
+///
+/// # Arguments
+/// * `lat` - The latitude of the point
+/// * `lon` - The longitude of the point
+/// * `jd` - The Julian date \[days\]
+///
+/// # Returns
+/// * `bool` - Whether the Sun is above the horizon at the point
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::math::Deg;
+/// use Rusty_SGP4::sun::{is_point_in_daylight, subsolar_point};
+///
+/// // The sub-solar point is in daylight and its antipode is in darkness
+/// let jd = 2453827.5;
+/// let (lat, lon) = subsolar_point(jd);
+/// assert!(is_point_in_daylight(lat, lon, jd));
+/// assert!(!is_point_in_daylight(Deg(-lat.0), Deg(lon.0 + 180.), jd));
+/// ```
+pub fn is_point_in_daylight(lat: Deg, lon: Deg, jd: f64) -> bool {
+    // Compare the point with the sub-solar point on the unit sphere
+    let (sun_lat, sun_lon) = subsolar_point(jd);
+    let (sin_lat, cos_lat) = Rad::from(lat).0.sin_cos();
+    let (sin_sun_lat, cos_sun_lat) = Rad::from(sun_lat).0.sin_cos();
+    let cos_angle = sin_lat * sin_sun_lat + cos_lat * cos_sun_lat * Rad::from(Deg(lon.0 - sun_lon.0)).0.cos();
+
+    return cos_angle > 0.;
+}
+
+/// Check if a satellite is lit by the Sun with a cylindrical shadow model
+///
+/// The Earth's shadow is modeled as a cylinder of the Earth's equatorial radius extending away from the Sun, so the
+/// satellite is lit unless it is on the night side and within one Earth radius of the Earth-Sun line. The penumbra
+/// is neglected, so the transitions are sharp.
+///
+/// # Arguments
+/// * `sat_pos_eci` - The geocentric position of the satellite in an inertial frame \[km\]
+/// * `sun_pos_eci` - The geocentric position of the Sun in the same frame \[km\]
+/// * `wgs` - The WGS model defining the Earth's radius
+///
+/// # Returns
+/// * `bool` - Whether the satellite is sunlit
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::WGS72;
+/// use Rusty_SGP4::math::Vector3;
+/// use Rusty_SGP4::sun::{is_sunlit, sun_position_eci};
+///
+/// // A satellite between the Earth and the Sun is lit
+/// let sun = sun_position_eci(2453827.5);
+/// let sat = sun * (7000. / sun.magnitude());
+/// assert!(is_sunlit(sat, sun, &WGS72));
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn is_sunlit(sat_pos_eci: Vector3, sun_pos_eci: Vector3, wgs: &Wgs) -> bool {
+    // The day side is always lit
+    let sun_dir = sun_pos_eci * (1. / sun_pos_eci.magnitude());
+    let along = sat_pos_eci.dot(&sun_dir); // [km]
+    if along >= 0. {
+        return true;
+    }
+
+    // On the night side, check the distance from the Earth-Sun line
+    let across = (sat_pos_eci - sun_dir * along).magnitude(); // [km]
+    return across > wgs.r_earth_eq;
+}
+
+/// Find the times a satellite enters and exits the Earth's shadow over a search window
+///
+/// The illumination is sampled every `step_min` minutes from `start_min` up to and including `stop_min` with the
+/// cylindrical shadow model of [`is_sunlit`], and each change between samples is refined by bisection. The Sun is
+/// found with [`sun_position_eci`] at each time, so the window may span days. The step must be short compared to the
+/// time spent in sunlight or shadow (a few minutes for low Earth orbits), or a brief eclipse can fall between samples.
+///
+/// # Arguments
+/// * `sgp4` - The SGP4 parameters
+/// * `start_min` - The start of the search window since the TLE epoch \[min\]
+/// * `stop_min` - The end of the search window since the TLE epoch \[min\]
+/// * `step_min` - The time between the coarse samples \[min\]
+///
+/// # Returns
+/// * `Result<Vec<(f64, EclipseKind)>, PropagationError>` - The time of each crossing since the TLE epoch \[min\] and
+///   its direction, in time order
+///
+/// # Errors
+/// Returns `PropagationError::InvalidStep` if the step is not positive.
+/// Returns `PropagationError::InvalidTimeRange` if the start or stop time is not finite, or the start time is after
+/// the stop time.
+/// Returns `PropagationError::TooManySamples` if the window holds more than 2^30 steps.
+/// Returns the first `PropagationError` of [`propagate`] if the SGP4 theory fails at any time.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+/// use Rusty_SGP4::sgp4::init_sgp4;
+/// use Rusty_SGP4::sun::eclipse_events;
+///
+/// // Define the ISS TLE
+/// let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let sgp4 = init_sgp4(&from_lines(line1, line2, None).unwrap(), None);
+///
+/// // The ISS passes through the shadow once per orbit
+/// let events = eclipse_events(&sgp4, 0., 92., 1.)?;
+/// assert_eq!(events.len(), 2);
+/// # Ok::<(), Rusty_SGP4::sgp4::PropagationError>(())
+/// ```
+#[cfg(feature = "std")]
+pub fn eclipse_events(sgp4: &Sgp4, start_min: f64, stop_min: f64, step_min: f64) -> Result<Vec<(f64, EclipseKind)>, PropagationError> {
+    // Validate the step and the time range
+    let n_steps = count_range_steps(start_min, stop_min, step_min)?;
+
+    // The illumination at a time since the epoch
+    let sunlit = |t: f64| -> Result<bool, PropagationError> {
+        let state = propagate(sgp4, t)?;
+        let sun = sun_position_eci(sgp4.jd0 + (sgp4.jdfrac0 + t / 1440.)); // [km]
+        return Ok(is_sunlit(state.position, sun, &sgp4.wgs));
+    };
+
+    // Sample the search window, including its end
+    let sample_time = |i: usize| if i > n_steps { stop_min } else { start_min + i as f64 * step_min }; // [min]
+    let n_samples = if sample_time(n_steps) < stop_min { n_steps + 2 } else { n_steps + 1 };
+
+    // Refine every change of illumination between samples
+    let mut events = Vec::new();
+    let mut lit_prev = sunlit(start_min)?;
+    for i in 1..n_samples {
+        let lit = sunlit(sample_time(i))?;
+        if lit != lit_prev {
+            // Bisect the bracket, keeping the illumination of its ends
+            let (mut t_lo, mut t_hi) = (sample_time(i - 1), sample_time(i)); // [min]
+            while t_hi - t_lo > ECLIPSE_TIME_TOLERANCE {
+                let t_mid = 0.5 * (t_lo + t_hi); // [min]
+                if sunlit(t_mid)? == lit_prev {
+                    t_lo = t_mid;
+                } else {
+                    t_hi = t_mid;
+                }
+            }
+            let kind = if lit_prev { EclipseKind::Entry } else { EclipseKind::Exit };
+            events.push((0.5 * (t_lo + t_hi), kind));
+        }
+        lit_prev = lit;
+    }
+
+    return Ok(events);
+}
+
+/// Estimate the apparent visual magnitude of a satellite seen by an observer
+///
+/// The standard magnitude follows the convention of McCants' catalog, the brightness at a range of 1000 km when half
+/// illuminated (a phase angle of 90 degrees). It is scaled by the inverse square of the range and by the phase
+/// function of a diffuse sphere, so
+///
+/// m = std_mag + 5 log10(range / 1000) − 2.5 log10(sin φ + (π − φ) cos φ)
+///
+/// where φ is the angle between the Sun and the observer as seen from the satellite. Whether the observer's sky is
+/// dark enough to see the satellite is not considered.
+///
+/// # Arguments
+/// * `sat_pos_eci` - The geocentric position of the satellite in an inertial frame \[km\]
+/// * `observer_eci` - The geocentric position of the observer in the same frame \[km\]
+/// * `sun_pos_eci` - The geocentric position of the Sun in the same frame \[km\]
+/// * `std_mag` - The standard magnitude of the satellite, at 1000 km and a phase angle of 90 degrees
+/// * `wgs` - The WGS model defining the Earth's radius for the shadow test
+///
+/// # Returns
+/// * `Option<f64>` - The apparent visual magnitude, or `None` if the satellite is in the Earth's shadow (see
+///   [`is_sunlit`]) or the Sun is directly behind it
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::common::WGS72;
+/// use Rusty_SGP4::math::Vector3;
+/// use Rusty_SGP4::sun::{estimated_magnitude, sun_position_eci};
+///
+/// // A satellite 1000 km from the observer at a phase angle of 90 degrees has its standard magnitude
+/// let sun = sun_position_eci(2453827.5);
+/// let sun_dir = sun * (1. / sun.magnitude());
+/// let sat = sun_dir * 7000.;
+/// let side = Vector3::new(-sun_dir.y, sun_dir.x, 0.);
+/// let observer = sat + side * (1000. / side.magnitude());
+/// let mag = estimated_magnitude(sat, observer, sun, 4., &WGS72).unwrap();
+/// assert!((mag - 4.).abs() < 1e-3);
+/// ```
+///
+/// # References
+/// - [Satellite Magnitudes by McCants](https://www.prismnet.com/~mmccants/tles/mccdesc.html)
+pub fn estimated_magnitude(sat_pos_eci: Vector3, observer_eci: Vector3, sun_pos_eci: Vector3, std_mag: f64, wgs: &Wgs) -> Option<f64> {
+    // A satellite in the Earth's shadow is not visible
+    if !is_sunlit(sat_pos_eci, sun_pos_eci, wgs) {
+        return None;
+    }
+
+    // The range and the phase angle between the Sun and the observer seen from the satellite
+    let to_sun = sun_pos_eci - sat_pos_eci; // [km]
+    let to_observer = observer_eci - sat_pos_eci; // [km]
+    let range = to_observer.magnitude(); // [km]
+    let cos_phase = (to_sun.dot(&to_observer) / (to_sun.magnitude() * range)).clamp(-1., 1.);
+    let phase = cos_phase.acos(); // [rad]
+
+    // The illuminated fraction seen by the observer vanishes with the Sun behind the satellite
+    let phase_function = phase.sin() + (core::f64::consts::PI - phase) * cos_phase;
+    if phase_function <= 0. {
+        return None;
+    }
+
+    return Some(std_mag + 5. * (range / 1000.).log10() - 2.5 * phase_function.log10());
+}
+
+// ----------
+// Unit Tests
+// ----------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::WGS72;
+
+    #[test]
+    fn test_sun_position_eci() {
+        // Example 5-1 from Vallado, April 2nd 2006 at 00:00 UTC
+        let sun = sun_position_eci(2453827.5) * (1. / AU);
+        let expected = Vector3::new(0.9771945, 0.1924424, 0.0834308); // [AU]
+        assert!((sun - expected).magnitude() < 1e-5, "Sun position: expected {:?} AU, got {:?}", expected, sun);
+    }
+
+    #[test]
+    fn test_subsolar_point() {
+        // The sub-solar latitude follows the solar declination over a year, from the March equinox of 2024
+        let jd_equinox = 2460389.629;
+        for day in 0..366 {
+            let jd = jd_equinox + day as f64;
+            let sun = sun_position_eci(jd);
+            let (lat, lon) = subsolar_point(jd);
+            assert!((lat.0 - (sun.z / sun.magnitude()).asin().to_degrees()).abs() < 1e-12);
+            assert!(lat.0.abs() < 23.45);
+            assert!((-180. ..180.).contains(&lon.0));
+        }
+
+        // It crosses the equator at the equinoxes and reaches the tropics at the solstices
+        assert!(subsolar_point(jd_equinox).0.0.abs() < 0.02);
+        assert!((subsolar_point(2460482.369).0.0 - 23.44).abs() < 0.01);
+        assert!(subsolar_point(2460576.030).0.0.abs() < 0.02);
+        assert!((subsolar_point(2460665.889).0.0 + 23.44).abs() < 0.01);
+
+        // The Sun is over the Greenwich meridian near noon, within the equation of time
+        let (_lat, lon) = subsolar_point(2460390.0);
+        assert!(lon.0.abs() < 5., "Longitude at noon: {}", lon.0);
+    }
+
+    #[test]
+    fn test_is_point_in_daylight() {
+        let jd = 2460482.369;
+        let (lat, lon) = subsolar_point(jd);
+
+        // Points a little either side of the terminator
+        assert!(is_point_in_daylight(Deg(lat.0 - 89.), lon, jd));
+        assert!(!is_point_in_daylight(Deg(lat.0 - 91.), lon, jd));
+
+        // The north pole is lit through the June solstice and the south pole is dark
+        assert!(is_point_in_daylight(Deg(90.), Deg(0.), jd));
+        assert!(!is_point_in_daylight(Deg(-90.), Deg(0.), jd));
+    }
+
+    #[test]
+    fn test_is_sunlit() {
+        let sun = sun_position_eci(2453827.5);
+        let sun_dir = sun * (1. / sun.magnitude());
+        let perp = Vector3::new(-sun_dir.y, sun_dir.x, 0.);
+        let perp = perp * (1. / perp.magnitude());
+
+        // A satellite on the day side is lit
+        assert!(is_sunlit(sun_dir * 7000., sun, &WGS72));
+
+        // A satellite directly behind the Earth from the Sun is in shadow
+        assert!(!is_sunlit(sun_dir * -7000., sun, &WGS72));
+
+        // A satellite on the night side is lit once it is clear of the shadow cylinder
+        assert!(!is_sunlit(sun_dir * -7000. + perp * (WGS72.r_earth_eq - 1.), sun, &WGS72));
+        assert!(is_sunlit(sun_dir * -7000. + perp * (WGS72.r_earth_eq + 1.), sun, &WGS72));
+
+        // A satellite over the terminator is lit
+        assert!(is_sunlit(perp * 7000., sun, &WGS72));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_eclipse_events() {
+        use crate::sgp4::init_sgp4;
+        use crate::tle::from_lines;
+
+        let line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(line1, line2, None).unwrap();
+        let sgp4 = init_sgp4(&tle, None);
+
+        // Over a day the entries and exits alternate, one of each per orbit
+        let events = eclipse_events(&sgp4, 0., 1440., 1.).unwrap();
+        assert!(events.len() >= 30, "{} events", events.len());
+        for pair in events.windows(2) {
+            assert_ne!(pair[0].1, pair[1].1);
+        }
+        for pair in events.windows(3) {
+            assert!((pair[2].0 - pair[0].0 - tle.period_minutes()).abs() < 1., "Spacing: {} min", pair[2].0 - pair[0].0);
+        }
+
+        // Each crossing is on the shadow boundary
+        for (t, kind) in &events {
+            let lit = |t: f64| is_sunlit(propagate(&sgp4, t).unwrap().position, sun_position_eci(sgp4.jd0 + (sgp4.jdfrac0 + t / 1440.)), &WGS72);
+            assert_eq!(lit(t - 1e-4), *kind == EclipseKind::Entry);
+            assert_eq!(lit(t + 1e-4), *kind == EclipseKind::Exit);
+        }
+
+        // A window in constant illumination has no crossings, and invalid windows are rejected
+        let (t_entry, _) = events[0];
+        assert!(eclipse_events(&sgp4, t_entry + 1., t_entry + 2., 0.5).unwrap().is_empty());
+        assert_eq!(eclipse_events(&sgp4, 0., 10., 0.), Err(PropagationError::InvalidStep { step: 0. }));
+        assert_eq!(eclipse_events(&sgp4, 10., 0., 1.), Err(PropagationError::InvalidTimeRange { start: 10., stop: 0. }));
+        assert_eq!(eclipse_events(&sgp4, 0., f64::INFINITY, 1.), Err(PropagationError::InvalidTimeRange { start: 0., stop: f64::INFINITY }));
+        assert!(matches!(eclipse_events(&sgp4, 0., 1e12, 1.), Err(PropagationError::TooManySamples { .. })));
+    }
+
+    #[test]
+    fn test_estimated_magnitude() {
+        let sun = sun_position_eci(2453827.5);
+        let sun_dir = sun * (1. / sun.magnitude());
+        let perp = Vector3::new(-sun_dir.y, sun_dir.x, 0.);
+        let perp = perp * (1. / perp.magnitude());
+
+        // An observer after dusk, 15 degrees past the terminator, sees a sunlit satellite 600 km away, 53 degrees up
+        // towards the Sun
+        let (sin_dusk, cos_dusk) = 15_f64.to_radians().sin_cos();
+        let observer = (perp * cos_dusk - sun_dir * sin_dusk) * WGS72.r_earth_eq;
+        let zenith = perp * cos_dusk - sun_dir * sin_dusk;
+        let sunward = perp * sin_dusk + sun_dir * cos_dusk;
+        let sat = observer + (zenith * 0.8 + sunward * 0.6) * 600.;
+        assert!(is_sunlit(sat, sun, &WGS72));
+        assert!(!is_sunlit(observer, sun, &WGS72));
+
+        // A bright satellite is a naked eye object
+        let mag = estimated_magnitude(sat, observer, sun, 3., &WGS72).unwrap();
+        assert!(mag > 1. && mag < 3., "Magnitude: {}", mag);
+
+        // Doubling the range dims the satellite by 5 log10(2) at the same phase
+        let far_observer = sat + (observer - sat) * 2.;
+        let far_mag = estimated_magnitude(sat, far_observer, sun, 3., &WGS72).unwrap();
+        assert!((far_mag - mag - 5. * 2_f64.log10()).abs() < 1e-9);
+
+        // Full phase is brighter than half phase by 2.5 log10(pi)
+        let full = estimated_magnitude(sun_dir * 7000., sun_dir * 8000., sun, 4., &WGS72).unwrap();
+        assert!((full - (4. - 2.5 * core::f64::consts::PI.log10())).abs() < 1e-6);
+
+        // A satellite in the Earth's shadow is not visible, and one backlit by the Sun is far too faint to see
+        assert_eq!(estimated_magnitude(sun_dir * -7000., sun_dir * -6400., sun, 4., &WGS72), None);
+        assert!(estimated_magnitude(sun_dir * 7000., sun_dir * 6000., sun, 4., &WGS72).is_none_or(|mag| mag > 30.));
+    }
+}
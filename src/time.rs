@@ -3,10 +3,13 @@
 // ------------------
 // External Libraries
 // ------------------
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
 
 // ------------------
 // Internal Libraries
 // ------------------
+use crate::math::normalize_rad_0_2pi;
 
 // -------
 // Structs
@@ -112,6 +115,45 @@ pub enum Timezone {
 // Constants
 // ---------
 
+// ---------------
+// Implementations
+// ---------------
+
+/// Converts a [`chrono::DateTime`] in UTC into a [`DateTime`].
+///
+/// # Examples
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use Rusty_SGP4::time::{DateTime, Timezone};
+///
+/// // Define a chrono datetime
+/// let chrono_datetime = Utc.with_ymd_and_hms(2008, 9, 20, 12, 25, 40).unwrap();
+///
+/// // Convert it into a DateTime
+/// let datetime = DateTime::from(chrono_datetime);
+/// assert_eq!((datetime.year, datetime.month, datetime.day), (2008, 9, 20));
+/// assert_eq!(datetime.timezone, Timezone::UTC);
+/// ```
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for DateTime {
+    fn from(datetime: chrono::DateTime<chrono::Utc>) -> Self {
+        use chrono::{Datelike, Timelike};
+
+        // Fold the sub-second component into the seconds field
+        let second = datetime.second() as f64 + datetime.nanosecond() as f64 * 1e-9;
+
+        DateTime {
+            year: datetime.year(),
+            month: datetime.month() as i32,
+            day: datetime.day() as i32,
+            hour: datetime.hour() as i32,
+            minute: datetime.minute() as i32,
+            second: second,
+            timezone: Timezone::UTC,
+        }
+    }
+}
+
 // ---------
 // Functions
 // ---------
@@ -129,6 +171,7 @@ pub enum Timezone {
 /// * `Result<(f64, f64), DateError>` - On success, returns a tuple containing:
 ///   - `jd` - The Julian day (integer part), a continuous count of days since 4713-01-01 12:00:00 BCE
 ///   - `jdfrac` - The Julian day fraction (fractional part), a continuous count of days since 4713-01-01 12:00:00 BCE
+///
 ///   On error, returns `DateError::DateTooEarly` if the date is before October 10th, 1582
 ///
 /// # Errors
@@ -152,6 +195,7 @@ pub enum Timezone {
 ///
 /// let (jd, jdfrac) = utc2jday(&datetime)?;
 /// let jd_total = jd + jdfrac;
+/// # Ok::<(), Rusty_SGP4::time::DateError>(())
 /// ```
 ///
 /// # References
@@ -166,7 +210,7 @@ pub fn utc2jday(utc_datetime: &DateTime) -> Result<(f64, f64), DateError> {
     let mut jdfrac: f64 = mjdfrac;
 
     // Make JD whole
-    if jdfrac >= 1.0 || jdfrac < 0.0 {
+    if !(0.0..1.0).contains(&jdfrac) {
         jd = jd + jdfrac.floor();
         jdfrac = jdfrac - jdfrac.floor();
     }
@@ -187,6 +231,7 @@ pub fn utc2jday(utc_datetime: &DateTime) -> Result<(f64, f64), DateError> {
 /// * `Result<(f64, f64), DateError>` - On success, returns a tuple containing:
 ///   - `mjd` - The Modified Julian day (integer part), a continuous count of days since 1858-11-17 00:00:00 CE
 ///   - `mjdfrac` - The Modified Julian day fraction (fractional part), a continuous count of days since 1858-11-17 00:00:00 CE
+///
 ///   On error, returns `DateError::DateTooEarly` if the date is before October 10th, 1582
 ///
 /// # Errors
@@ -210,6 +255,7 @@ pub fn utc2jday(utc_datetime: &DateTime) -> Result<(f64, f64), DateError> {
 ///
 /// let (mjd, mjdfrac) = utc2mjday(&datetime)?;
 /// let mjd_total = mjd + mjdfrac;
+/// # Ok::<(), Rusty_SGP4::time::DateError>(())
 /// ```
 ///
 /// # References
@@ -232,7 +278,7 @@ pub fn utc2mjday(utc_datetime: &DateTime) -> Result<(f64, f64), DateError> {
     let day = utc_datetime.day as f64;
     let hour = utc_datetime.hour as f64;
     let minute = utc_datetime.minute as f64;
-    let second = utc_datetime.second as f64;
+    let second = utc_datetime.second;
 
     // Modify month and year to account for leap years, start year in March instead of January
     let year_leap: f64;
@@ -253,7 +299,7 @@ pub fn utc2mjday(utc_datetime: &DateTime) -> Result<(f64, f64), DateError> {
     let mut mjdfrac = (second + minute * 60. + hour * 3600.) / 86400.;
 
     // Validate mjdfrac
-    if mjdfrac >= 1.0 || mjdfrac < 0.0 {
+    if !(0.0..1.0).contains(&mjdfrac) {
         mjd = mjd + mjdfrac.floor();
         mjdfrac = mjdfrac - mjdfrac.floor();
     }
@@ -282,6 +328,7 @@ pub fn utc2mjday(utc_datetime: &DateTime) -> Result<(f64, f64), DateError> {
 ///
 /// // Day 123.5 of 2024 = May 2nd, 2024 at 12:00:00
 /// let datetime = dayofyr2utc(2024, 123.5)?;
+/// # Ok::<(), Rusty_SGP4::time::DateError>(())
 /// ```
 pub fn dayofyr2utc(year: i32, dayofyr: f64) -> Result<DateTime, DateError> {
     // Validate day of year is positive
@@ -321,7 +368,7 @@ pub fn dayofyr2utc(year: i32, dayofyr: f64) -> Result<DateTime, DateError> {
         
         if day_int <= day_count + days_this_month {
             month = (idx + 1) as i32;
-            day = (day_int - day_count) as i32;
+            day = day_int - day_count;
             break;
         }
         day_count += days_this_month;
@@ -392,6 +439,117 @@ pub fn dayofyr2utc(year: i32, dayofyr: f64) -> Result<DateTime, DateError> {
     return Ok(datetime);
 }
 
+/// Convert a UTC datetime to a year and day of year
+///
+/// This is the inverse of [dayofyr2utc]. The day of year is 1-based (1.0 = January 1st at 00:00:00 UTC).
+///
+/// # Arguments
+/// * `datetime` - The datetime as a [DateTime] structure (in UTC)
+///
+/// # Returns
+/// * `Result<(i32, f64), DateError>` - On success, returns a tuple containing:
+///   - `year` - The year
+///   - `dayofyr` - The day of year with fractional component
+///
+/// # Errors
+/// Returns `DateError::DateTooEarly` if the provided date is before October 10th, 1582.
+/// Returns `DateError::DateNotUTC` if the provided date is not in UTC
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::time::{utc2dayofyr, DateTime, Timezone};
+///
+/// // May 2nd, 2024 at 12:00:00 = Day 123.5 of 2024
+/// let datetime = DateTime { year: 2024, month: 5, day: 2, hour: 12, minute: 0, second: 0., timezone: Timezone::UTC };
+/// let (year, dayofyr) = utc2dayofyr(&datetime)?;
+/// assert_eq!((year, dayofyr), (2024, 123.5));
+/// # Ok::<(), Rusty_SGP4::time::DateError>(())
+/// ```
+pub fn utc2dayofyr(utc_datetime: &DateTime) -> Result<(i32, f64), DateError> {
+    // Calculate the MJD of the datetime
+    let (mjd, mjdfrac) = utc2mjday(utc_datetime)?;
+
+    // Calculate the MJD of midnight on January 1st
+    let new_year = DateTime { year: utc_datetime.year, month: 1, day: 1, hour: 0, minute: 0, second: 0., timezone: Timezone::UTC };
+    let (mjd_new_year, _) = utc2mjday(&new_year)?;
+
+    // Count the days since the start of the year
+    let dayofyr = (mjd - mjd_new_year) + mjdfrac + 1.;
+
+    return Ok((utc_datetime.year, dayofyr));
+}
+
+/// Calculate the Julian date from calendar date and time components.
+///
+/// This uses the closed-form expression from Vallado, which is valid for dates between March 1st, 1900 and
+/// February 28th, 2100. Unlike [utc2jday], the time scale is not checked, so the components may be UTC or UT1.
+///
+/// # Arguments
+/// * `year` - The year (1900-2100)
+/// * `month` - The month (1-12)
+/// * `day` - The day of month (1-31)
+/// * `hour` - The hour (0-23)
+/// * `minute` - The minute (0-59)
+/// * `second` - The second with fractional component (0.0-59.999...)
+///
+/// # Returns
+/// * `jd` - The Julian date \[days\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::time::julian_date;
+///
+/// // The J2000.0 epoch
+/// let jd = julian_date(2000, 1, 1, 12, 0, 0.);
+/// assert_eq!(jd, 2451545.0);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn julian_date(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: f64) -> f64 {
+    // Calculate the Julian date at midnight
+    let year = year as f64;
+    let month = month as f64;
+    let jd0 = 367. * year - (7. * (year + ((month + 9.) / 12.).floor()) * 0.25).floor() + (275. * month / 9.).floor() + day as f64 + 1721013.5;
+
+    // Calculate the fraction of the day
+    let jdfrac = ((second / 60. + minute as f64) / 60. + hour as f64) / 24.;
+
+    return jd0 + jdfrac;
+}
+
+/// Calculate the Greenwich mean sidereal time (GMST) from a Julian date.
+///
+/// This uses the IAU 1982 GMST polynomial, which is the model referenced by SGP4.
+///
+/// # Arguments
+/// * `jd_ut1` - The Julian date in UT1 \[days\]
+///
+/// # Returns
+/// * `gmst` - The Greenwich mean sidereal time in [0, 2π) \[rad\]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::time::{gmst, julian_date};
+///
+/// // Calculate GMST at the J2000.0 epoch
+/// let theta = gmst(julian_date(2000, 1, 1, 12, 0, 0.));
+/// assert!((theta - 4.894961212823756).abs() < 1e-9);
+/// ```
+///
+/// # References
+/// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+pub fn gmst(jd_ut1: f64) -> f64 {
+    // Calculate the Julian centuries since J2000.0
+    let tut1 = (jd_ut1 - 2451545.0) / 36525.0; // [centuries]
+
+    // Calculate the Greenwich sidereal time in seconds
+    let temp = -6.2e-6 * tut1.powi(3) + 0.093104 * tut1.powi(2) + (876600.0 * 3600.0 + 8640184.812866) * tut1 + 67310.54841; // [seconds]
+
+    // Convert to radians and wrap to [0, 2pi), 360/86400 = 1/240 degrees per second
+    return normalize_rad_0_2pi((temp / 240.0).to_radians());
+}
+
 // ----------
 // Unit Tests
 // ----------
@@ -399,6 +557,7 @@ pub fn dayofyr2utc(year: i32, dayofyr: f64) -> Result<DateTime, DateError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::f64::consts::PI;
 
     #[test]
     fn test_utc2jday() {
@@ -628,4 +787,63 @@ mod tests {
         assert!(result.is_err(), "Day 0.0 of 2024: should return error for day of year equal to 0, got Ok({:?})", result);
         assert_eq!(result.unwrap_err(), DateError::InvalidDayOfYear, "Day 0.0 of 2024: error should be InvalidDayOfYear");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_utc2dayofyr() {
+        // Day 60.5 of 2024 (February 29, 2024 at 12:00:00)
+        let datetime = DateTime { year: 2024, month: 2, day: 29, hour: 12, minute: 0, second: 0., timezone: Timezone::UTC };
+        assert_eq!(utc2dayofyr(&datetime).unwrap(), (2024, 60.5));
+
+        // Round trips with dayofyr2utc
+        for (year, dayofyr) in [(1959, 100.5), (2008, 264.51782528), (2023, 365.75), (2024, 1.0)] {
+            let (year_out, dayofyr_out) = utc2dayofyr(&dayofyr2utc(year, dayofyr).unwrap()).unwrap();
+            assert_eq!(year_out, year);
+            assert!((dayofyr_out - dayofyr).abs() < 1e-9, "Day of year: expected {}, got {}", dayofyr, dayofyr_out);
+        }
+
+        // Non-UTC datetime
+        let datetime = DateTime { timezone: Timezone::UT1, ..datetime };
+        assert_eq!(utc2dayofyr(&datetime).unwrap_err(), DateError::DateNotUTC);
+    }
+
+    #[test]
+    fn test_julian_date() {
+        // J2000.0 epoch
+        assert_eq!(julian_date(2000, 1, 1, 12, 0, 0.), 2451545.0);
+
+        // Agrees with utc2jday
+        let datetime = DateTime { year: 2024, month: 1, day: 15, hour: 12, minute: 30, second: 45.5, timezone: Timezone::UTC };
+        let (jd, jdfrac) = utc2jday(&datetime).unwrap();
+        assert!((julian_date(2024, 1, 15, 12, 30, 45.5) - (jd + jdfrac)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gmst() {
+        // GMST at the J2000.0 epoch is 280.46061837 degrees
+        let theta = gmst(2451545.0);
+        assert!((theta - 4.894961212823756).abs() < 1e-9, "GMST at J2000: expected 4.894961, got {}", theta);
+
+        // Example 3-5 from Vallado: 1992-08-20 12:14:00 UT1 is 152.578787886 degrees
+        let theta = gmst(julian_date(1992, 8, 20, 12, 14, 0.));
+        assert!((theta.to_degrees() - 152.578787886).abs() < 1e-6, "GMST: expected 152.578787886 deg, got {}", theta.to_degrees());
+
+        // Wrapped to [0, 2pi)
+        for jd in [2400000.5, 2451545.0, 2460000.25, 2470000.75] {
+            let theta = gmst(jd);
+            assert!((0. ..2. * PI).contains(&theta));
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_from_chrono() {
+        use chrono::{TimeZone, Utc};
+
+        // Convert a chrono datetime with a sub-second component
+        let chrono_datetime = Utc.with_ymd_and_hms(2000, 2, 29, 23, 59, 30).unwrap() + chrono::Duration::milliseconds(250);
+        let datetime = DateTime::from(chrono_datetime);
+        assert_eq!((datetime.year, datetime.month, datetime.day, datetime.hour, datetime.minute), (2000, 2, 29, 23, 59));
+        assert!((datetime.second - 30.25).abs() < 1e-9, "Seconds test failed: expected 30.25, got {}", datetime.second);
+        assert_eq!(datetime.timezone, Timezone::UTC);
+    }
+}
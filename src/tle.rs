@@ -3,35 +3,71 @@
 // ------------------
 // External Libraries
 // ------------------
+use core::f64::consts::PI;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use core::fmt;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+#[cfg(feature = "std")]
 use std::fs;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
 
 // ------------------
 // Internal Libraries
 // ------------------
+use crate::common::{eccentric_to_true_anomaly, mean_to_eccentric_anomaly, Wgs};
+use crate::math::{normalize_deg_0_360, Deg, Rad};
+use crate::sgp4::{PropagationError, XPDOTP};
+use crate::time::{dayofyr2utc, utc2dayofyr, utc2jday, utc2mjday, DateTime, DateError};
 
 // -------
 // Structs
 // -------
 
+/// The string type used for the text fields of a [`Tle`].
+///
+/// This is `String` with the `std` feature (enabled by default). Without it, the crate does not allocate and text
+/// fields are stored in a fixed-capacity `heapless::String` sized for the longest field (the 24 character name line).
+#[cfg(feature = "std")]
+pub type TleString = String;
+
+/// The string type used for the text fields of a [`Tle`].
+///
+/// This is `String` with the `std` feature (enabled by default). Without it, the crate does not allocate and text
+/// fields are stored in a fixed-capacity `heapless::String` sized for the longest field (the 24 character name line).
+#[cfg(not(feature = "std"))]
+pub type TleString = heapless::String<24>;
+
 /// Two-Line Element (TLE) orbital parameters for an Earth-orbiting satellite.
 ///
 /// This struct represents the parsed contents of a standard NORAD TLE.
 ///
+/// With the `serde` feature enabled, a [`Tle`] can be serialized and deserialized
+/// directly (e.g. cached as JSON) without re-parsing the two-line text. The
+/// classification is written as a one-character string.
+///
 /// References:
 /// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
-#[derive(Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tle {
     /// Common name of the satellite (e.g., "ISS (ZARYA)")
-    pub common_name: String,
+    pub common_name: TleString,
 
     /// NORAD satellite catalog number
     pub satellite_catalog_number: i32,
 
-    /// Classification (`U` = Unclassified, `C` = Classified, `S` = Secret)
+    /// Classification (`U` = Unclassified, `C` = Classified, `S` = Secret, see [`Classification`])
     pub classification: char,
 
     /// International designator (launch year, launch number, piece)
-    pub international_designator: String,
+    pub international_designator: TleString,
 
     /// Epoch year (e.g. 2024)
     pub epoch_year: i32,
@@ -48,7 +84,7 @@ pub struct Tle {
     /// B* drag term \[1/Earth radii\]
     pub bstar: f64,
 
-    /// Ephemeris type (always zero)
+    /// Ephemeris type (0 in distributed element sets, see [`EphemerisType`])
     pub ephemeris_type: i32,
 
     /// Element set number
@@ -76,463 +112,3734 @@ pub struct Tle {
     pub revolution_number_at_epoch: i64,
 }
 
-// ---------
-// Enums
-// ---------
-
-// ---------
-// Constants
-// ---------
-
-// ---------
-// Functions
-// ---------
-
-/// Builds a [`Tle`] struct from the lines of a Two-Line Element set.
-///
-/// Given the two required TLE lines (line 1 and line 2), and an optional
-/// name line (line 0), this function parses the input into a [`Tle`] struct.
-///
-/// # Arguments
-/// * `line1` - The first TLE data line (NORAD line 1)
-/// * `line2` - The second TLE data line (NORAD line 2)
-/// * `line0` - Optional name line (line 0)
-///
-/// # Panics
-/// * If the TLE lines (1 and 2) are of invalid lengths (must be 69 characters)
-/// * If the TLE lines (1 and 2) are invalid (checksum fails)
+/// Fluent builder for a [`Tle`]
 ///
-/// # Returns
-/// * [`Tle`] - Struct containing the parsed TLE data.
+/// Starts either from scratch ([`TleBuilder::new`]) or from an existing TLE ([`TleBuilder::from_tle`]), sets fields
+/// with the `with_*` methods and validates the orbital elements in [`TleBuilder::build`]. The checksums are not stored
+/// on the [`Tle`], so the lines written by [`to_lines`] for the built TLE always carry correct checksums.
 ///
 /// # Examples
 /// ```rust
-/// // Define the TLE lines
-/// let tle_line0 = "ISS (ZARYA)";
-/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+/// use Rusty_SGP4::tle::{from_lines, TleBuilder};
+///
+/// // Parse the ISS TLE
+/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
 /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
-/// 
-/// // Parse the TLE lines into a TLE struct
-/// let tle = from_lines(tle_line1, tle_line2, Some(tle_line0));
-/// 
-/// // Assert the TLE struct is correct
-/// assert_eq!(tle.satellite_catalog_number, 25544);
-/// ```
+/// let tle = from_lines(tle_line1, tle_line2, None)?;
 ///
-/// # References
-/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
-pub fn from_lines(line1: &str, line2: &str, line0: Option<&str>) -> Tle {
-    // Create mutable TLE struct
-    let mut tle = Tle {
-        common_name: String::new(),
-        satellite_catalog_number: 0,
-        classification: '0',
-        international_designator: String::new(),
-        epoch_year: 0,
-        epoch_day: 0.0,
-        first_derivative_of_mean_motion: 0.0,
-        second_derivative_of_mean_motion: 0.0,
-        bstar: 0.0,
-        ephemeris_type: 0,
-        element_set_number: 0,
-        inclination: 0.0,
-        right_ascension_of_ascending_node: 0.0,
-        eccentricity: 0.0,
-        argument_of_perigee: 0.0,
-        mean_anomaly: 0.0,
-        mean_motion: 0.0,
-        revolution_number_at_epoch: 0,
-    };
+/// // Perturb the mean motion and eccentricity
+/// let perturbed = TleBuilder::from_tle(&tle)
+///     .with_mean_motion(15.72)
+///     .with_eccentricity(0.0007)
+///     .build()?;
+///
+/// // The other fields are carried over from the parsed TLE
+/// assert_eq!(perturbed.mean_motion, 15.72);
+/// assert_eq!(perturbed.inclination, tle.inclination);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TleBuilder {
+    /// The TLE being built
+    tle: Tle,
+}
 
-    // Validate the TLE checksum
-    if !tle_checksum(line1) || !tle_checksum(line2) {
-        println!("TLE lines are invalid");
-        return tle;
-    }
+/// Lazy iterator over the entries of a multi-satellite TLE reader, see [`tle_iter`]
+#[cfg(feature = "std")]
+struct TleRecords<R> {
+    /// The reader the lines are read from
+    reader: R,
 
-    // Extract the common name of the satellite from line 0
-    if let Some(name_line) = line0 {
-        if name_line.len() < 1 || name_line.len() > 24 {
-            println!("TLE string for Line 0 is invalid");
-        } else {
-            tle.common_name = name_line.to_string();
-        }
-    }
-    
-    // Parse through line 1 and populate TLE struct
-    if line1.len() < 69 || line1.len() > 69 {
-        println!("TLE string is invalid");
-    } else {
-        // Line 1
-        // Satellite catalog number
-        tle.satellite_catalog_number = line1[2..7].trim().parse::<i32>().unwrap();
-
-        // Classification
-        tle.classification = line1[7..8].trim().parse::<char>().unwrap();
-
-        // International designator
-        tle.international_designator = line1[9..17].trim().to_string();
-
-        // Epoch year (last two numbers)
-        let yr_two_digit = line1[18..20].trim().parse::<i32>().unwrap();
-        if yr_two_digit < 57 {
-            tle.epoch_year = 2000 + yr_two_digit
-        } else {
-            tle.epoch_year = 1900 + yr_two_digit
-        }
+    /// Non-empty lines read ahead of the current entry (at most 3, of at most [`MAX_STREAM_LINE_LENGTH`] bytes), with
+    /// `None` for a line that is too long or not UTF-8
+    pending: VecDeque<Option<String>>,
 
-        // Epoch day of year
-        tle.epoch_day = line1[20..32].trim().parse::<f64>().unwrap();
+    /// The reader is exhausted or has failed
+    finished: bool,
 
-        // 1st derivative of mean motion [revs/day^2]
-        tle.first_derivative_of_mean_motion = line1[33..43].trim().parse::<f64>().unwrap() * 2.0;
+    /// The kind of I/O error the reader failed with, yielded once the pending lines are used up
+    error: Option<io::ErrorKind>,
+}
 
-        // 2nd derivative of mean motion [revs/days^3]
-        // Account for - in 2nd derivative of mean motion
-        if line1[44..45].parse::<char>().unwrap() == '-' {
-            tle.second_derivative_of_mean_motion = format!("-0.{}", line1[45..50].trim()).parse::<f64>().unwrap() * 10.0_f64.powi(line1[50..52].parse::<i32>().unwrap()) * 6.0_f64;
-        } else {
-            tle.second_derivative_of_mean_motion = format!("0.{}", line1[45..50].trim()).parse::<f64>().unwrap() * 10.0_f64.powi(line1[50..52].parse::<i32>().unwrap()) * 6.0_f64;
-        }
+/// The location and format of a field in the TLE data lines, see [`FIELD_SPECS`] and [`parse_with_spec`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldSpec {
+    /// The name of the [`Tle`] field the value is stored in (e.g. `"inclination"`)
+    pub name: &'static str,
 
-        // B* [1/Earth Radii]
-        // Account for - in B* term
-        if line1[53..54].parse::<char>().unwrap() == '-' {
-            tle.bstar = format!("-0.{}", line1[54..59].trim()).parse::<f64>().unwrap() * 10.0_f64.powi(line1[59..61].parse::<i32>().unwrap());
-        } else {
-            tle.bstar = format!("0.{}", line1[54..59].trim()).parse::<f64>().unwrap() * 10.0_f64.powi(line1[59..61].parse::<i32>().unwrap());
-        }
+    /// The data line holding the field (1 or 2)
+    pub line: usize,
 
-        // Ephemeris type
-        tle.ephemeris_type = line1[62..63].parse::<i32>().unwrap();
+    /// The index of the first character of the field
+    pub start: usize,
 
-        // Element set number
-        tle.element_set_number = line1[64..68].trim().parse::<i32>().unwrap();
-    }
+    /// The index one past the last character of the field
+    pub end: usize,
 
-    // Parse through line 2 and populate TLE struct
-    if line2.len() < 69 || line2.len() > 69 {
-        println!("TLE string is invalid");
-    } else {
-        // Line 2
-        // Inclination [degs]
-        tle.inclination = line2[8..16].trim().parse::<f64>().unwrap();
+    /// How the characters of the field are decoded
+    pub kind: FieldKind,
+}
 
-        // Right ascension of ascending node [degs]
-        tle.right_ascension_of_ascending_node = line2[17..25].trim().parse::<f64>().unwrap();
+/// A TLE as served in the JSON arrays of some APIs, see [`from_json_array`]
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct JsonTleEntry {
+    /// The common name of the satellite (line 0), empty if absent
+    #[serde(default)]
+    name: String,
 
-        // Eccentricity
-        tle.eccentricity = format!("0.{}", line2[26..33].trim()).parse::<f64>().unwrap();
+    /// The first TLE data line
+    line1: String,
 
-        // Argument of perigee [degs]
-        tle.argument_of_perigee = line2[34..42].trim().parse::<f64>().unwrap();
+    /// The second TLE data line
+    line2: String,
+}
 
-        // Mean anomaly [degs]
-        tle.mean_anomaly = line2[43..51].trim().parse::<f64>().unwrap();
+// ---------
+// Enums
+// ---------
 
-        // Mean motion [revs/day]
-        tle.mean_motion = line2[52..63].trim().parse::<f64>().unwrap();
+/// Errors that can occur while parsing a Two-Line Element set
+#[derive(Debug, Clone, PartialEq)]
+pub enum TleError {
+    /// A TLE data line is not 69 characters long
+    WrongLineLength { line: usize, length: usize },
+    /// A TLE data line failed its checksum test
+    ChecksumFailed { line: usize },
+    /// A TLE line contains a tab or a non-ASCII character, so its fixed columns cannot be located (line 0 is the name
+    /// line, which may only be non-ASCII when parsed from a string)
+    NonAsciiOrTab { line: usize },
+    /// A field could not be parsed, carrying the field name, its 1-based inclusive column range on the TLE line (`None`
+    /// for fields that are not read from fixed columns, such as those of an OMM) and the offending substring
+    FieldParse { field: &'static str, columns: Option<(usize, usize)>, raw: TleString },
+    /// The lines do not form a complete 2-line or 3-line TLE entry
+    InvalidLineCount,
+    /// A field is outside its valid range, carrying the field name and the offending value
+    OutOfRange { field: &'static str, value: f64 },
+    /// The text is not valid JSON or does not have the expected structure
+    InvalidJson,
+    /// A [`FieldSpec`] names an unknown field, a line other than 1 or 2, or a kind that does not fit the field
+    InvalidFieldSpec { field: &'static str },
+    /// Reading the TLE text failed, carrying the kind of I/O error
+    #[cfg(feature = "std")]
+    Io { kind: io::ErrorKind },
+}
 
-        // Revolution number at epoch
-        tle.revolution_number_at_epoch = line2[63..68].trim().parse::<i64>().unwrap();
-    }
+/// How the characters of a TLE field are decoded, see [`FieldSpec`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldKind {
+    /// A 5 character catalog number, numeric or in the Alpha-5 format (e.g. `E8493` = 148493)
+    CatalogNumber,
+    /// A single character
+    Character,
+    /// Text, with surrounding whitespace removed
+    Text,
+    /// A two-digit year, expanded to 1957-2056
+    Year,
+    /// An integer
+    Integer,
+    /// A decimal number, scaled by the multiplier (e.g. 2 for the 1st derivative of mean motion)
+    Decimal { multiplier: f64 },
+    /// A number with an assumed leading decimal point (e.g. `0006703` = 0.0006703)
+    DecimalFraction,
+    /// A mantissa with an assumed leading decimal point and a power of ten exponent (e.g. `-11606-4`), scaled by the
+    /// multiplier (e.g. 6 for the 2nd derivative of mean motion)
+    Exponential { multiplier: i64 },
+}
 
-    return tle;
+/// The value of a TLE field, decoded according to its [`FieldKind`]
+enum FieldValue {
+    /// An integer value
+    Integer(i64),
+    /// A real value
+    Real(f64),
+    /// A single character
+    Character(char),
+    /// A text value
+    Text(TleString),
 }
 
-/// Builds a vector of [`Tle`] structs from a string containing Two-Line Element sets.
-///
-/// This function parses a string containing one or more TLEs in either
-/// 2-line or 3-line (name + 2 lines) format and returns all successfully
-/// parsed entries.
-///
-/// # Arguments
-/// * `tle_string` - A string containing one or more Two-Line Element sets
-///
-/// # Returns
-/// * `Vec<Tle>` - A vector containing all successfully parsed TLEs
+/// The orbit model an element set was generated for, from the ephemeris type field (column 63 of line 1)
 ///
-/// # Examples
-/// ```rust
-/// // Define the TLE string
-/// let tle_string = "ISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
-/// 
-/// // Parse the TLE string into a TLE struct
-/// let tles = from_string(tle_string);
-/// let tle = &tles[0];
-/// 
-/// // Assert the TLE struct is correct
-/// assert_eq!(tle.satellite_catalog_number, 25544);
-/// ```
-///
-/// # References
-/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
-pub fn from_string(tle_string: &str) -> Vec<Tle> {
-    // Parse the string into lines, removing spaces
-    let lines: Vec<&str> = tle_string
-        .lines()
-        .map(str::trim)
-        .filter(|l| !l.is_empty())
-        .collect();
+/// References:
+/// - [Spacetrack Report #3 by Hoots et al](https://celestrak.org/NORAD/documentation/spacetrk.pdf)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EphemerisType {
+    /// SGP4, the near-Earth model (type 0, the default in distributed element sets, or type 2)
+    Sgp4,
+    /// SGP8, the near-Earth model of Spacetrack Report #3 (type 4)
+    Sgp8,
+    /// SDP4, the deep-space extension of SGP4 (type 3)
+    Sdp4,
+    /// SDP8, the deep-space extension of SGP8 (type 5)
+    Sdp8,
+    /// Any other ephemeris type, carrying the raw value
+    Unknown(i32),
+}
 
-    // Create the tles vector
-    let mut tles = Vec::new();
-    let mut i = 0;
-
-    // Iterate through the lines building TLE structs when possible
-    while i < lines.len() {
-        println!("{}", lines[i]);
-        // Find TLEs within the string, either 2 or 3 line entries
-        if lines[i].starts_with('1') {
-            // This is likely a 2 line entry, check length
-            if i + 1 >= lines.len() {
-                break;
-            }
-            // Check that next line starts with '2'
-            if lines[i + 1].starts_with('2') {
-                let tle = from_lines(lines[i], lines[i + 1], None);
-                tles.push(tle);
-                i += 2;
-            } else {
-                i += 1;
-            }
-        } else {
-            // This is likely a 3 line entry, check length
-            if i + 2 >= lines.len() {
-                break;
-            }
-            // Check that next line 2 lines starts with '1' and '2'
-            if lines[i + 1].starts_with('1') && lines[i + 2].starts_with('2') {
-                println!("{}\n{}\n{}", lines[i], lines[i+1], lines[i+2]);
-                let tle = from_lines(lines[i + 1], lines[i + 2], Some(lines[i]));
-                tles.push(tle);
-                i += 3;
-            } else {
-                i += 1;
-            }
-        }
-    }
-    // Return vector of TLEs
-    return tles;
+/// The security classification of an element set, from the classification field (column 8 of line 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    /// Unclassified (`U`), as for all publicly distributed element sets
+    Unclassified,
+    /// Classified (`C`)
+    Classified,
+    /// Secret (`S`)
+    Secret,
+    /// Any other classification, carrying the raw character
+    Other(char),
 }
 
-/// Builds a vector of [`Tle`] structs from a file containing Two-Line Element sets.
-///
-/// This function parses a file containing one or more TLEs in either
-/// 2-line or 3-line (name + 2 lines) format and returns all successfully
-/// parsed entries.
-///
-/// # Arguments
-/// * `file_path` - A path to a file containing one or more Two-Line Element sets
-///
-/// # Returns
-/// * `Vec<Tle>` - A vector containing all successfully parsed TLEs.
-///
-/// # Examples
-/// ```rust
-/// // Define the TLE file path
-/// let tle_file_path = "assets/test.tle";
-/// 
-/// // Parse the TLE file into a TLE struct
-/// let tles = from_file(tle_file_path);
-/// let tle = &tles[12];
-/// 
-/// // Assert the TLE structs are correct
-/// assert_eq!(iss_tle.satellite_catalog_number, 25544);
-/// ```
-///
-/// # References
-/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
-pub fn from_file(file_path: &str) -> Vec<Tle> {
-    // Open the TLE file
-    let tle_string = fs::read_to_string(file_path)
-        .expect("Cannot read TLE file");
-    
-    // Parse tle string into a vector of TLEs
-    let tles = from_string(&tle_string);
+/// The orbital regime of a satellite, see [`Tle::orbit_regime`] for the thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrbitRegime {
+    /// Low Earth orbit, entirely below 2000 km
+    Leo,
+    /// Medium Earth orbit, entirely between 2000 km and the geostationary altitude
+    Meo,
+    /// Geosynchronous orbit, with a period of about one sidereal day and a near circular orbit
+    Geo,
+    /// Highly elliptical orbit (e.g. Molniya or geostationary transfer orbits)
+    Heo,
+    /// Any other orbit (e.g. an eccentric orbit crossing the regime boundaries, or beyond the geostationary belt)
+    Other,
+}
 
-    // Return the vector of TLEs
-    return tles;
+/// Issues found in a parsed Two-Line Element set that do not prevent it from being used
+#[derive(Debug, Clone, PartialEq)]
+pub enum TleWarning {
+    /// A field is outside its physically valid domain, carrying the field name and the offending value
+    OutOfRange { field: &'static str, value: f64 },
+    /// The inclination is outside of \[0, 180\] degrees, which no prograde or retrograde orbit has, carrying the
+    /// offending value \[deg\]
+    InvalidInclination { value: f64 },
 }
 
-/// Calculate the checksum of the TLE line.
-///
-/// Given a TLE line, calculate the checksum of that line. Follow the following rules: 
-/// - Ignore alpha characters
-/// - Sum digits 0-9 as integer values
-/// - '-' is treated as 1
-/// - Return checksum % 10
-///
-/// # Arguments
-/// * `line` - The TLE line to calculate the checksum of
-///
-/// # Panics
-/// * If the TLE line is invalid (must be 69 characters)
+// ---------
+// Constants
+// ---------
+
+/// Leading characters of an Alpha-5 catalog number, in order of the 10,000 block they encode (`A` = 10xxxx, `Z` = 33xxxx).
+/// `I` and `O` are skipped to avoid confusion with `1` and `0`.
 ///
-/// # Returns
-/// * `checksum` - The checksum of the TLE line (integer 0-9)
+/// References:
+/// - [Space-Track Alpha-5 Documentation](https://www.space-track.org/documentation#tle-alpha5)
+const ALPHA5_LETTERS: &str = "ABCDEFGHJKLMNPQRSTUVWXYZ";
+
+/// The first catalog number cataloged at the start of each era, with the year the era starts. The years are the
+/// approximate years in which the catalog reached each milestone, and the last entry is the last milestone, past
+/// which the era of a catalog number is not estimated.
 ///
-/// # Examples
-/// ```rust
-/// // Define the TLE line
-/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
-/// 
-/// // Calculate the checksum of the TLE line
-/// let checksum = calc_checksum(tle_line1);
-/// 
-/// // Assert the checksum is correct
-/// assert_eq!(checksum, 1);
-/// ```
-pub fn calc_checksum(line: &str) -> i32 {
-    // Initialize checksum to 0
-    let mut checksum = 0;
+/// References:
+/// - [Celestrak SATCAT](https://celestrak.org/satcat/search.php)
+#[rustfmt::skip]
+const CATALOG_ERAS: [(i32, i32); 13] = [
+    (1, 1957), (1000, 1965), (4300, 1970), (7600, 1975), (11640, 1980), (15480, 1985), (20400, 1990),
+    (23460, 1995), (26050, 2000), (28500, 2005), (36250, 2010), (40350, 2015), (44900, 2020),
+];
 
-    // Loop through the line and calculate the checksum
-    for c in line.chars().take(68) {
-        match c {
-            '0'..='9' => checksum += (c as u8 - b'0') as i32,
-            '-' => checksum += 1,
-            _ => {}
-        }
-    }
+/// The width of a field in the TLE exponential format, a signed 5 digit mantissa and a signed 1 digit exponent
+const EXPONENTIAL_FIELD_WIDTH: usize = 8;
 
-    // Calculate the checksum
-    checksum = checksum % 10;
+/// The largest magnitude of the multiplier of an exponential field, so the scaled 5 digit mantissa fits an `i64`
+const MAX_EXPONENTIAL_MULTIPLIER: i64 = i64::MAX / 99999;
 
-    // Return the checksum
-    return checksum;
-}
+/// The longest line read by [`tle_iter`], well above the 69 character data lines and the 24 character name line
+#[cfg(feature = "std")]
+const MAX_STREAM_LINE_LENGTH: usize = 256;
 
-/// Check if the TLE line has been corrupted by running a checksum test.
-///
-/// Given a TLE line, check if the checksum of that line is valid.
-///
-/// # Arguments
-/// * `line` - The TLE line to check the checksum of
+/// The longest line skipped by [`tle_iter`], beyond which the reader is taken to have no line endings
+#[cfg(feature = "std")]
+const MAX_SKIPPED_LINE_LENGTH: usize = 1 << 20;
+
+/// The altitude below which an orbit is low Earth orbit \[km\]
+const LEO_MAX_ALTITUDE: f64 = 2000.;
+
+/// The altitude of the geostationary belt, which bounds medium Earth orbit \[km\]
+const GEO_ALTITUDE: f64 = 35786.;
+
+/// The period of a geosynchronous orbit, one sidereal day \[min\]
+const GEO_PERIOD: f64 = 1436.07;
+
+/// The largest difference from the geosynchronous period of an orbit still classified as geosynchronous \[min\]
+const GEO_PERIOD_TOLERANCE: f64 = 30.;
+
+/// The largest eccentricity of a geosynchronous orbit
+const GEO_MAX_ECCENTRICITY: f64 = 0.01;
+
+/// The smallest eccentricity of a highly elliptical orbit
+const HEO_MIN_ECCENTRICITY: f64 = 0.25;
+
+/// The fields of the standard TLE data lines, in the order they are parsed by [`from_lines`]
 ///
-/// # Returns
-/// * `bool` - True if the checksum of the line is valid, false if otherwise
+/// A copy with shifted columns can be passed to [`parse_with_spec`] to read nonstandard layouts.
 ///
-/// # Examples
-/// ```rust
-/// // Define the TLE line
-/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
-/// 
-/// // Calculate the checksum of the TLE line
-/// let checksum = tle_checksum(tle_line1);
-/// 
-/// // Assert the checksum is correct
-/// assert_eq!(checksum, true);
-/// ```
-pub fn tle_checksum(line: &str) -> bool {
-    // Calculate the checksum of the line
-    let checksum = calc_checksum(line);
+/// References:
+/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
+#[rustfmt::skip]
+pub const FIELD_SPECS: [FieldSpec; 17] = [
+    // Line 1
+    FieldSpec { name: "satellite_catalog_number", line: 1, start: 2, end: 7, kind: FieldKind::CatalogNumber },
+    FieldSpec { name: "classification", line: 1, start: 7, end: 8, kind: FieldKind::Character },
+    FieldSpec { name: "international_designator", line: 1, start: 9, end: 17, kind: FieldKind::Text },
+    FieldSpec { name: "epoch_year", line: 1, start: 18, end: 20, kind: FieldKind::Year },
+    FieldSpec { name: "epoch_day", line: 1, start: 20, end: 32, kind: FieldKind::Decimal { multiplier: 1. } },
+    FieldSpec { name: "first_derivative_of_mean_motion", line: 1, start: 33, end: 43, kind: FieldKind::Decimal { multiplier: 2. } },
+    FieldSpec { name: "second_derivative_of_mean_motion", line: 1, start: 44, end: 52, kind: FieldKind::Exponential { multiplier: 6 } },
+    FieldSpec { name: "bstar", line: 1, start: 53, end: 61, kind: FieldKind::Exponential { multiplier: 1 } },
+    FieldSpec { name: "ephemeris_type", line: 1, start: 62, end: 63, kind: FieldKind::Integer },
+    FieldSpec { name: "element_set_number", line: 1, start: 64, end: 68, kind: FieldKind::Integer },
 
-    // Compare the checksum to the last character of the line
-    if checksum == line[68..69].parse::<i32>().unwrap() {
-        return true;
-    } else {
-        return false;
+    // Line 2
+    FieldSpec { name: "inclination", line: 2, start: 8, end: 16, kind: FieldKind::Decimal { multiplier: 1. } },
+    FieldSpec { name: "right_ascension_of_ascending_node", line: 2, start: 17, end: 25, kind: FieldKind::Decimal { multiplier: 1. } },
+    FieldSpec { name: "eccentricity", line: 2, start: 26, end: 33, kind: FieldKind::DecimalFraction },
+    FieldSpec { name: "argument_of_perigee", line: 2, start: 34, end: 42, kind: FieldKind::Decimal { multiplier: 1. } },
+    FieldSpec { name: "mean_anomaly", line: 2, start: 43, end: 51, kind: FieldKind::Decimal { multiplier: 1. } },
+    FieldSpec { name: "mean_motion", line: 2, start: 52, end: 63, kind: FieldKind::Decimal { multiplier: 1. } },
+    FieldSpec { name: "revolution_number_at_epoch", line: 2, start: 63, end: 68, kind: FieldKind::Integer },
+];
+
+// ---------------
+// Implementations
+// ---------------
+
+impl Tle {
+    /// Converts the TLE epoch into a calendar datetime.
+    ///
+    /// The two-digit epoch year is expanded when the TLE is parsed (57-99 -> 1957-1999, 00-56 -> 2000-2056),
+    /// so this splits the fractional day of year into month, day, hour, minute and second, accounting for leap years.
+    ///
+    /// # Returns
+    /// * `Result<DateTime, DateError>` - The epoch as a [`DateTime`] (in UTC)
+    ///
+    /// # Errors
+    ///   - `DateError::InvalidDayOfYear` if the epoch day is less than 1 or exceeds the number of days in the epoch year
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the TLE lines
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+    ///
+    /// // Day 264.51782528 of 2008 = September 20th, 2008 at 12:25:40.104 UTC
+    /// let epoch = tle.epoch_datetime()?;
+    /// assert_eq!((epoch.year, epoch.month, epoch.day), (2008, 9, 20));
+    /// # Ok::<(), Rusty_SGP4::time::DateError>(())
+    /// ```
+    pub fn epoch_datetime(&self) -> Result<DateTime, DateError> {
+        return dayofyr2utc(self.epoch_year, self.epoch_day);
     }
-}
 
-// ----------
-// Unit Tests
-// ----------
+    /// Converts the TLE epoch into a Julian date.
+    ///
+    /// The whole and fractional days are summed, so the result is suitable for [`crate::time::gmst`] but only
+    /// resolves the epoch to about 40 µs. Use [`crate::time::utc2jday`] on [`Tle::epoch_datetime`] to keep them apart.
+    ///
+    /// # Returns
+    /// * `Result<f64, DateError>` - The Julian date of the epoch \[days\]
+    ///
+    /// # Errors
+    ///   - `DateError::InvalidDayOfYear` if the epoch day is less than 1 or exceeds the number of days in the epoch year
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    /// use Rusty_SGP4::time::gmst;
+    ///
+    /// // Define the TLE lines
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+    ///
+    /// // The sidereal time at the epoch
+    /// let jd = tle.epoch_julian_date()?;
+    /// assert!((jd - 2454730.01782528).abs() < 1e-6);
+    /// let theta = gmst(jd);
+    /// # Ok::<(), Rusty_SGP4::time::DateError>(())
+    /// ```
+    pub fn epoch_julian_date(&self) -> Result<f64, DateError> {
+        let (jd, jdfrac) = utc2jday(&self.epoch_datetime()?)?;
+        return Ok(jd + jdfrac);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Converts the TLE epoch into a Modified Julian date (MJD = JD - 2400000.5).
+    ///
+    /// # Returns
+    /// * `Result<f64, DateError>` - The Modified Julian date of the epoch \[days\]
+    ///
+    /// # Errors
+    ///   - `DateError::InvalidDayOfYear` if the epoch day is less than 1 or exceeds the number of days in the epoch year
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the TLE lines
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+    ///
+    /// // September 20th, 2008 is MJD 54729
+    /// assert_eq!(tle.epoch_mjd()?.floor(), 54729.);
+    /// # Ok::<(), Rusty_SGP4::time::DateError>(())
+    /// ```
+    pub fn epoch_mjd(&self) -> Result<f64, DateError> {
+        let (mjd, mjdfrac) = utc2mjday(&self.epoch_datetime()?)?;
+        return Ok(mjd + mjdfrac);
+    }
 
-    #[test]
-    fn test_checksum_calculation() {
-        // Define the TLE line
-        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+    /// Converts the TLE epoch into a [`chrono::DateTime`] in UTC.
+    ///
+    /// The fractional day of year is carried through at nanosecond resolution, so
+    /// the result can be compared directly against other `chrono` timestamps.
+    ///
+    /// # Returns
+    /// * `Result<chrono::DateTime<chrono::Utc>, DateError>` - The epoch as a `chrono` datetime
+    ///
+    /// # Errors
+    ///   - `DateError::InvalidDayOfYear` if the epoch day is less than 1 or exceeds the number of days in the epoch year
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the TLE lines
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+    ///
+    /// // Compare the epoch against a chrono timestamp
+    /// let epoch = tle.epoch_chrono()?;
+    /// assert!(epoch > Utc.with_ymd_and_hms(2008, 9, 20, 12, 0, 0).unwrap());
+    /// # Ok::<(), Rusty_SGP4::time::DateError>(())
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn epoch_chrono(&self) -> Result<chrono::DateTime<chrono::Utc>, DateError> {
+        // Find the calendar date from the whole day of year
+        let day_int = self.epoch_day.floor();
+        let date = chrono::NaiveDate::from_yo_opt(self.epoch_year, day_int as u32)
+            .ok_or(DateError::InvalidDayOfYear)?;
 
-        // Calculate the checksum of the TLE line
-        let checksum = calc_checksum(tle_line1);
+        // Add the fractional day as nanoseconds past midnight
+        let nanoseconds = ((self.epoch_day - day_int) * 86400e9).round() as i64;
+        let epoch = date.and_time(chrono::NaiveTime::MIN) + chrono::Duration::nanoseconds(nanoseconds);
 
-        // Assert the checksum is correct
-        assert_eq!(checksum, 1);
+        return Ok(epoch.and_utc());
+    }
+
+    /// Calculates the age of the element set, the time elapsed from the epoch to `now`.
+    ///
+    /// # Arguments
+    /// * `now` - The time to measure the age at
+    ///
+    /// # Returns
+    /// * `Result<chrono::Duration, DateError>` - The age, negative if the epoch is after `now`
+    ///
+    /// # Errors
+    ///   - `DateError::InvalidDayOfYear` if the epoch day is less than 1 or exceeds the number of days in the epoch year
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+    ///
+    /// // The elements are about two days old on September 22nd
+    /// let age = tle.age(Utc.with_ymd_and_hms(2008, 9, 22, 12, 0, 0).unwrap())?;
+    /// assert_eq!(age.num_days(), 1);
+    /// assert_eq!(age.num_hours(), 47);
+    /// # Ok::<(), Rusty_SGP4::time::DateError>(())
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn age(&self, now: chrono::DateTime<chrono::Utc>) -> Result<chrono::Duration, DateError> {
+        return Ok(now - self.epoch_chrono()?);
+    }
+
+    /// Checks if the element set is older than a maximum age at `now`.
+    ///
+    /// An element set with an invalid epoch is always stale, since propagating it cannot be trusted.
+    ///
+    /// # Arguments
+    /// * `now` - The time to measure the age at
+    /// * `max_age` - The maximum age of a fresh element set
+    ///
+    /// # Returns
+    /// * `bool` - Whether the age exceeds `max_age`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+    ///
+    /// // Flag elements older than 3 days
+    /// let now = Utc.with_ymd_and_hms(2008, 9, 25, 0, 0, 0).unwrap();
+    /// assert!(tle.is_stale(now, Duration::days(3)));
+    /// assert!(!tle.is_stale(now, Duration::days(7)));
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn is_stale(&self, now: chrono::DateTime<chrono::Utc>, max_age: chrono::Duration) -> bool {
+        return match self.age(now) {
+            Ok(age) => age > max_age,
+            Err(_) => true,
+        };
+    }
+
+    /// Splits the international designator into its launch year, launch number and piece.
+    ///
+    /// The designator packs a two-digit launch year, a three-digit launch number within that
+    /// year and a one to three letter piece (e.g. `98067A` or `98067AB`). The piece is left
+    /// blank by some sources, in which case it is returned empty. The launch year is expanded
+    /// with the same rule as the epoch year (57-99 -> 1957-1999, 00-56 -> 2000-2056).
+    ///
+    /// # Returns
+    /// * `Option<(i32, i32, TleString)>` - The (launch year, launch number, piece), or `None` if the designator is
+    ///   blank or malformed (a piece longer than three characters or holding anything but letters)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // The ISS was the 67th launch of 1998
+    /// let (launch_year, launch_number, piece) = tle.designator_parts().unwrap();
+    /// assert_eq!((launch_year, launch_number, piece.as_str()), (1998, 67, "A"));
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn designator_parts(&self) -> Option<(i32, i32, TleString)> {
+        let designator = self.international_designator.trim();
+
+        // The year and launch number are 5 digits followed by a piece of up to 3 letters
+        let (digits, piece) = designator.split_at_checked(5)?;
+        if !digits.chars().all(|c| c.is_ascii_digit()) || piece.len() > 3 || !piece.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        // Expand the two-digit launch year
+        let yr_two_digit: i32 = digits[0..2].parse().ok()?;
+        let launch_year = if yr_two_digit < 57 { 2000 + yr_two_digit } else { 1900 + yr_two_digit };
+        let launch_number: i32 = digits[2..5].parse().ok()?;
+
+        return Some((launch_year, launch_number, to_tle_string(piece)));
+    }
+
+    /// Estimates the years in which the object was cataloged from its catalog number.
+    ///
+    /// Catalog numbers are assigned roughly in order of cataloging, so well-known milestones of the catalog bound the
+    /// era of an object when its international designator is missing. This is a best-effort heuristic: debris is
+    /// often cataloged years after the launch that created it, so the era can be later than the launch year. Prefer
+    /// [`Tle::designator_parts`] when the designator is present.
+    ///
+    /// # Returns
+    /// * `Option<(i32, i32)>` - The first and last year of the era, both approximate, or `None` for zero and for
+    ///   catalog numbers from 44900, cataloged from about 2020 on, which includes analyst objects (80000 to 89999) and
+    ///   Alpha-5 numbers (from 100000)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // The ISS was launched in 1998
+    /// assert_eq!(tle.approx_launch_era(), Some((1995, 2000)));
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn approx_launch_era(&self) -> Option<(i32, i32)> {
+        let catalog_number = self.satellite_catalog_number;
+        if catalog_number < CATALOG_ERAS[0].0 {
+            return None;
+        }
+
+        // Find the era whose catalog number range holds the catalog number
+        return CATALOG_ERAS.windows(2).find(|eras| catalog_number < eras[1].0).map(|eras| (eras[0].1, eras[1].1));
+    }
+
+    /// Interprets the ephemeris type field as the orbit model the element set was generated for.
+    ///
+    /// Distributed element sets always carry type 0, which is treated as SGP4. The SGP4 propagator of this crate
+    /// selects between its near-Earth and deep-space branches from the orbital period, so both `Sgp4` and `Sdp4`
+    /// element sets can be propagated.
+    ///
+    /// # Returns
+    /// * [`EphemerisType`] - The orbit model, or `EphemerisType::Unknown` for an unassigned value
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::{from_lines, EphemerisType};
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // Type 0 element sets are for SGP4
+    /// assert_eq!(tle.ephemeris_kind(), EphemerisType::Sgp4);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn ephemeris_kind(&self) -> EphemerisType {
+        return match self.ephemeris_type {
+            0 | 2 => EphemerisType::Sgp4,
+            3 => EphemerisType::Sdp4,
+            4 => EphemerisType::Sgp8,
+            5 => EphemerisType::Sdp8,
+            other => EphemerisType::Unknown(other),
+        };
+    }
+
+    /// Check whether the element set was generated for SGP4 or SDP4, the models propagated by this crate
+    ///
+    /// This is the check behind the warning of [`crate::sgp4::init_sgp4`] under the `log` feature, so element sets
+    /// for other orbit models can be rejected without it.
+    ///
+    /// # Returns
+    /// * `bool` - True if the ephemeris type is `EphemerisType::Sgp4` or `EphemerisType::Sdp4`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // Distributed element sets are for SGP4
+    /// assert!(tle.has_sgp4_ephemeris());
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn has_sgp4_ephemeris(&self) -> bool {
+        return matches!(self.ephemeris_kind(), EphemerisType::Sgp4 | EphemerisType::Sdp4);
+    }
+
+    /// Interprets the classification field, so it can be matched instead of compared as a raw character.
+    ///
+    /// # Returns
+    /// * [`Classification`] - The classification, or `Classification::Other` for an unexpected character
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::{from_lines, Classification};
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // Public element sets are unclassified
+    /// assert_eq!(tle.classification_kind(), Classification::Unclassified);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn classification_kind(&self) -> Classification {
+        return match self.classification {
+            'U' => Classification::Unclassified,
+            'C' => Classification::Classified,
+            'S' => Classification::Secret,
+            other => Classification::Other(other),
+        };
+    }
+
+    /// Calculates the time of day of the TLE epoch in seconds past midnight.
+    ///
+    /// Only the fractional part of the epoch day is converted, so the whole day count does not
+    /// dilute the precision of the result. The epoch day is stored as an `f64`, whose spacing
+    /// near day 366 is about 6e-14 days (5 ns), so the 8 decimal digits of the TLE epoch field
+    /// (a resolution of 864 µs) are preserved and the result is accurate to better than 1 µs.
+    ///
+    /// # Returns
+    /// * `f64` - The seconds past midnight of the epoch in \[0, 86400) \[s\]
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // Day 264.51782528 is at 12:25:40.104192 UTC
+    /// assert!((tle.epoch_fraction_seconds() - 44740.104192).abs() < 1e-6);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn epoch_fraction_seconds(&self) -> f64 {
+        // Remove the whole days before scaling
+        let fraction = self.epoch_day - self.epoch_day.floor(); // [days]
+
+        return fraction * 86400.;
+    }
+
+    /// Calculates the orbital period from the mean motion.
+    ///
+    /// # Returns
+    /// * `f64` - The orbital period \[min\]
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // The ISS orbits roughly every 92 minutes
+    /// assert!((tle.period_minutes() - 91.6).abs() < 0.1);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn period_minutes(&self) -> f64 {
+        return 1440. / self.mean_motion;
+    }
+
+    /// Converts the mean motion from revolutions per day to radians per minute, the unit used by SGP4.
+    ///
+    /// # Returns
+    /// * `f64` - The mean motion \[rad/min\]
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // One revolution per period
+    /// assert!((tle.mean_motion_rad_per_min() * tle.period_minutes() - 2. * core::f64::consts::PI).abs() < 1e-12);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn mean_motion_rad_per_min(&self) -> f64 {
+        return self.mean_motion / XPDOTP;
+    }
+
+    /// Converts the mean motion from revolutions per day to radians per second.
+    ///
+    /// # Returns
+    /// * `f64` - The mean motion \[rad/s\]
+    pub fn mean_motion_rad_per_sec(&self) -> f64 {
+        return self.mean_motion_rad_per_min() / 60.;
+    }
+
+    /// Calculates the semi-major axis from the mean motion with Kepler's third law, a = (mu / n^2)^(1/3).
+    ///
+    /// The TLE mean motion is used directly (Kozai convention), so this is an approximation
+    /// of the mean semi-major axis rather than the SGP4 Brouwer value.
+    ///
+    /// # Arguments
+    /// * `wgs` - The WGS model providing the gravitational parameter
+    ///
+    /// # Returns
+    /// * `f64` - The semi-major axis \[km\]
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::common::WGS72;
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // Calculate the semi-major axis
+    /// let a = tle.semi_major_axis(&WGS72);
+    /// assert!((a - 6731.).abs() < 1.);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn semi_major_axis(&self, wgs: &Wgs) -> f64 {
+        // Convert the mean motion to rad/s
+        let n = self.mean_motion * 2. * PI / 86400.; // [rad/s]
+
+        // Apply Kepler's third law
+        let a = (wgs.mu / n.powi(2)).cbrt(); // [km]
+
+        return a;
+    }
+
+    /// Calculates the apogee altitude above the equatorial radius.
+    ///
+    /// # Arguments
+    /// * `wgs` - The WGS model providing the gravitational parameter and equatorial radius
+    ///
+    /// # Returns
+    /// * `f64` - The apogee altitude \[km\]
+    pub fn apogee_altitude(&self, wgs: &Wgs) -> f64 {
+        return self.semi_major_axis(wgs) * (1. + self.eccentricity) - wgs.r_earth_eq;
+    }
+
+    /// Calculates the perigee altitude above the equatorial radius.
+    ///
+    /// # Arguments
+    /// * `wgs` - The WGS model providing the gravitational parameter and equatorial radius
+    ///
+    /// # Returns
+    /// * `f64` - The perigee altitude \[km\]
+    pub fn perigee_altitude(&self, wgs: &Wgs) -> f64 {
+        return self.semi_major_axis(wgs) * (1. - self.eccentricity) - wgs.r_earth_eq;
+    }
+
+    /// Classifies the orbit into a regime from its eccentricity, period and altitudes.
+    ///
+    /// The checks are made in order, and the first that holds decides the regime:
+    /// * `Heo` - The eccentricity is at least 0.25
+    /// * `Geo` - The period is within 30 minutes of one sidereal day (1436.07 minutes) and the eccentricity is below 0.01
+    /// * `Leo` - The apogee altitude is below 2000 km
+    /// * `Meo` - The perigee altitude is at least 2000 km and the apogee altitude is below the geostationary altitude
+    ///   (35786 km)
+    /// * `Other` - Any other orbit
+    ///
+    /// # Arguments
+    /// * `wgs` - The WGS model providing the gravitational parameter and equatorial radius
+    ///
+    /// # Returns
+    /// * `OrbitRegime` - The orbital regime
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::common::WGS72;
+    /// use Rusty_SGP4::tle::{from_lines, OrbitRegime};
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // The ISS is in low Earth orbit
+    /// assert_eq!(tle.orbit_regime(&WGS72), OrbitRegime::Leo);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn orbit_regime(&self, wgs: &Wgs) -> OrbitRegime {
+        if self.eccentricity >= HEO_MIN_ECCENTRICITY {
+            return OrbitRegime::Heo;
+        }
+        if (self.period_minutes() - GEO_PERIOD).abs() <= GEO_PERIOD_TOLERANCE && self.eccentricity < GEO_MAX_ECCENTRICITY {
+            return OrbitRegime::Geo;
+        }
+
+        let apogee = self.apogee_altitude(wgs); // [km]
+        let perigee = self.perigee_altitude(wgs); // [km]
+        if apogee < LEO_MAX_ALTITUDE {
+            return OrbitRegime::Leo;
+        }
+        if perigee >= LEO_MAX_ALTITUDE && apogee < GEO_ALTITUDE {
+            return OrbitRegime::Meo;
+        }
+
+        return OrbitRegime::Other;
+    }
+
+    /// Calculates the secular drift of the right ascension of the ascending node due to J2.
+    ///
+    /// The first-order J2 rate is dΩ/dt = -3/2 n J2 (R / p)² cos(i), with the semi-latus rectum p found from
+    /// [`Tle::semi_major_axis`]. A sun-synchronous orbit drifts eastward at about 0.9856 deg/day, matching the mean
+    /// motion of the Sun.
+    ///
+    /// # Arguments
+    /// * `wgs` - The WGS model providing the gravitational parameter, equatorial radius and J2
+    ///
+    /// # Returns
+    /// * `f64` - The nodal regression rate, negative for prograde orbits \[deg/day\]
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::common::WGS72;
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // The orbit of the ISS regresses by about 5 degrees per day
+    /// assert!((tle.nodal_regression(&WGS72) + 5.1).abs() < 0.1);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    ///
+    /// # References
+    /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+    pub fn nodal_regression(&self, wgs: &Wgs) -> f64 {
+        return -1.5 * self.j2_secular_factor(wgs) * self.inclination.to_radians().cos();
+    }
+
+    /// Calculates the secular drift of the argument of perigee due to J2.
+    ///
+    /// The first-order J2 rate is dω/dt = 3/4 n J2 (R / p)² (5 cos²(i) - 1), which vanishes at the critical
+    /// inclinations of 63.4 and 116.6 degrees.
+    ///
+    /// # Arguments
+    /// * `wgs` - The WGS model providing the gravitational parameter, equatorial radius and J2
+    ///
+    /// # Returns
+    /// * `f64` - The apsidal rotation rate \[deg/day\]
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::common::WGS72;
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // The perigee of the ISS advances by about 4 degrees per day
+    /// assert!((tle.apsidal_rotation(&WGS72) - 3.8).abs() < 0.1);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    ///
+    /// # References
+    /// - [Fundamentals of Astrodynamics and Applications by Vallado et al](https://celestrak.org/software/vallado-sw.php)
+    pub fn apsidal_rotation(&self, wgs: &Wgs) -> f64 {
+        return 0.75 * self.j2_secular_factor(wgs) * (5. * self.inclination.to_radians().cos().powi(2) - 1.);
+    }
+
+    /// Calculates the factor n J2 (R / p)² common to the first-order J2 secular rates.
+    ///
+    /// # Arguments
+    /// * `wgs` - The WGS model providing the gravitational parameter, equatorial radius and J2
+    ///
+    /// # Returns
+    /// * `f64` - The factor \[deg/day\]
+    fn j2_secular_factor(&self, wgs: &Wgs) -> f64 {
+        let n = self.mean_motion * 360.; // [deg/day]
+        let p = self.semi_major_axis(wgs) * (1. - self.eccentricity.powi(2)); // [km]
+        return n * wgs.j2 * (wgs.r_earth_eq / p).powi(2);
+    }
+
+    /// Calculate the true anomaly at epoch from the mean anomaly and eccentricity
+    ///
+    /// Kepler's equation is solved for the eccentric anomaly (see [`mean_to_eccentric_anomaly`]),
+    /// which is then converted to the true anomaly (see [`eccentric_to_true_anomaly`]).
+    ///
+    /// # Returns
+    /// * `Result<f64, PropagationError>` - The true anomaly in \[0, 360) \[degrees\]
+    ///
+    /// # Errors
+    /// Returns the `PropagationError` of [`mean_to_eccentric_anomaly`] if the eccentricity is outside of \[0, 1) or
+    /// Kepler's equation does not converge.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // The near circular orbit has a true anomaly close to the mean anomaly
+    /// assert!((tle.true_anomaly().unwrap() - tle.mean_anomaly).abs() < 0.1);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn true_anomaly(&self) -> Result<f64, PropagationError> {
+        // Solve Kepler's equation
+        let ecc_anom = mean_to_eccentric_anomaly(self.mean_anomaly.to_radians(), self.eccentricity)?; // [rad]
+
+        // Convert to the true anomaly and wrap to [0, 360)
+        return Ok(normalize_deg_0_360(eccentric_to_true_anomaly(ecc_anom, self.eccentricity).to_degrees()));
+    }
+
+    /// Returns the inclination in radians.
+    ///
+    /// # Returns
+    /// * `Rad` - The inclination
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// assert!((tle.inclination_rad().0 - 51.6416_f64.to_radians()).abs() < 1e-12);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn inclination_rad(&self) -> Rad {
+        return Rad::from(Deg(self.inclination));
+    }
+
+    /// Returns the right ascension of the ascending node in radians.
+    ///
+    /// # Returns
+    /// * `Rad` - The right ascension of the ascending node
+    pub fn right_ascension_of_ascending_node_rad(&self) -> Rad {
+        return Rad::from(Deg(self.right_ascension_of_ascending_node));
+    }
+
+    /// Returns the argument of perigee in radians.
+    ///
+    /// # Returns
+    /// * `Rad` - The argument of perigee
+    pub fn argument_of_perigee_rad(&self) -> Rad {
+        return Rad::from(Deg(self.argument_of_perigee));
+    }
+
+    /// Returns the mean anomaly in radians.
+    ///
+    /// # Returns
+    /// * `Rad` - The mean anomaly
+    pub fn mean_anomaly_rad(&self) -> Rad {
+        return Rad::from(Deg(self.mean_anomaly));
+    }
+
+    /// Check each orbital element against its physically valid domain
+    ///
+    /// Parsing only checks that each field is numeric, so a TLE may parse with impossible values.
+    /// This reports every element outside its domain without failing, so suspect TLEs can be
+    /// ingested leniently and flagged for review. The checked domains are
+    /// - Eccentricity in \[0, 1)
+    /// - Inclination in \[0, 180\] degrees, reported as `TleWarning::InvalidInclination` since values outside
+    ///   of it indicate a corrupted line rather than a retrograde orbit (90 to 180 degrees)
+    /// - Right ascension of the ascending node, argument of perigee and mean anomaly in \[0, 360) degrees
+    /// - Mean motion greater than zero
+    ///
+    /// # Returns
+    /// * `Vec<TleWarning>` - The issues found, in field order (empty for a valid TLE)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::{from_lines, TleWarning};
+    ///
+    /// // Define the ISS TLE with an inclination of 181 degrees
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544 181.0000 247.4627 0006703 130.5360 325.0288 15.72125391563534";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // The TLE parses, but the inclination is flagged
+    /// let warnings = tle.validate_physical();
+    /// assert_eq!(warnings, vec![TleWarning::InvalidInclination { value: 181.0 }]);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn validate_physical(&self) -> Vec<TleWarning> {
+        let mut warnings = Vec::new();
+
+        // Check the inclination, where retrograde orbits are valid
+        if !(0.0..=180.0).contains(&self.inclination) {
+            warnings.push(TleWarning::InvalidInclination { value: self.inclination });
+        }
+
+        // Check the other elements against their domains
+        let checks = [
+            ("right_ascension_of_ascending_node", self.right_ascension_of_ascending_node, (0.0..360.0).contains(&self.right_ascension_of_ascending_node)),
+            ("eccentricity", self.eccentricity, (0.0..1.0).contains(&self.eccentricity)),
+            ("argument_of_perigee", self.argument_of_perigee, (0.0..360.0).contains(&self.argument_of_perigee)),
+            ("mean_anomaly", self.mean_anomaly, (0.0..360.0).contains(&self.mean_anomaly)),
+            ("mean_motion", self.mean_motion, self.mean_motion > 0.),
+        ];
+        for (field, value, valid) in checks {
+            if !valid {
+                warnings.push(TleWarning::OutOfRange { field: field, value: value });
+            }
+        }
+
+        return warnings;
+    }
+
+    /// Writes the TLE as three lines, with the name line prefixed by the `0 ` line number.
+    ///
+    /// Some consumers of the three-line format expect the name line to carry a line number like the data lines.
+    /// The data lines are those of [`to_lines`], and the name line parses back with [`from_lines`], which removes
+    /// the prefix. Use the [`Display`](fmt::Display) implementation for the unprefixed name line.
+    ///
+    /// # Returns
+    /// * `Result<Option<(String, String, String)>, TleError>` - The prefixed name line and the two data lines, or
+    ///   `None` if the common name is empty
+    ///
+    /// # Errors
+    /// Returns the `TleError` of [`to_lines`] if a real valued field is not finite.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the TLE lines
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, Some("ISS (ZARYA)"))?;
+    ///
+    /// // Write the lines with the prefixed name line
+    /// let (line0, line1, line2) = tle.to_lines_with_name_prefix()?.unwrap();
+    /// assert_eq!(line0, "0 ISS (ZARYA)");
+    /// assert_eq!(line1, tle_line1);
+    /// assert_eq!(line2, tle_line2);
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_lines_with_name_prefix(&self) -> Result<Option<(String, String, String)>, TleError> {
+        // There is no name line to write without a name
+        if self.common_name.is_empty() {
+            return Ok(None);
+        }
+
+        let (line1, line2) = to_lines(self)?;
+        return Ok(Some((format!("0 {}", self.common_name), line1, line2)));
+    }
+
+    /// Checks if two TLEs hold the same elements, up to a tolerance on the real valued fields.
+    ///
+    /// Writing and re-parsing a TLE rounds its real fields to the precision of their columns, so exact
+    /// comparison with `==` is brittle. Here the angles (inclination, right ascension of the ascending node,
+    /// argument of perigee and mean anomaly) are compared absolutely in degrees, allowing for the wrap at
+    /// 360 degrees, and the other real fields relatively, scaled by the larger magnitude (but at least 1, so
+    /// values near zero are compared absolutely). Integer, character and text fields must match exactly.
+    ///
+    /// # Arguments
+    /// * `other` - The TLE to compare with
+    /// * `tol` - The tolerance on the real valued fields, in degrees for the angles and relative otherwise
+    ///
+    /// # Returns
+    /// * `bool` - Whether every field matches within the tolerance
+    ///
+    /// # Examples
+    /// ```rust
+    /// use Rusty_SGP4::tle::from_lines;
+    ///
+    /// // Define the ISS TLE
+    /// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    /// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    /// let tle = from_lines(tle_line1, tle_line2, None)?;
+    ///
+    /// // A tiny change of the inclination is within the tolerance
+    /// let mut other = tle.clone();
+    /// other.inclination += 1e-12;
+    /// assert!(tle != other);
+    /// assert!(tle.approx_eq(&other, 1e-9));
+    /// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+    /// ```
+    pub fn approx_eq(&self, other: &Tle, tol: f64) -> bool {
+        // Real values relative to their magnitude, and angles absolutely across the wrap at 360 degrees
+        let close = |a: f64, b: f64| (a - b).abs() <= tol * a.abs().max(b.abs()).max(1.);
+        let close_angle = |a: f64, b: f64| {
+            let delta = (a - b).abs() % 360.; // [deg]
+            delta.min(360. - delta) <= tol
+        };
+
+        return self.common_name == other.common_name
+            && self.satellite_catalog_number == other.satellite_catalog_number
+            && self.classification == other.classification
+            && self.international_designator == other.international_designator
+            && self.epoch_year == other.epoch_year
+            && close(self.epoch_day, other.epoch_day)
+            && close(self.first_derivative_of_mean_motion, other.first_derivative_of_mean_motion)
+            && close(self.second_derivative_of_mean_motion, other.second_derivative_of_mean_motion)
+            && close(self.bstar, other.bstar)
+            && self.ephemeris_type == other.ephemeris_type
+            && self.element_set_number == other.element_set_number
+            && close_angle(self.inclination, other.inclination)
+            && close_angle(self.right_ascension_of_ascending_node, other.right_ascension_of_ascending_node)
+            && close(self.eccentricity, other.eccentricity)
+            && close_angle(self.argument_of_perigee, other.argument_of_perigee)
+            && close_angle(self.mean_anomaly, other.mean_anomaly)
+            && close(self.mean_motion, other.mean_motion)
+            && self.revolution_number_at_epoch == other.revolution_number_at_epoch;
+    }
+}
+
+impl TleBuilder {
+    /// Starts building a TLE from scratch.
+    ///
+    /// All fields are zero or empty except the classification, which defaults to unclassified (`U`),
+    /// and the epoch, which defaults to the first day of 2000.
+    ///
+    /// # Returns
+    /// * `TleBuilder` - The builder
+    pub fn new() -> TleBuilder {
+        let tle = Tle {
+            classification: 'U',
+            epoch_year: 2000,
+            epoch_day: 1.0,
+            ..Tle::default()
+        };
+        return TleBuilder { tle: tle };
+    }
+
+    /// Starts building a TLE from a copy of an existing one.
+    ///
+    /// # Arguments
+    /// * `tle` - The TLE to start from
+    ///
+    /// # Returns
+    /// * `TleBuilder` - The builder
+    pub fn from_tle(tle: &Tle) -> TleBuilder {
+        return TleBuilder { tle: tle.clone() };
+    }
+
+    /// Sets the common name of the satellite.
+    pub fn with_common_name(mut self, common_name: &str) -> TleBuilder {
+        self.tle.common_name = to_tle_string(common_name);
+        return self;
+    }
+
+    /// Sets the NORAD satellite catalog number.
+    pub fn with_satellite_catalog_number(mut self, satellite_catalog_number: i32) -> TleBuilder {
+        self.tle.satellite_catalog_number = satellite_catalog_number;
+        return self;
+    }
+
+    /// Sets the classification (`U`, `C` or `S`).
+    pub fn with_classification(mut self, classification: char) -> TleBuilder {
+        self.tle.classification = classification;
+        return self;
+    }
+
+    /// Sets the international designator (e.g. `98067A`).
+    pub fn with_international_designator(mut self, international_designator: &str) -> TleBuilder {
+        self.tle.international_designator = to_tle_string(international_designator);
+        return self;
+    }
+
+    /// Sets the epoch from a four digit year and a fractional day of year.
+    pub fn with_epoch(mut self, epoch_year: i32, epoch_day: f64) -> TleBuilder {
+        self.tle.epoch_year = epoch_year;
+        self.tle.epoch_day = epoch_day;
+        return self;
+    }
+
+    /// Sets the first time derivative of mean motion \[revs/day^2\].
+    pub fn with_first_derivative_of_mean_motion(mut self, first_derivative_of_mean_motion: f64) -> TleBuilder {
+        self.tle.first_derivative_of_mean_motion = first_derivative_of_mean_motion;
+        return self;
+    }
+
+    /// Sets the second time derivative of mean motion \[revs/day^3\].
+    pub fn with_second_derivative_of_mean_motion(mut self, second_derivative_of_mean_motion: f64) -> TleBuilder {
+        self.tle.second_derivative_of_mean_motion = second_derivative_of_mean_motion;
+        return self;
+    }
+
+    /// Sets the B* drag term \[1/Earth radii\].
+    pub fn with_bstar(mut self, bstar: f64) -> TleBuilder {
+        self.tle.bstar = bstar;
+        return self;
+    }
+
+    /// Sets the element set number.
+    pub fn with_element_set_number(mut self, element_set_number: i32) -> TleBuilder {
+        self.tle.element_set_number = element_set_number;
+        return self;
+    }
+
+    /// Sets the orbital inclination \[degrees\].
+    pub fn with_inclination(mut self, inclination: f64) -> TleBuilder {
+        self.tle.inclination = inclination;
+        return self;
+    }
+
+    /// Sets the right ascension of the ascending node \[degrees\].
+    pub fn with_right_ascension_of_ascending_node(mut self, right_ascension_of_ascending_node: f64) -> TleBuilder {
+        self.tle.right_ascension_of_ascending_node = right_ascension_of_ascending_node;
+        return self;
+    }
+
+    /// Sets the orbital eccentricity \[\].
+    pub fn with_eccentricity(mut self, eccentricity: f64) -> TleBuilder {
+        self.tle.eccentricity = eccentricity;
+        return self;
+    }
+
+    /// Sets the argument of perigee \[degrees\].
+    pub fn with_argument_of_perigee(mut self, argument_of_perigee: f64) -> TleBuilder {
+        self.tle.argument_of_perigee = argument_of_perigee;
+        return self;
+    }
+
+    /// Sets the mean anomaly \[degrees\].
+    pub fn with_mean_anomaly(mut self, mean_anomaly: f64) -> TleBuilder {
+        self.tle.mean_anomaly = mean_anomaly;
+        return self;
+    }
+
+    /// Sets the mean motion \[revs/day\].
+    pub fn with_mean_motion(mut self, mean_motion: f64) -> TleBuilder {
+        self.tle.mean_motion = mean_motion;
+        return self;
+    }
+
+    /// Sets the revolution number at epoch \[revs\].
+    pub fn with_revolution_number_at_epoch(mut self, revolution_number_at_epoch: i64) -> TleBuilder {
+        self.tle.revolution_number_at_epoch = revolution_number_at_epoch;
+        return self;
+    }
+
+    /// Validates the orbital elements and returns the built TLE.
+    ///
+    /// # Returns
+    /// * `Result<Tle, TleError>` - The built TLE
+    ///
+    /// # Errors
+    /// * `TleError::OutOfRange` if the eccentricity is not in \[0, 1) or the inclination is not in \[0, 180\] degrees
+    pub fn build(self) -> Result<Tle, TleError> {
+        // Validate the eccentricity
+        let eccentricity = self.tle.eccentricity;
+        if !(0.0..1.0).contains(&eccentricity) {
+            return Err(TleError::OutOfRange { field: "eccentricity", value: eccentricity });
+        }
+
+        // Validate the inclination
+        let inclination = self.tle.inclination;
+        if !(0.0..=180.0).contains(&inclination) {
+            return Err(TleError::OutOfRange { field: "inclination", value: inclination });
+        }
+
+        return Ok(self.tle);
+    }
+}
+
+impl Default for TleBuilder {
+    fn default() -> TleBuilder {
+        return TleBuilder::new();
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::BufRead> TleRecords<R> {
+    /// Reads ahead until `count` non-empty lines are pending or the reader is exhausted.
+    ///
+    /// If reading fails, the lines already pending are kept and the error is stored for [`TleRecords::next`] to
+    /// yield after them.
+    fn fill(&mut self, count: usize) {
+        while self.pending.len() < count && !self.finished {
+            match self.read_line() {
+                Ok(Some(Some(line))) => {
+                    if !line.trim().is_empty() {
+                        self.pending.push_back(Some(line.trim().to_string()));
+                    }
+                }
+                Ok(Some(None)) => self.pending.push_back(None),
+                Ok(None) => self.finished = true,
+                Err(error) => {
+                    self.finished = true;
+                    self.error = Some(error.kind());
+                }
+            }
+        }
+    }
+
+    /// Reads the next line without its line ending.
+    ///
+    /// Lines end at `\n` or `\r`, so Unix, Windows and classic Mac OS line endings are all accepted, with the empty
+    /// line between `\r` and `\n` skipped by [`TleRecords::fill`]. A line longer than [`MAX_STREAM_LINE_LENGTH`] is
+    /// read up to its line ending without being buffered, so a long comment costs no memory.
+    ///
+    /// # Returns
+    /// * `io::Result<Option<Option<String>>>` - The line, `Some(None)` for a line that is too long or not UTF-8, or
+    ///   `None` once the reader is exhausted
+    ///
+    /// # Errors
+    /// Returns the error of the reader, or `io::ErrorKind::InvalidData` for a line longer than
+    /// [`MAX_SKIPPED_LINE_LENGTH`], as from a reader without line endings.
+    fn read_line(&mut self) -> io::Result<Option<Option<String>>> {
+        let mut line = Vec::new();
+        let mut length = 0; // [bytes]
+        loop {
+            let buffer = match self.reader.fill_buf() {
+                Ok(buffer) => buffer,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            if buffer.is_empty() {
+                if length == 0 {
+                    return Ok(None);
+                }
+                break;
+            }
+
+            // Take the bytes up to the line ending, or the whole buffer if the line continues, keeping them only
+            // while the line fits
+            let end = buffer.iter().position(|&byte| byte == b'\n' || byte == b'\r');
+            let piece = end.unwrap_or(buffer.len());
+            length += piece;
+            if length <= MAX_STREAM_LINE_LENGTH {
+                line.extend_from_slice(&buffer[..piece]);
+            }
+            match end {
+                Some(end) => {
+                    self.reader.consume(end + 1);
+                    break;
+                }
+                None => self.reader.consume(piece),
+            }
+            if length > MAX_SKIPPED_LINE_LENGTH {
+                return Err(io::Error::from(io::ErrorKind::InvalidData));
+            }
+        }
+
+        if length > MAX_STREAM_LINE_LENGTH {
+            return Ok(Some(None));
+        }
+        return Ok(Some(String::from_utf8(line).ok()));
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::BufRead> Iterator for TleRecords<R> {
+    type Item = Result<Tle, TleError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line_starts_with = |pending: &VecDeque<Option<String>>, index: usize, prefix: &str| {
+            return pending.get(index).and_then(|line| line.as_deref()).is_some_and(|line| line.starts_with(prefix));
+        };
+
+        // Read ahead enough lines for a 3 line entry, skipping comments, headers, unreadable lines and the names of
+        // incomplete entries, which are neither data lines nor the name line of a complete entry
+        loop {
+            self.fill(3);
+            let is_data = line_starts_with(&self.pending, 0, "1 ") || line_starts_with(&self.pending, 0, "2 ");
+            let is_name = line_starts_with(&self.pending, 1, "1 ") && line_starts_with(&self.pending, 2, "2 ");
+            if self.pending.is_empty() || is_data || is_name {
+                break;
+            }
+            self.pending.pop_front();
+        }
+
+        // Once every line is used up, report the error that ended the reading, if any. A lone line 1 at the end was
+        // cut short by the error rather than missing its line 2.
+        if self.pending.is_empty() || (self.pending.len() == 1 && self.error.is_some() && line_starts_with(&self.pending, 0, "1 ")) {
+            self.pending.clear();
+            return self.error.take().map(|kind| Err(TleError::Io { kind: kind }));
+        }
+
+        // Find the entry, either 2 or 3 lines, grouping lines as in from_file_contents
+        let starts_with = |index: usize, prefix: &str| line_starts_with(&self.pending, index, prefix);
+        let line = |index: usize| self.pending[index].as_deref().unwrap_or_default();
+        let entry = if starts_with(0, "1 ") {
+            if starts_with(1, "2 ") {
+                let result = from_lines(line(0), line(1), None);
+                self.pending.drain(..2);
+                result
+            } else {
+                self.pending.pop_front();
+                Err(TleError::InvalidLineCount)
+            }
+        } else if starts_with(1, "1 ") && starts_with(2, "2 ") {
+            // An unreadable name line spoils its entry only
+            let result = match self.pending[0].as_deref() {
+                Some(name) => from_lines(line(1), line(2), Some(name)),
+                None => Err(TleError::Io { kind: io::ErrorKind::InvalidData }),
+            };
+            self.pending.drain(..3);
+            result
+        } else {
+            self.pending.pop_front();
+            Err(TleError::InvalidLineCount)
+        };
+
+        return Some(entry);
+    }
+}
+
+/// Formats a [`Tle`] as a Two-Line Element set.
+///
+/// The name line (line 0) is written first if the common name is not empty,
+/// followed by the two data lines (see [`to_lines`]), separated by newlines.
+/// Formatting fails with `fmt::Error` for a TLE that [`to_lines`] rejects.
+#[cfg(feature = "std")]
+impl fmt::Display for Tle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Build the data lines
+        let (line1, line2) = to_lines(self).map_err(|_error| fmt::Error)?;
+
+        // Write the name line if there is one
+        if !self.common_name.is_empty() {
+            writeln!(f, "{}", self.common_name)?;
+        }
+
+        write!(f, "{}\n{}", line1, line2)
+    }
+}
+
+/// Formats a [`TleError`] as a message naming the line, field or columns that broke.
+#[cfg(feature = "std")]
+impl fmt::Display for TleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TleError::WrongLineLength { line, length } => write!(f, "line {} is {} characters long instead of 69", line, length),
+            TleError::ChecksumFailed { line } => write!(f, "line {} failed its checksum test", line),
+            TleError::NonAsciiOrTab { line } => write!(f, "line {} contains a tab or a non-ASCII character", line),
+            TleError::FieldParse { field, columns: Some((first, last)), raw } => {
+                write!(f, "failed to parse '{}' at columns {}\u{2013}{}: '{}'", field, first, last, raw)
+            }
+            TleError::FieldParse { field, columns: None, raw } => write!(f, "failed to parse '{}': '{}'", field, raw),
+            TleError::InvalidLineCount => write!(f, "the lines do not form a complete TLE entry"),
+            TleError::OutOfRange { field, value } => write!(f, "'{}' is out of range: {}", field, value),
+            TleError::InvalidJson => write!(f, "the text is not valid JSON of the expected structure"),
+            TleError::InvalidFieldSpec { field } => write!(f, "invalid field spec for '{}'", field),
+            TleError::Io { kind } => write!(f, "reading the TLE failed: {}", kind),
+        }
+    }
+}
+
+// ---------
+// Functions
+// ---------
+
+/// Builds a [`Tle`] struct from the lines of a Two-Line Element set.
+///
+/// Given the two required TLE lines (line 1 and line 2), and an optional
+/// name line (line 0), this function parses the input into a [`Tle`] struct.
+/// Line terminators (`\r`, `\n`) and trailing whitespace are ignored.
+///
+/// Each data line is accepted in one of two formats:
+/// * The standard 69 character line, starting with its line number and a space (`1 ` or `2 `)
+/// * A 67 character line with the line number column stripped, starting with the catalog number. The line
+///   number is restored before parsing, so the checksum is that of the standard line.
+///
+/// The name line may carry the `0 ` line number prefix of the three-line format, which is removed. A blank name
+/// line leaves the common name empty, and a name longer than 24 characters is truncated to its first 24 (with a
+/// warning when the `log` feature is enabled).
+///
+/// # Arguments
+/// * `line1` - The first TLE data line (NORAD line 1)
+/// * `line2` - The second TLE data line (NORAD line 2)
+/// * `line0` - Optional name line (line 0)
+///
+/// # Returns
+/// * `Result<Tle, TleError>` - On success, the struct containing the parsed TLE data
+///
+/// # Errors
+/// * `TleError::NonAsciiOrTab` if the TLE lines (1 and 2) contain a tab or a non-ASCII character
+/// * `TleError::WrongLineLength` if the TLE lines (1 and 2) are of invalid lengths (must be 69 characters, or 67
+///   characters without the line number)
+/// * `TleError::ChecksumFailed` if the TLE lines (1 and 2) are invalid (checksum fails)
+/// * `TleError::FieldParse` if a field cannot be parsed, carrying its columns and the offending substring
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_lines;
+///
+/// // Define the TLE lines
+/// let tle_line0 = "ISS (ZARYA)";
+/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+/// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// 
+/// // Parse the TLE lines into a TLE struct
+/// let tle = from_lines(tle_line1, tle_line2, Some(tle_line0))?;
+/// 
+/// // Assert the TLE struct is correct
+/// assert_eq!(tle.satellite_catalog_number, 25544);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+///
+/// # References
+/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
+pub fn from_lines(line1: &str, line2: &str, line0: Option<&str>) -> Result<Tle, TleError> {
+    // Remove line terminators and trailing whitespace (the last character of a data line is always the checksum digit)
+    let line1 = line1.trim_end();
+    let line2 = line2.trim_end();
+    let line0 = line0.map(str::trim_end);
+
+    // Reject tabs and non-ASCII characters, which shift the fixed columns
+    if has_tab_or_non_ascii(line1) {
+        return Err(TleError::NonAsciiOrTab { line: 1 });
+    }
+    if has_tab_or_non_ascii(line2) {
+        return Err(TleError::NonAsciiOrTab { line: 2 });
+    }
+
+    // Restore the line numbers of lines without them
+    let mut buffer1 = [0u8; 69];
+    let mut buffer2 = [0u8; 69];
+    let line1 = restore_line_number(line1, b'1', &mut buffer1);
+    let line2 = restore_line_number(line2, b'2', &mut buffer2);
+
+    // Validate the TLE line lengths
+    if line1.len() != 69 {
+        return Err(TleError::WrongLineLength { line: 1, length: line1.len() });
+    }
+    if line2.len() != 69 {
+        return Err(TleError::WrongLineLength { line: 2, length: line2.len() });
+    }
+
+    // Validate the TLE checksum
+    validate_checksums(line1, line2)?;
+
+    // Parse the fields of the data lines
+    let mut tle = parse_with_spec(line1, line2, &FIELD_SPECS)?;
+
+    // Extract the common name of the satellite from line 0, without the line number of the three-line format
+    if let Some(name_line) = line0 {
+        let name = name_line.strip_prefix("0 ").unwrap_or(name_line).trim_end();
+        tle.common_name = to_tle_string(truncate_name(name));
+    }
+
+    return Ok(tle);
+}
+
+/// Builds a [`Tle`] struct from the three lines of a named Two-Line Element set.
+///
+/// This is [`from_lines`] for callers that always have the name line, so nothing has to be inferred from a line
+/// count. The name may carry the `0 ` line number of the three-line format, which is removed, and a blank name
+/// leaves the common name empty.
+///
+/// # Arguments
+/// * `name` - The name line (line 0)
+/// * `line1` - The first TLE data line
+/// * `line2` - The second TLE data line
+///
+/// # Returns
+/// * `Result<Tle, TleError>` - On success, the struct containing the parsed TLE data
+///
+/// # Errors
+/// Returns the errors of [`from_lines`].
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_3le;
+///
+/// // Parse the three lines of the ISS TLE
+/// let tle = from_3le(
+///     "ISS (ZARYA)",
+///     "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921",
+///     "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+/// )?;
+/// assert_eq!(tle.common_name, "ISS (ZARYA)");
+/// assert_eq!(tle.satellite_catalog_number, 25544);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+///
+/// # References
+/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
+pub fn from_3le(name: &str, line1: &str, line2: &str) -> Result<Tle, TleError> {
+    return from_lines(line1, line2, Some(name));
+}
+
+/// Builds a [`Tle`] struct from TLE data lines with a custom column layout.
+///
+/// Each field of `spec` is sliced from its line and decoded according to its [`FieldKind`], in order, and fields
+/// missing from `spec` are left zero. This is the parser behind [`from_lines`], which passes [`FIELD_SPECS`] after
+/// validating the line lengths and checksums. Those checks assume the standard layout, so they are not made here:
+/// only trailing whitespace is removed and lines with tabs or non-ASCII characters are rejected.
+///
+/// # Arguments
+/// * `line1` - The first TLE data line
+/// * `line2` - The second TLE data line
+/// * `spec` - The location and format of each field
+///
+/// # Returns
+/// * `Result<Tle, TleError>` - On success, the struct containing the parsed fields
+///
+/// # Errors
+/// * `TleError::NonAsciiOrTab` if a line contains a tab or a non-ASCII character
+/// * `TleError::FieldParse` if a field cannot be parsed, carrying its columns and the offending substring
+/// * `TleError::OutOfRange` if an integer does not fit its field
+/// * `TleError::InvalidFieldSpec` if a field spec names an unknown field, a line other than 1 or 2, or a kind that
+///   does not fit the field, or an exponential field is not 8 columns wide or has a multiplier that overflows
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::{from_lines, parse_with_spec, FIELD_SPECS};
+///
+/// // Define the ISS TLE lines, with the element set number shifted one column left
+/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// let shifted_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0 292 7";
+///
+/// // Move the element set number in a copy of the standard layout
+/// let mut spec = FIELD_SPECS;
+/// let element_set = spec.iter_mut().find(|field| field.name == "element_set_number").unwrap();
+/// element_set.start -= 1;
+/// element_set.end -= 1;
+///
+/// // Both layouts parse into the same TLE
+/// assert_eq!(parse_with_spec(shifted_line1, tle_line2, &spec)?, from_lines(tle_line1, tle_line2, None)?);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+pub fn parse_with_spec(line1: &str, line2: &str, spec: &[FieldSpec]) -> Result<Tle, TleError> {
+    // Create mutable TLE struct
+    let mut tle = Tle {
+        common_name: TleString::new(),
+        satellite_catalog_number: 0,
+        classification: '0',
+        international_designator: TleString::new(),
+        epoch_year: 0,
+        epoch_day: 0.0,
+        first_derivative_of_mean_motion: 0.0,
+        second_derivative_of_mean_motion: 0.0,
+        bstar: 0.0,
+        ephemeris_type: 0,
+        element_set_number: 0,
+        inclination: 0.0,
+        right_ascension_of_ascending_node: 0.0,
+        eccentricity: 0.0,
+        argument_of_perigee: 0.0,
+        mean_anomaly: 0.0,
+        mean_motion: 0.0,
+        revolution_number_at_epoch: 0,
+    };
+
+    // Remove line terminators and trailing whitespace
+    let line1 = line1.trim_end();
+    let line2 = line2.trim_end();
+
+    // Reject tabs and non-ASCII characters, which shift the fixed columns
+    if has_tab_or_non_ascii(line1) {
+        return Err(TleError::NonAsciiOrTab { line: 1 });
+    }
+    if has_tab_or_non_ascii(line2) {
+        return Err(TleError::NonAsciiOrTab { line: 2 });
+    }
+
+    // Validate the whole spec before parsing any field
+    for field in spec {
+        validate_field_spec(field)?;
+    }
+
+    // Parse each field in order, stopping at the first error
+    for field in spec {
+        let line = if field.line == 1 { line1 } else { line2 };
+        let value = parse_spec_field(line, field)?;
+        set_field(&mut tle, field.name, value)?;
+    }
+
+    return Ok(tle);
+}
+
+/// Builds a [`Tle`] struct from classical orbital elements.
+///
+/// This is the inverse of parsing, for synthesizing TLEs for test orbits. The remaining fields
+/// take the defaults of [`TleBuilder::new`] (unclassified, no drag, element set 0) and can be set
+/// afterwards with [`TleBuilder::from_tle`]. Checksums are not stored, so the lines written by
+/// [`to_lines`] always carry correct checksums.
+///
+/// # Arguments
+/// * `epoch` - The epoch as a [`DateTime`] (in UTC)
+/// * `inclination` - The orbital inclination \[degrees\]
+/// * `right_ascension_of_ascending_node` - The right ascension of the ascending node \[degrees\]
+/// * `eccentricity` - The orbital eccentricity \[\]
+/// * `argument_of_perigee` - The argument of perigee \[degrees\]
+/// * `mean_anomaly` - The mean anomaly \[degrees\]
+/// * `mean_motion` - The mean motion \[revs/day\]
+/// * `satellite_catalog_number` - The NORAD satellite catalog number
+///
+/// # Returns
+/// * `Result<Tle, TleError>` - The TLE holding the elements
+///
+/// # Errors
+/// * `TleError::OutOfRange` if the epoch is invalid or not in UTC, the eccentricity is not in \[0, 1)
+///   or the inclination is not in \[0, 180\] degrees
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::time::{DateTime, Timezone};
+/// use Rusty_SGP4::tle::from_elements;
+///
+/// // Define a sun-synchronous orbit
+/// let epoch = DateTime { year: 2024, month: 4, day: 9, hour: 12, minute: 0, second: 0., timezone: Timezone::UTC };
+/// let tle = from_elements(&epoch, 97.5, 120., 0.001, 90., 270., 14.9, 99999)?;
+///
+/// // The epoch is stored as a day of year
+/// assert_eq!((tle.epoch_year, tle.epoch_day), (2024, 100.5));
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn from_elements(
+    epoch: &DateTime,
+    inclination: f64,
+    right_ascension_of_ascending_node: f64,
+    eccentricity: f64,
+    argument_of_perigee: f64,
+    mean_anomaly: f64,
+    mean_motion: f64,
+    satellite_catalog_number: i32,
+) -> Result<Tle, TleError> {
+    // Convert the epoch to a year and day of year
+    let (epoch_year, epoch_day) = utc2dayofyr(epoch)
+        .map_err(|_| TleError::OutOfRange { field: "epoch", value: epoch.year as f64 })?;
+
+    // Fill and validate the elements
+    let tle = TleBuilder::new()
+        .with_satellite_catalog_number(satellite_catalog_number)
+        .with_epoch(epoch_year, epoch_day)
+        .with_inclination(inclination)
+        .with_right_ascension_of_ascending_node(right_ascension_of_ascending_node)
+        .with_eccentricity(eccentricity)
+        .with_argument_of_perigee(argument_of_perigee)
+        .with_mean_anomaly(mean_anomaly)
+        .with_mean_motion(mean_motion)
+        .build()?;
+
+    return Ok(tle);
+}
+
+/// Builds a [`Tle`] struct from the bytes of a single Two-Line Element set.
+///
+/// This parses a 2-line or 3-line (name + 2 lines) entry straight from a byte buffer, e.g. as read from a socket,
+/// without first converting it to a string. Every line must be ASCII, which makes the bytes valid UTF-8 so the lines
+/// are parsed in place by [`from_lines`]. Blank lines and `\n` or `\r\n` line endings are ignored.
+///
+/// # Arguments
+/// * `data` - The bytes of a single Two-Line Element set
+///
+/// # Returns
+/// * `Result<Tle, TleError>` - The parsed TLE
+///
+/// # Errors
+/// * `TleError::InvalidLineCount` if the data does not hold exactly 2 or 3 non-blank lines
+/// * `TleError::NonAsciiOrTab` if a line contains a non-ASCII byte, carrying its line number (0 for the name line)
+/// * Any error returned by [`from_lines`]
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_bytes;
+///
+/// // Define the TLE bytes
+/// let data = b"ISS (ZARYA)\r\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\r\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537\r\n";
+///
+/// // Parse the TLE bytes into a TLE struct
+/// let tle = from_bytes(data)?;
+/// assert_eq!(tle.satellite_catalog_number, 25544);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+///
+/// # References
+/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
+pub fn from_bytes(data: &[u8]) -> Result<Tle, TleError> {
+    // Collect the non-blank lines, at most 3
+    let mut lines: [&[u8]; 3] = [&[]; 3];
+    let mut n_lines = 0;
+    for line in data.split(|&b| b == b'\n').map(<[u8]>::trim_ascii_end).filter(|line| !line.is_empty()) {
+        if n_lines == 3 {
+            return Err(TleError::InvalidLineCount);
+        }
+        lines[n_lines] = line;
+        n_lines += 1;
+    }
+
+    // Number the lines as in the 2-line or 3-line format
+    let first_line = match n_lines {
+        2 => 1,
+        3 => 0,
+        _ => return Err(TleError::InvalidLineCount),
+    };
+
+    // Validate that each line is ASCII, and so valid UTF-8
+    let mut text: [&str; 3] = [""; 3];
+    for (i, line) in lines[..n_lines].iter().enumerate() {
+        if !line.is_ascii() {
+            return Err(TleError::NonAsciiOrTab { line: first_line + i });
+        }
+        text[i] = core::str::from_utf8(line).map_err(|_| TleError::NonAsciiOrTab { line: first_line + i })?;
+    }
+
+    return match n_lines {
+        2 => from_lines(text[0], text[1], None),
+        _ => from_lines(text[1], text[2], Some(text[0])),
+    };
+}
+
+/// Builds a vector of [`Tle`] structs from a string containing Two-Line Element sets.
+///
+/// This function parses a string containing one or more TLEs in either
+/// 2-line or 3-line (name + 2 lines) format. Every data line must belong to a
+/// complete TLE entry and every entry must parse, otherwise an error is returned.
+/// Other lines, like comments and headers, are skipped (see [`from_file_contents`]).
+///
+/// # Arguments
+/// * `tle_string` - A string containing one or more Two-Line Element sets
+///
+/// # Returns
+/// * `Result<Vec<Tle>, TleError>` - On success, a vector containing all of the parsed TLEs
+///
+/// # Errors
+/// * `TleError::InvalidLineCount` if a data line does not belong to a complete 2-line or 3-line entry
+/// * Any error returned by [`from_lines`] for the first entry that fails to parse
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_string;
+///
+/// // Define the TLE string
+/// let tle_string = "ISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// 
+/// // Parse the TLE string into a TLE struct
+/// let tles = from_string(tle_string)?;
+/// let tle = &tles[0];
+/// 
+/// // Assert the TLE struct is correct
+/// assert_eq!(tle.satellite_catalog_number, 25544);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+///
+/// # References
+/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
+#[cfg(feature = "std")]
+pub fn from_string(tle_string: &str) -> Result<Vec<Tle>, TleError> {
+    // Parse each entry, stopping at the first error
+    let tles = from_file_contents(tle_string).into_iter().collect();
+
+    // Return vector of TLEs
+    return tles;
+}
+
+/// Builds a vector of [`Tle`] structs from a JSON array of `{"name", "line1", "line2"}` objects.
+///
+/// Some APIs serve TLEs as JSON, keeping the lines of each entry intact rather than splitting them into the mean
+/// elements of an OMM (see the `omm` feature). Each object is parsed with [`from_lines`], using the name as the name
+/// line when it is present and not empty. Every entry must parse, otherwise an error is returned.
+///
+/// # Arguments
+/// * `s` - The JSON text
+///
+/// # Returns
+/// * `Result<Vec<Tle>, TleError>` - On success, a vector containing the parsed TLEs in the order of the array
+///
+/// # Errors
+/// * `TleError::InvalidJson` if the text is not a JSON array of objects with string `line1` and `line2` fields
+/// * Any error returned by [`from_lines`] for the first entry that fails to parse
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_json_array;
+///
+/// // Define the JSON array
+/// let json = r#"[{"name": "ISS (ZARYA)",
+///     "line1": "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921",
+///     "line2": "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"}]"#;
+///
+/// // Parse the JSON array into TLE structs
+/// let tles = from_json_array(json)?;
+/// assert_eq!(tles[0].common_name, "ISS (ZARYA)");
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+#[cfg(feature = "serde")]
+pub fn from_json_array(s: &str) -> Result<Vec<Tle>, TleError> {
+    // Deserialize the array of entries
+    let entries: Vec<JsonTleEntry> = serde_json::from_str(s).map_err(|_| TleError::InvalidJson)?;
+
+    // Parse each entry, stopping at the first error
+    return entries
+        .iter()
+        .map(|entry| {
+            let line0 = if entry.name.trim().is_empty() { None } else { Some(entry.name.as_str()) };
+            from_lines(&entry.line1, &entry.line2, line0)
+        })
+        .collect();
+}
+
+/// Builds a vector of [`Tle`] structs from a string containing Two-Line Element sets, skipping invalid entries.
+///
+/// This is the best-effort counterpart of [`from_string`]. Lines that do not
+/// belong to a complete entry and entries that fail to parse are skipped, and
+/// all successfully parsed entries are returned. With the `log` feature, each
+/// skipped entry is reported as a warning along with its error.
+///
+/// # Arguments
+/// * `tle_string` - A string containing one or more Two-Line Element sets
+///
+/// # Returns
+/// * `Vec<Tle>` - A vector containing all successfully parsed TLEs
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_string_lossy;
+///
+/// // Define a TLE string with a truncated entry before the ISS
+/// let tle_string = "1 25544U 98067A\nISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+/// 
+/// // Parse the TLE string, skipping the truncated entry
+/// let tles = from_string_lossy(tle_string);
+/// 
+/// // Assert only the ISS was parsed
+/// assert_eq!(tles.len(), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn from_string_lossy(tle_string: &str) -> Vec<Tle> {
+    // Parse each entry
+    let entries = from_file_contents(tle_string);
+
+    // Report the entries that are skipped
+    #[cfg(feature = "log")]
+    for (index, entry) in entries.iter().enumerate() {
+        if let Err(error) = entry {
+            log::warn!("Skipping invalid TLE entry {}: {:?}", index, error);
+        }
+    }
+
+    // Keep only the successfully parsed TLEs
+    let tles = entries
+        .into_iter()
+        .filter_map(Result::ok)
+        .collect();
+
+    // Return vector of TLEs
+    return tles;
+}
+
+/// Builds a vector of [`Tle`] structs from a file containing Two-Line Element sets.
+///
+/// This function parses a file containing one or more TLEs in either
+/// 2-line or 3-line (name + 2 lines) format and returns all successfully
+/// parsed entries (see [`from_string_lossy`]).
+///
+/// # Arguments
+/// * `file_path` - A path to a file containing one or more Two-Line Element sets
+///
+/// # Returns
+/// * `Vec<Tle>` - A vector containing all successfully parsed TLEs.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_file;
+///
+/// // Define the TLE file path
+/// let tle_file_path = "assets/test.tle";
+/// 
+/// // Parse the TLE file into a TLE struct
+/// let tles = from_file(tle_file_path);
+/// let tle = &tles[12];
+/// 
+/// // Assert the TLE structs are correct
+/// assert_eq!(tle.satellite_catalog_number, 25544);
+/// ```
+///
+/// # References
+/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
+#[cfg(feature = "std")]
+pub fn from_file(file_path: &str) -> Vec<Tle> {
+    // Open the TLE file
+    let tle_string = fs::read_to_string(file_path)
+        .expect("Cannot read TLE file");
+    
+    // Parse tle string into a vector of TLEs
+    let tles = from_string_lossy(&tle_string);
+
+    // Return the vector of TLEs
+    return tles;
+}
+
+/// Builds the parse result of every entry in a multi-satellite TLE file.
+///
+/// This reads the file and parses its contents with [`from_file_contents`].
+///
+/// # Arguments
+/// * `path` - A path to a file containing one or more Two-Line Element sets
+///
+/// # Returns
+/// * `io::Result<Vec<Result<Tle, TleError>>>` - The parse result of each record, in order
+///
+/// # Errors
+/// * Any I/O error encountered while reading the file
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::from_path;
+///
+/// // Parse every record in the TLE file
+/// let entries = from_path("assets/test.tle")?;
+///
+/// // Keep the records that parsed successfully
+/// let tles: Vec<_> = entries.into_iter().filter_map(Result::ok).collect();
+/// assert_eq!(tles[12].satellite_catalog_number, 25544);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Vec<Result<Tle, TleError>>> {
+    // Read the TLE file
+    let tle_string = fs::read_to_string(path)?;
+
+    // Parse each record in the file
+    return Ok(from_file_contents(&tle_string));
+}
+
+/// Builds the parse result of every entry in the contents of a multi-satellite TLE file.
+///
+/// Bulk files (e.g. from Celestrak) contain many TLEs concatenated with newlines.
+/// Lines are grouped into records by their `1 ` and `2 ` line-number prefixes, so
+/// 2-line and 3-line (named) records may be intermixed, and each record is parsed
+/// independently so one bad record does not abort the rest. Blank lines and CRLF
+/// line endings are ignored, as are lines that are neither data lines nor a name
+/// line directly preceding a line 1, like the comments and headers of Space-Track
+/// bulk exports.
+///
+/// # Arguments
+/// * `tle_string` - The contents of a file containing one or more Two-Line Element sets
+///
+/// # Returns
+/// * `Vec<Result<Tle, TleError>>` - The parse result of each record, in order. Lines that
+///   do not belong to a complete record produce `TleError::InvalidLineCount`
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::{from_file_contents, TleError};
+///
+/// // Define a file with a corrupted checksum on the first record
+/// let contents = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2920\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537\nISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537\n";
+///
+/// // Parse each record independently
+/// let entries = from_file_contents(contents);
+/// assert_eq!(entries[0].as_ref().err(), Some(&TleError::ChecksumFailed { line: 1 }));
+/// assert_eq!(entries[1].as_ref().map(|tle| tle.satellite_catalog_number).ok(), Some(25544));
+/// ```
+///
+/// # References
+/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
+#[cfg(feature = "std")]
+pub fn from_file_contents(tle_string: &str) -> Vec<Result<Tle, TleError>> {
+    // Parse each record (reading from a string cannot fail)
+    let entries = tle_iter(tle_string.as_bytes()).collect();
+
+    // Return the parsed entries
+    return entries;
+}
+
+/// Builds a lazy iterator over the entries of a reader containing multi-satellite Two-Line Element sets.
+///
+/// This is the streaming counterpart of [`from_file_contents`] for catalogs too large to hold in
+/// memory. Lines are read on demand and end at `\n` or `\r`, and at most one entry (3 lines of at
+/// most 256 bytes) is buffered on top of the reader's own buffer, so memory use does not grow with
+/// the size of the catalog. Records are grouped and parsed exactly as in [`from_file_contents`], so
+/// one bad record yields an error and the iteration continues. Lines longer than 256 bytes or that
+/// are not UTF-8 are skipped like comments, unless they are the name line of an entry.
+///
+/// # Arguments
+/// * `reader` - A buffered reader of one or more Two-Line Element sets (e.g. a `BufReader<File>`)
+///
+/// # Returns
+/// * `impl Iterator<Item = Result<Tle, TleError>>` - The parse result of each record, in order. Lines that
+///   do not belong to a complete record produce `TleError::InvalidLineCount`
+///
+/// # Errors
+/// Each item is an error if its record fails to parse (see [`from_lines`]). An entry whose name line is
+/// longer than 256 bytes or not UTF-8 yields a `TleError::Io` of kind `io::ErrorKind::InvalidData`,
+/// and the iteration continues. If reading fails, the records read before the failure are yielded,
+/// then a `TleError::Io` carrying the kind of I/O error, and the iteration ends. A reader without a
+/// line ending in its first megabyte past the last line fails with `io::ErrorKind::InvalidData`.
+///
+/// # Examples
+/// ```rust
+/// use std::fs::File;
+/// use std::io::BufReader;
+/// use Rusty_SGP4::tle::tle_iter;
+///
+/// // Stream the records of a TLE file
+/// let reader = BufReader::new(File::open("assets/test.tle")?);
+///
+/// // Count the ISS records without collecting the catalog
+/// let iss_count = tle_iter(reader)
+///     .filter_map(Result::ok)
+///     .filter(|tle| tle.satellite_catalog_number == 25544)
+///     .count();
+/// assert_eq!(iss_count, 1);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # References
+/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
+#[cfg(feature = "std")]
+pub fn tle_iter<R: io::BufRead>(reader: R) -> impl Iterator<Item = Result<Tle, TleError>> {
+    return TleRecords { reader: reader, pending: VecDeque::with_capacity(3), finished: false, error: None };
+}
+
+/// Builds a vector of [`Tle`] structs from a reader of a gzip-compressed multi-satellite Two-Line Element set.
+///
+/// Celestrak and Space-Track serve bulk catalogs gzip-compressed (`.gz`). The stream is decompressed on the fly and
+/// its records are grouped as in [`tle_iter`], and every entry must parse, as in [`from_string`].
+///
+/// # Arguments
+/// * `reader` - A reader of the gzip-compressed catalog (e.g. a `File`)
+///
+/// # Returns
+/// * `Result<Vec<Tle>, TleError>` - On success, a vector containing all of the parsed TLEs
+///
+/// # Errors
+/// * `TleError::Io` if reading or decompressing the stream fails (e.g. it is not gzip-compressed)
+/// * `TleError::InvalidLineCount` if a data line does not belong to a complete 2-line or 3-line entry
+/// * Any error returned by [`from_lines`] for the first entry that fails to parse
+///
+/// # Examples
+/// ```rust
+/// use std::fs::File;
+/// use Rusty_SGP4::tle::from_gzip_reader;
+///
+/// // Read a compressed bulk export
+/// let tles = from_gzip_reader(File::open("assets/spacetrack_bulk.txt.gz").unwrap())?;
+/// assert_eq!(tles.len(), 3);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+#[cfg(feature = "flate2")]
+pub fn from_gzip_reader<R: io::Read>(reader: R) -> Result<Vec<Tle>, TleError> {
+    // Decompress the stream and parse each entry, stopping at the first error
+    let decoder = io::BufReader::new(flate2::read::MultiGzDecoder::new(reader));
+    let tles = tle_iter(decoder).collect();
+
+    return tles;
+}
+
+/// Convert a string slice into a [`TleString`].
+///
+/// # Arguments
+/// * `text` - The text to convert
+///
+/// # Returns
+/// * `TleString` - The owned text
+#[cfg(feature = "std")]
+fn to_tle_string(text: &str) -> TleString {
+    return text.to_string();
+}
+
+/// Convert a string slice into a [`TleString`], truncating it to the string capacity.
+///
+/// # Arguments
+/// * `text` - The text to convert
+///
+/// # Returns
+/// * `TleString` - The owned text, holding as many leading characters as fit
+#[cfg(not(feature = "std"))]
+fn to_tle_string(text: &str) -> TleString {
+    let mut tle_string = TleString::new();
+    for c in text.chars() {
+        if tle_string.push(c).is_err() {
+            break;
+        }
+    }
+    return tle_string;
+}
+
+/// Truncate a satellite name to the 24 characters of the TLE name line.
+///
+/// # Arguments
+/// * `name` - The satellite name
+///
+/// # Returns
+/// * `&str` - The first 24 characters of the name
+fn truncate_name(name: &str) -> &str {
+    return match name.char_indices().nth(24) {
+        Some((end, _)) => {
+            #[cfg(feature = "log")]
+            log::warn!("Truncating the satellite name {:?} to 24 characters", name);
+            &name[..end]
+        }
+        None => name,
+    };
+}
+
+/// Check that a field spec can be decoded without overflowing or reading past its field.
+///
+/// # Arguments
+/// * `spec` - The location and format of the field
+///
+/// # Returns
+/// * `Result<(), TleError>` - Nothing, or `TleError::InvalidFieldSpec` if the field is on a line other than 1 or 2, or
+///   is an exponential field that is not 8 columns wide or whose multiplier overflows the scaled mantissa
+fn validate_field_spec(spec: &FieldSpec) -> Result<(), TleError> {
+    let invalid = Err(TleError::InvalidFieldSpec { field: spec.name });
+    if spec.line != 1 && spec.line != 2 {
+        return invalid;
+    }
+    let exponential_fits = match spec.kind {
+        FieldKind::Exponential { multiplier } => {
+            spec.end.checked_sub(spec.start) == Some(EXPONENTIAL_FIELD_WIDTH) && multiplier.unsigned_abs() <= MAX_EXPONENTIAL_MULTIPLIER as u64
+        }
+        _ => true,
+    };
+    if !exponential_fits {
+        return invalid;
+    }
+
+    return Ok(());
+}
+
+/// Decode a field of a TLE data line according to its spec.
+///
+/// # Arguments
+/// * `line` - The TLE data line holding the field
+/// * `spec` - The location and format of the field
+///
+/// # Returns
+/// * `Result<FieldValue, TleError>` - The decoded value, or `TleError::FieldParse` carrying the offending substring
+fn parse_spec_field(line: &str, spec: &FieldSpec) -> Result<FieldValue, TleError> {
+    let (name, start, end) = (spec.name, spec.start, spec.end);
+    let value = match spec.kind {
+        FieldKind::CatalogNumber => FieldValue::Integer(parse_catalog_number(line, start, end, name)? as i64),
+        FieldKind::Character => FieldValue::Character(parse_field(line, start, end, name)?),
+        FieldKind::Text => FieldValue::Text(to_tle_string(field_slice(line, start, end, name)?.trim())),
+        FieldKind::Year => {
+            // Expand the two-digit year
+            let yr_two_digit: i64 = parse_field(line, start, end, name)?;
+            FieldValue::Integer(if yr_two_digit < 57 { 2000 + yr_two_digit } else { 1900 + yr_two_digit })
+        }
+        FieldKind::Integer => FieldValue::Integer(parse_field(line, start, end, name)?),
+        FieldKind::Decimal { multiplier } => FieldValue::Real(parse_field::<f64>(line, start, end, name)? * multiplier),
+        FieldKind::DecimalFraction => {
+            let raw = field_slice(line, start, end, name)?;
+            FieldValue::Real(parse_decimal_fraction(raw.trim()).ok_or_else(|| field_parse_error(name, start, end, raw))?)
+        }
+        FieldKind::Exponential { multiplier } => FieldValue::Real(parse_exponential_field(line, start, end, name, multiplier)?),
+    };
+
+    return Ok(value);
+}
+
+/// Store a decoded value in the named field of a TLE.
+///
+/// # Arguments
+/// * `tle` - The TLE being parsed
+/// * `name` - The name of the field
+/// * `value` - The decoded value
+///
+/// # Returns
+/// * `Result<(), TleError>` - `TleError::OutOfRange` if an integer does not fit the field, or
+///   `TleError::InvalidFieldSpec` if the field is unknown or the value is of the wrong kind
+fn set_field(tle: &mut Tle, name: &'static str, value: FieldValue) -> Result<(), TleError> {
+    let to_i32 = |value: i64| i32::try_from(value).map_err(|_| TleError::OutOfRange { field: name, value: value as f64 });
+    match (name, value) {
+        ("satellite_catalog_number", FieldValue::Integer(value)) => tle.satellite_catalog_number = to_i32(value)?,
+        ("classification", FieldValue::Character(value)) => tle.classification = value,
+        ("international_designator", FieldValue::Text(value)) => tle.international_designator = value,
+        ("epoch_year", FieldValue::Integer(value)) => tle.epoch_year = to_i32(value)?,
+        ("epoch_day", FieldValue::Real(value)) => tle.epoch_day = value,
+        ("first_derivative_of_mean_motion", FieldValue::Real(value)) => tle.first_derivative_of_mean_motion = value,
+        ("second_derivative_of_mean_motion", FieldValue::Real(value)) => tle.second_derivative_of_mean_motion = value,
+        ("bstar", FieldValue::Real(value)) => tle.bstar = value,
+        ("ephemeris_type", FieldValue::Integer(value)) => tle.ephemeris_type = to_i32(value)?,
+        ("element_set_number", FieldValue::Integer(value)) => tle.element_set_number = to_i32(value)?,
+        ("inclination", FieldValue::Real(value)) => tle.inclination = value,
+        ("right_ascension_of_ascending_node", FieldValue::Real(value)) => tle.right_ascension_of_ascending_node = value,
+        ("eccentricity", FieldValue::Real(value)) => tle.eccentricity = value,
+        ("argument_of_perigee", FieldValue::Real(value)) => tle.argument_of_perigee = value,
+        ("mean_anomaly", FieldValue::Real(value)) => tle.mean_anomaly = value,
+        ("mean_motion", FieldValue::Real(value)) => tle.mean_motion = value,
+        ("revolution_number_at_epoch", FieldValue::Integer(value)) => tle.revolution_number_at_epoch = value,
+        _ => return Err(TleError::InvalidFieldSpec { field: name }),
+    }
+
+    return Ok(());
+}
+
+/// Check if a TLE data line contains a tab or a non-ASCII character.
+///
+/// The fields are sliced at fixed byte offsets, so a tab (standing in for several columns) or a multi-byte UTF-8
+/// character would misalign them or split a character.
+///
+/// # Arguments
+/// * `line` - The TLE data line
+///
+/// # Returns
+/// * `bool` - Whether the line contains a tab or a non-ASCII character
+fn has_tab_or_non_ascii(line: &str) -> bool {
+    return line.bytes().any(|b| b == b'\t' || !b.is_ascii());
+}
+
+/// Restore the line number column of a TLE data line that has been stripped of it.
+///
+/// A 67 character line that does not start with the line number and a space is copied into `buffer` behind
+/// them. Any other line is returned unchanged, to be validated as a standard 69 character line.
+///
+/// # Arguments
+/// * `line` - The TLE data line
+/// * `line_number` - The expected line number (`b'1'` or `b'2'`)
+/// * `buffer` - The storage for the restored line
+///
+/// # Returns
+/// * `&str` - The line with its line number
+fn restore_line_number<'a>(line: &'a str, line_number: u8, buffer: &'a mut [u8; 69]) -> &'a str {
+    if line.len() != 67 || line.as_bytes().starts_with(&[line_number, b' ']) {
+        return line;
+    }
+    buffer[0] = line_number;
+    buffer[1] = b' ';
+    buffer[2..].copy_from_slice(line.as_bytes());
+    return core::str::from_utf8(buffer).unwrap_or(line);
+}
+
+/// Parse the digits of a number with an assumed leading decimal point (e.g. `0006703` = 0.0006703).
+///
+/// The digits are read as an integer and scaled by a power of ten, which rounds identically to parsing `0.<digits>`.
+///
+/// # Arguments
+/// * `digits` - The digits following the assumed decimal point
+///
+/// # Returns
+/// * `Option<f64>` - The parsed value, or `None` if a character is not a digit
+fn parse_decimal_fraction(digits: &str) -> Option<f64> {
+    // Only digits are allowed after the assumed decimal point
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    if digits.is_empty() {
+        return Some(0.);
+    }
+
+    // Scale the integer value by the number of digits
+    let numerator = digits.parse::<u64>().ok()? as f64;
+    return Some(numerator / 10.0_f64.powi(digits.len() as i32));
+}
+
+/// Build the error for a fixed-column field of a TLE line that cannot be parsed.
+///
+/// This is the single place the 0-based, end-exclusive indices of the parser are converted to the 1-based, inclusive
+/// columns of the TLE format documentation (e.g. indices 52..63 are columns 53-63).
+///
+/// # Arguments
+/// * `field` - The name of the field
+/// * `start` - The index of the first character of the field
+/// * `end` - The index one past the last character of the field
+/// * `raw` - The offending substring
+///
+/// # Returns
+/// * `TleError` - The `TleError::FieldParse` carrying the field name, columns and substring
+fn field_parse_error(field: &'static str, start: usize, end: usize, raw: &str) -> TleError {
+    return TleError::FieldParse { field: field, columns: Some((start + 1, end)), raw: to_tle_string(raw) };
+}
+
+/// Extract a fixed-column field of a TLE line.
+///
+/// The columns are checked rather than indexed, so a short line or one with multi-byte UTF-8 characters is an error
+/// instead of a panic. The field must be ASCII, so it can be sliced further by byte offsets.
+///
+/// # Arguments
+/// * `line` - The TLE line
+/// * `start` - The index of the first character of the field
+/// * `end` - The index one past the last character of the field
+/// * `field` - The name of the field, reported on error
+///
+/// # Returns
+/// * `Result<&str, TleError>` - The field, or `TleError::FieldParse` if the columns are missing or not ASCII
+fn field_slice<'a>(line: &'a str, start: usize, end: usize, field: &'static str) -> Result<&'a str, TleError> {
+    return match line.get(start..end) {
+        Some(raw) if raw.is_ascii() => Ok(raw),
+        _ => Err(field_parse_error(field, start, end, line.get(start..end).unwrap_or(""))),
+    };
+}
+
+/// Parse a fixed-column field of a TLE line.
+///
+/// # Arguments
+/// * `line` - The TLE line
+/// * `start` - The index of the first character of the field
+/// * `end` - The index one past the last character of the field
+/// * `field` - The name of the field, reported on error
+///
+/// # Returns
+/// * `Result<T, TleError>` - The parsed field, or `TleError::FieldParse` carrying the offending substring
+fn parse_field<T: FromStr>(line: &str, start: usize, end: usize, field: &'static str) -> Result<T, TleError> {
+    let raw = field_slice(line, start, end, field)?;
+    return raw.trim().parse::<T>().map_err(|_| field_parse_error(field, start, end, raw));
+}
+
+/// Parse a fixed-column field of a TLE line written in the TLE exponential format (e.g. `-11606-4` = -0.11606e-4).
+///
+/// The sign of the mantissa may be `-`, `+` or a space (positive), and the exponent may be signed with `-`, `+` or a
+/// space (positive), since feeds differ in how they write positive values.
+///
+/// The mantissa digits are kept as an integer and scaled by `multiplier` before a single division (or multiplication)
+/// by a power of ten, so the result is the closest `f64` to the exact value (e.g. `-00100-2` times 6 is exactly
+/// `-6e-5`, not `-6.000000000000001e-5`).
+///
+/// # Arguments
+/// * `line` - The TLE line
+/// * `start` - The index of the sign character of the field
+/// * `end` - The index one past the last character of the exponent
+/// * `field` - The name of the field, reported on error
+/// * `multiplier` - An integer factor applied to the value (e.g. 6 for the 2nd derivative of mean motion)
+///
+/// # Returns
+/// * `Result<f64, TleError>` - The parsed field, or `TleError::FieldParse` carrying the offending substring
+fn parse_exponential_field(line: &str, start: usize, end: usize, field: &'static str, multiplier: i64) -> Result<f64, TleError> {
+    let raw = field_slice(line, start, end, field)?;
+    let field_error = || field_parse_error(field, start, end, raw);
+
+    if raw.len() != EXPONENTIAL_FIELD_WIDTH {
+        return Err(field_error());
+    }
+
+    // Sign of the mantissa
+    let negative = raw.get(0..1).and_then(parse_sign).ok_or_else(field_error)?;
+
+    // Mantissa digits with an assumed leading decimal point
+    let digits = raw.get(1..6).ok_or_else(field_error)?.trim();
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(field_error());
+    }
+    let mantissa = if digits.is_empty() { 0 } else { digits.parse::<i64>().map_err(|_| field_error())? };
+
+    // Power of ten exponent, a sign followed by a single digit
+    let exponent_negative = raw.get(6..7).and_then(parse_sign).ok_or_else(field_error)?;
+    let exponent_digit = raw.get(7..8).ok_or_else(field_error)?.parse::<i32>().map_err(|_| field_error())?;
+    let exponent = if exponent_negative { -exponent_digit } else { exponent_digit };
+
+    // Scale the signed integer mantissa in a single rounding step (powers of ten up to 1e22 are exact)
+    let signed_mantissa = if negative { -mantissa } else { mantissa };
+    let numerator = signed_mantissa.checked_mul(multiplier).ok_or_else(field_error)? as f64;
+    let power = exponent - digits.len() as i32;
+    if power < 0 {
+        return Ok(numerator / 10.0_f64.powi(-power));
+    }
+    return Ok(numerator * 10.0_f64.powi(power));
+}
+
+/// Parse the sign character of a TLE exponential field.
+///
+/// # Arguments
+/// * `sign` - The sign character, `-`, `+` or a space
+///
+/// # Returns
+/// * `Option<bool>` - True if the sign is negative, or `None` if the character is not a sign
+fn parse_sign(sign: &str) -> Option<bool> {
+    return match sign {
+        "-" => Some(true),
+        "+" | " " => Some(false),
+        _ => None,
+    };
+}
+
+/// Parse the catalog number field of a TLE line, decoding Alpha-5 catalog numbers (e.g. `E8493` = 148493).
+///
+/// # Arguments
+/// * `line` - The TLE line
+/// * `start` - The index of the first character of the field
+/// * `end` - The index one past the last character of the field
+/// * `field` - The name of the field, reported on error
+///
+/// # Returns
+/// * `Result<i32, TleError>` - The numeric catalog number, or `TleError::FieldParse` carrying the offending substring
+fn parse_catalog_number(line: &str, start: usize, end: usize, field: &'static str) -> Result<i32, TleError> {
+    let raw = field_slice(line, start, end, field)?;
+    let field_error = || field_parse_error(field, start, end, raw);
+
+    // Numeric catalog numbers are parsed as usual
+    let leading = raw.chars().next().ok_or_else(field_error)?;
+    if !leading.is_ascii_alphabetic() {
+        return parse_field(line, start, end, field);
+    }
+
+    // The leading letter selects a block of 10,000 starting at 100,000
+    let block = ALPHA5_LETTERS.find(leading).ok_or_else(field_error)? as i32 + 10;
+
+    // The remaining 4 characters must be digits
+    let digits = &raw[1..];
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(field_error());
+    }
+    let remainder = digits.parse::<i32>().map_err(|_| field_error())?;
+
+    return Ok(block * 10000 + remainder);
+}
+
+/// Builds the two data lines of a Two-Line Element set from a [`Tle`] struct.
+///
+/// This is the inverse of [`from_lines`]. Each field is written with its exact
+/// column width in the canonical 69-column format, and the checksum of each
+/// line is recomputed. The exponential fields (2nd derivative of mean motion
+/// and B*) are written with a normalized mantissa, so a TLE whose source used
+/// an unnormalized mantissa (e.g. `-00100-2`) is written as its equivalent
+/// normalized form (`-10000-4`). Zero is written as `00000-0`, as in the
+/// element sets distributed by NORAD, and values below 1e-10, whose exponent
+/// does not fit in one digit, are written as zero. Values of 1e9 or more are
+/// written as the largest representable value, `99999+9`. Catalog numbers from
+/// 100,000 to 339,999 are written in the Alpha-5 format.
+///
+/// # Arguments
+/// * `tle` - The TLE to format
+///
+/// # Returns
+/// * `Result<(String, String), TleError>` - The first and second TLE data lines
+///
+/// # Errors
+/// Returns `TleError::OutOfRange` carrying the field name and value if a real valued field is not finite.
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::{from_lines, to_lines};
+///
+/// // Define the ISS TLE lines
+/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+/// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+///
+/// // Parse the TLE lines and write them back out
+/// let tle = from_lines(tle_line1, tle_line2, None)?;
+/// let (line1, line2) = to_lines(&tle)?;
+///
+/// // Assert the lines are identical
+/// assert_eq!(line1, tle_line1);
+/// assert_eq!(line2, tle_line2);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+///
+/// # References
+/// - [Celestrak TLE Format](https://celestrak.org/columns/v04n03/#FAQ01)
+#[cfg(feature = "std")]
+pub fn to_lines(tle: &Tle) -> Result<(String, String), TleError> {
+    // Only finite values fit the fixed width fields
+    let reals = [
+        ("epoch_day", tle.epoch_day),
+        ("first_derivative_of_mean_motion", tle.first_derivative_of_mean_motion),
+        ("second_derivative_of_mean_motion", tle.second_derivative_of_mean_motion),
+        ("bstar", tle.bstar),
+        ("inclination", tle.inclination),
+        ("right_ascension_of_ascending_node", tle.right_ascension_of_ascending_node),
+        ("eccentricity", tle.eccentricity),
+        ("argument_of_perigee", tle.argument_of_perigee),
+        ("mean_anomaly", tle.mean_anomaly),
+        ("mean_motion", tle.mean_motion),
+    ];
+    if let Some(&(field, value)) = reals.iter().find(|(_field, value)| !value.is_finite()) {
+        return Err(TleError::OutOfRange { field: field, value: value });
+    }
+
+    // Line 1
+    let mut line1 = format!(
+        "1 {}{} {:<8} {:02}{:012.8} {} {} {} {} {:>4}",
+        format_catalog_number(tle.satellite_catalog_number),
+        tle.classification,
+        tle.international_designator,
+        tle.epoch_year % 100,
+        tle.epoch_day,
+        format_first_derivative(tle.first_derivative_of_mean_motion / 2.0),
+        format_exponential_field(tle.second_derivative_of_mean_motion / 6.0),
+        format_exponential_field(tle.bstar),
+        tle.ephemeris_type,
+        tle.element_set_number,
+    );
+    line1.push_str(&calc_checksum(&line1).to_string());
+
+    // Line 2 (eccentricity has an assumed leading decimal point)
+    let mut line2 = format!(
+        "2 {} {:8.4} {:8.4} {:07} {:8.4} {:8.4} {:11.8}{:>5}",
+        format_catalog_number(tle.satellite_catalog_number),
+        tle.inclination,
+        tle.right_ascension_of_ascending_node,
+        (tle.eccentricity * 1e7).round() as i64,
+        tle.argument_of_perigee,
+        tle.mean_anomaly,
+        tle.mean_motion,
+        tle.revolution_number_at_epoch,
+    );
+    line2.push_str(&calc_checksum(&line2).to_string());
+
+    return Ok((line1, line2));
+}
+
+/// Format the catalog number field of a TLE line, using the Alpha-5 format above 99,999 (e.g. 148493 = `E8493`).
+///
+/// # Arguments
+/// * `catalog_number` - The numeric catalog number
+///
+/// # Returns
+/// * `String` - The 5 character field (catalog numbers above 339,999 cannot be encoded and are written in full)
+#[cfg(feature = "std")]
+fn format_catalog_number(catalog_number: i32) -> String {
+    // Numeric catalog numbers are zero padded
+    if catalog_number < 100000 {
+        return format!("{:05}", catalog_number);
+    }
+
+    // The leading letter encodes the block of 10,000
+    let block = (catalog_number / 10000 - 10) as usize;
+    return match ALPHA5_LETTERS.chars().nth(block) {
+        Some(letter) => format!("{}{:04}", letter, catalog_number % 10000),
+        None => catalog_number.to_string(),
+    };
+}
+
+/// Format the 1st derivative of mean motion field of line 1 (e.g. `-.00002182`).
+///
+/// # Arguments
+/// * `value` - The 1st derivative of mean motion divided by two \[revs/day^2\]
+///
+/// # Returns
+/// * `String` - The 10 character field, a sign followed by the value with no leading zero
+#[cfg(feature = "std")]
+fn format_first_derivative(value: f64) -> String {
+    // Format the magnitude and drop the leading zero
+    let magnitude = format!("{:.8}", value.abs());
+    let sign = if value < 0. { '-' } else { ' ' };
+
+    return format!("{}{}", sign, magnitude.trim_start_matches('0'));
+}
+
+/// Format a field in the TLE exponential format (e.g. -0.11606e-4 = `-11606-4`).
+///
+/// Zero and values that round below 1e-10 are written as `00000-0`, and values that round to 1e9 or more saturate at
+/// `99999+9`, so the field always fits its 8 columns.
+///
+/// # Arguments
+/// * `value` - The finite value of the field
+///
+/// # Returns
+/// * `String` - The 8 character field, a sign, a 5 digit mantissa with an assumed leading decimal point and a signed exponent
+#[cfg(feature = "std")]
+fn format_exponential_field(value: f64) -> String {
+    // Zero has no normalized mantissa
+    if value == 0. {
+        return " 00000-0".to_string();
+    }
+
+    // Normalize the mantissa to [0.1, 1), limiting the exponent so the scaling stays finite
+    let mut exponent = (value.abs().log10().floor() as i32 + 1).clamp(-11, 10);
+    let mut mantissa = (value.abs() / 10.0_f64.powi(exponent) * 1e5).round() as i64;
+
+    // Protect against rounding up to 1.0
+    if mantissa >= 100000 {
+        mantissa = mantissa / 10;
+        exponent = exponent + 1;
+    }
+
+    // The exponent has a single digit
+    let sign = if value < 0. { '-' } else { ' ' };
+    if exponent < -9 {
+        return " 00000-0".to_string();
+    }
+    if exponent > 9 {
+        return format!("{}99999+9", sign);
+    }
+    let exponent_sign = if exponent < 0 { '-' } else { '+' };
+
+    return format!("{}{:05}{}{}", sign, mantissa, exponent_sign, exponent.abs());
+}
+
+/// Calculate the checksum of the TLE line.
+///
+/// Given a TLE line, calculate the checksum of that line. Follow the following rules: 
+/// - Ignore alpha characters
+/// - Sum digits 0-9 as integer values
+/// - '-' is treated as 1
+/// - Return checksum % 10
+///
+/// # Arguments
+/// * `line` - The TLE line to calculate the checksum of
+///
+/// # Panics
+/// * If the TLE line is invalid (must be 69 characters)
+///
+/// # Returns
+/// * `checksum` - The checksum of the TLE line (integer 0-9)
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::calc_checksum;
+///
+/// // Define the TLE line
+/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+/// 
+/// // Calculate the checksum of the TLE line
+/// let checksum = calc_checksum(tle_line1);
+/// 
+/// // Assert the checksum is correct
+/// assert_eq!(checksum, 1);
+/// ```
+pub fn calc_checksum(line: &str) -> i32 {
+    // Initialize checksum to 0
+    let mut checksum = 0;
+
+    // Loop through the line and calculate the checksum
+    for c in line.chars().take(68) {
+        match c {
+            '0'..='9' => checksum += (c as u8 - b'0') as i32,
+            '-' => checksum += 1,
+            _ => {}
+        }
+    }
+
+    // Calculate the checksum
+    checksum = checksum % 10;
+
+    // Return the checksum
+    return checksum;
+}
+
+/// Check if the TLE line has been corrupted by running a checksum test.
+///
+/// Given a TLE line, check if the checksum of that line (column 69) is valid.
+///
+/// # Arguments
+/// * `line` - The TLE line to check the checksum of
+///
+/// # Returns
+/// * `Result<bool, TleError>` - On success, true if the checksum of the line is valid, false if otherwise
+///
+/// # Errors
+/// * `TleError::FieldParse` if the line has no checksum digit in column 69
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::tle_checksum;
+///
+/// // Define the TLE line
+/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+/// 
+/// // Calculate the checksum of the TLE line
+/// let checksum = tle_checksum(tle_line1)?;
+/// 
+/// // Assert the checksum is correct
+/// assert_eq!(checksum, true);
+/// # Ok::<(), Rusty_SGP4::tle::TleError>(())
+/// ```
+pub fn tle_checksum(line: &str) -> Result<bool, TleError> {
+    // Calculate the checksum of the line
+    let checksum = calc_checksum(line);
+
+    // Read the checksum digit from the last character of the line
+    let raw = line.get(68..69).unwrap_or("");
+    let expected = raw.parse::<i32>()
+        .map_err(|_| field_parse_error("checksum", 68, 69, raw))?;
+
+    // Compare the checksum to the last character of the line
+    return Ok(checksum == expected);
+}
+
+/// Validate the checksums of both data lines of a Two-Line Element set.
+///
+/// # Arguments
+/// * `line1` - The first TLE data line (NORAD line 1)
+/// * `line2` - The second TLE data line (NORAD line 2)
+///
+/// # Returns
+/// * `Result<(), TleError>` - Ok if both checksums are valid
+///
+/// # Errors
+/// * `TleError::ChecksumFailed` carrying the number of the first line whose checksum fails
+/// * `TleError::FieldParse` if a line has no checksum digit in column 69
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::validate_checksums;
+///
+/// // Define the TLE lines
+/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+/// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+///
+/// // Validate the checksums of both lines
+/// assert!(validate_checksums(tle_line1, tle_line2).is_ok());
+/// ```
+pub fn validate_checksums(line1: &str, line2: &str) -> Result<(), TleError> {
+    // Check line 1
+    if !tle_checksum(line1)? {
+        return Err(TleError::ChecksumFailed { line: 1 });
+    }
+
+    // Check line 2
+    if !tle_checksum(line2)? {
+        return Err(TleError::ChecksumFailed { line: 2 });
+    }
+
+    return Ok(());
+}
+
+/// Rewrite the checksums of both data lines of a Two-Line Element set.
+///
+/// Unlike `validate_checksums`, which reports a wrong checksum, this recomputes the checksum of each line from its
+/// first 68 columns and writes it to column 69, e.g. after a field has been edited by hand. Lines shorter than 68
+/// columns are padded with spaces so the checksum always lands in column 69.
+///
+/// # Arguments
+/// * `line1` - The first TLE data line (NORAD line 1)
+/// * `line2` - The second TLE data line (NORAD line 2)
+///
+/// # Returns
+/// * `(String, String)` - The two lines with corrected checksums
+///
+/// # Examples
+/// ```rust
+/// use Rusty_SGP4::tle::{validate_checksums, with_corrected_checksums};
+///
+/// // Define the TLE lines, with the mean motion of line 2 edited
+/// let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+/// let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125491563537";
+///
+/// // Correct the checksums of both lines
+/// let (line1, line2) = with_corrected_checksums(tle_line1, tle_line2);
+/// assert_eq!(line2, "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125491563538");
+/// assert!(validate_checksums(&line1, &line2).is_ok());
+/// ```
+#[cfg(feature = "std")]
+pub fn with_corrected_checksums(line1: &str, line2: &str) -> (String, String) {
+    return (correct_checksum(line1), correct_checksum(line2));
+}
+
+/// Rewrite the checksum digit (column 69) of a single TLE line.
+///
+/// # Arguments
+/// * `line` - The TLE line to correct
+///
+/// # Returns
+/// * `String` - The first 68 columns of the line, padded with spaces if needed, followed by the checksum digit
+#[cfg(feature = "std")]
+fn correct_checksum(line: &str) -> String {
+    // Keep the data columns and pad short lines
+    let data: String = line.trim_end_matches(['\r', '\n']).chars().take(68).collect();
+    let mut corrected = format!("{:<68}", data);
+
+    // Append the recomputed checksum
+    corrected.push_str(&calc_checksum(&corrected).to_string());
+    return corrected;
+}
+
+// ----------
+// Unit Tests
+// ----------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::WGS72;
+    #[cfg(feature = "std")]
+    use crate::time::Timezone;
+
+    #[test]
+    fn test_checksum_calculation() {
+        // Define the TLE line
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+
+        // Calculate the checksum of the TLE line
+        let checksum = calc_checksum(tle_line1);
+
+        // Assert the checksum is correct
+        assert_eq!(checksum, 1);
     }
 
     #[test]
     fn test_checksum_comparison() {
         // Define the TLE line
         let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
-        let tle_line2 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2922";
+        let tle_line2 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2922";
+
+        // Calculate the checksum of the TLE line
+        let checksum = tle_checksum(tle_line1).unwrap();
+        let checksum2 = tle_checksum(tle_line2).unwrap();
+
+        // Assert the checksum is correct
+        assert!(checksum);
+        assert!(!checksum2);
+
+        // A line without a checksum digit is an error rather than a panic
+        let result = tle_checksum(&tle_line1[..60]);
+        assert_eq!(result, Err(TleError::FieldParse { field: "checksum", columns: Some((69, 69)), raw: TleString::new() }));
+    }
+
+    #[test]
+    fn test_checksum_validation() {
+        // Define the ISS TLE lines (both with valid checksums)
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+        // Assert both lines are valid
+        assert_eq!(calc_checksum(tle_line2), 7);
+        assert_eq!(validate_checksums(tle_line1, tle_line2), Ok(()));
+
+        // Corrupt the mean motion of line 2 without fixing the checksum
+        let tle_line2_bad = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125491563537";
+        assert_eq!(validate_checksums(tle_line1, tle_line2_bad), Err(TleError::ChecksumFailed { line: 2 }));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_checksum_correction() {
+        // Define the ISS TLE lines, with a deliberately wrong checksum on line 1
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2925";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+        // Only the checksum digit changes
+        let (line1, line2) = with_corrected_checksums(tle_line1, tle_line2);
+        assert_eq!(line1, "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921");
+        assert_eq!(line2, tle_line2);
+        assert_eq!(validate_checksums(&line1, &line2), Ok(()));
+
+        // A line without a checksum digit has one appended in column 69
+        let (line1, _) = with_corrected_checksums(&tle_line1[..68], tle_line2);
+        assert_eq!(line1, "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921");
+    }
+
+    #[test]
+    fn test_tle_parsing_from_lines() {
+        // Define the TLE lines
+        let tle_line0 = "ISS (ZARYA)";
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+        // Parse the TLE lines into a TLE struct
+        let tle = from_lines(tle_line1, tle_line2, Some(tle_line0)).unwrap();
+
+        // Assert the TLE struct is correct
+        assert_eq!(tle.common_name, "ISS (ZARYA)");
+        assert_eq!(tle.satellite_catalog_number, 25544);
+        assert_eq!(tle.classification, 'U');
+        assert_eq!(tle.international_designator, "98067A");
+        assert_eq!(tle.epoch_year, 2008);
+        assert_eq!(tle.epoch_day, 264.51782528);
+        assert_eq!(tle.first_derivative_of_mean_motion, -0.00004364);
+        assert_eq!(tle.second_derivative_of_mean_motion, -6.0e-5);
+        assert_eq!(tle.bstar, -0.000011606);
+        assert_eq!(tle.ephemeris_type, 0);
+        assert_eq!(tle.element_set_number, 292);
+        assert_eq!(tle.inclination, 51.6416);
+        assert_eq!(tle.right_ascension_of_ascending_node, 247.4627);
+        assert_eq!(tle.eccentricity, 0.0006703);
+        assert_eq!(tle.argument_of_perigee, 130.536);
+        assert_eq!(tle.mean_anomaly, 325.0288);
+        assert_eq!(tle.mean_motion, 15.72125391);
+        assert_eq!(tle.revolution_number_at_epoch, 56353);
+    }
+
+    #[test]
+    fn test_tle_parsing_name_line() {
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let name = |line0: &str| from_lines(tle_line1, tle_line2, Some(line0)).unwrap().common_name;
+
+        // A name of exactly 24 characters is kept whole, with trailing padding removed
+        assert_eq!(name("ISS (ZARYA) MODULE 1A/R1"), "ISS (ZARYA) MODULE 1A/R1");
+        assert_eq!(name("ISS (ZARYA)             "), "ISS (ZARYA)");
+
+        // The line number of the three-line format is removed
+        assert_eq!(name("0 ISS (ZARYA)"), "ISS (ZARYA)");
+        assert_eq!(name("0 ISS (ZARYA) MODULE 1A/R1"), "ISS (ZARYA) MODULE 1A/R1");
+
+        // An over-long name is truncated to 24 characters
+        assert_eq!(name("INTERNATIONAL SPACE STATION"), "INTERNATIONAL SPACE STAT");
+
+        // A blank name line leaves the name empty
+        assert_eq!(name(""), "");
+        assert_eq!(name("   "), "");
+    }
+
+    #[test]
+    fn test_tle_parsing_without_line_numbers() {
+        // Define the TLE lines with and without the line number column
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let stripped_line1 = &tle_line1[2..];
+        let stripped_line2 = &tle_line2[2..];
+        assert_eq!(stripped_line1.len(), 67);
+
+        // Both formats parse into the same TLE, in any combination
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(from_lines(stripped_line1, stripped_line2, None).unwrap(), tle);
+        assert_eq!(from_lines(tle_line1, stripped_line2, None).unwrap(), tle);
+        assert_eq!(from_lines(stripped_line1, tle_line2, None).unwrap(), tle);
+
+        // The checksum of a stripped line is that of the standard line
+        let stripped_line2_bad = "25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563538";
+        assert_eq!(from_lines(stripped_line1, stripped_line2_bad, None).err(), Some(TleError::ChecksumFailed { line: 2 }));
+
+        // A truncated standard line is not mistaken for a stripped line
+        let truncated_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  29";
+        assert_eq!(from_lines(truncated_line1, tle_line2, None).err(), Some(TleError::WrongLineLength { line: 1, length: 67 }));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_approx_eq() {
+        let tle_line0 = "ISS (ZARYA)";
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, Some(tle_line0)).unwrap();
+
+        // The parse of the written TLE is approximately equal to the original
+        let round_trip = from_string(&tle.to_string()).unwrap().remove(0);
+        assert!(tle.approx_eq(&round_trip, 1e-12));
+        assert!(round_trip.approx_eq(&tle, 1e-12));
+
+        // Real fields are compared within the tolerance, relatively for large values
+        let other = Tle { mean_motion: tle.mean_motion * (1. + 1e-10), bstar: tle.bstar + 1e-11, ..tle.clone() };
+        assert!(tle.approx_eq(&other, 1e-9));
+        assert!(!tle.approx_eq(&other, 1e-12));
+        let other = Tle { epoch_day: tle.epoch_day + 1e-6, ..tle.clone() };
+        assert!(tle.approx_eq(&other, 1e-8));
+        assert!(!tle.approx_eq(&other, 1e-9));
+
+        // Angles are compared absolutely across the wrap at 360 degrees
+        let a = Tle { mean_anomaly: 359.9999999999, ..tle.clone() };
+        let b = Tle { mean_anomaly: 0.0000000001, ..tle.clone() };
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&Tle { mean_anomaly: 180., ..tle.clone() }, 1e-9));
+
+        // Integer, character and text fields must match exactly
+        assert!(!tle.approx_eq(&Tle { element_set_number: 293, ..tle.clone() }, 1.));
+        assert!(!tle.approx_eq(&Tle { classification: 'C', ..tle.clone() }, 1.));
+        assert!(!tle.approx_eq(&Tle { common_name: to_tle_string("ISS"), ..tle.clone() }, 1.));
+
+        // A NaN never compares equal
+        assert!(!tle.approx_eq(&Tle { eccentricity: f64::NAN, ..tle.clone() }, 1.));
+    }
+
+    #[test]
+    fn test_tle_parsing_from_3le() {
+        let tle_line0 = "ISS (ZARYA)";
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+        // The three components parse as from_lines with the name
+        let tle = from_3le(tle_line0, tle_line1, tle_line2).unwrap();
+        assert_eq!(tle, from_lines(tle_line1, tle_line2, Some(tle_line0)).unwrap());
+        assert_eq!(tle.common_name, "ISS (ZARYA)");
+        assert_eq!(tle.satellite_catalog_number, 25544);
+
+        // The line number and terminators of the name line are removed, and a blank name is empty
+        assert_eq!(from_3le("0 ISS (ZARYA)\r\n", tle_line1, tle_line2).unwrap(), tle);
+        assert_eq!(from_3le("", tle_line1, tle_line2).unwrap().common_name, "");
+
+        // Errors in the data lines are reported
+        let corrupted_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563538";
+        assert_eq!(from_3le(tle_line0, tle_line1, corrupted_line2).err(), Some(TleError::ChecksumFailed { line: 2 }));
+    }
+
+    #[test]
+    fn test_tle_parsing_with_spec() {
+        // The standard layout reproduces the parse of from_lines, bit for bit
+        let entries = [
+            ("1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921", "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"),
+            ("1 66925U 25286A   25348.25003472 -.01252121  46857-3 -33539-2 0  9990", "2 66925  53.1578  43.0382 0000844  85.9465  63.2898 15.93822682  2231"),
+            ("1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753", "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667"),
+        ];
+        for (tle_line1, tle_line2) in entries {
+            let expected = from_lines(tle_line1, tle_line2, None).unwrap();
+            let tle = parse_with_spec(tle_line1, tle_line2, &FIELD_SPECS).unwrap();
+            assert_eq!(tle, expected);
+            assert_eq!(tle.second_derivative_of_mean_motion.to_bits(), expected.second_derivative_of_mean_motion.to_bits());
+            assert_eq!(tle.bstar.to_bits(), expected.bstar.to_bits());
+            assert_eq!(tle.epoch_day.to_bits(), expected.epoch_day.to_bits());
+            assert_eq!(tle.eccentricity.to_bits(), expected.eccentricity.to_bits());
+        }
+        let tle = parse_with_spec(entries[0].0, entries[0].1, &FIELD_SPECS).unwrap();
+        assert_eq!((tle.satellite_catalog_number, tle.classification, tle.international_designator.as_str()), (25544, 'U', "98067A"));
+        assert_eq!((tle.epoch_year, tle.epoch_day), (2008, 264.51782528));
+        assert_eq!((tle.first_derivative_of_mean_motion, tle.second_derivative_of_mean_motion, tle.bstar), (-0.00004364, -6.0e-5, -1.1606e-5));
+        assert_eq!((tle.ephemeris_type, tle.element_set_number, tle.revolution_number_at_epoch), (0, 292, 56353));
+        assert_eq!((tle.inclination, tle.right_ascension_of_ascending_node, tle.eccentricity), (51.6416, 247.4627, 0.0006703));
+        assert_eq!((tle.argument_of_perigee, tle.mean_anomaly, tle.mean_motion), (130.536, 325.0288, 15.72125391));
+
+        // A layout with the inclination shifted one column right parses with a shifted spec
+        let shifted_line2 = "2 25544   51.6416247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let mut spec = FIELD_SPECS;
+        spec[10].start += 1;
+        spec[10].end += 1;
+        assert_eq!(parse_with_spec(entries[0].0, shifted_line2, &spec).unwrap(), tle);
+
+        // A subset of the fields leaves the rest zero
+        let tle = parse_with_spec(entries[0].0, entries[0].1, &FIELD_SPECS[..1]).unwrap();
+        assert_eq!((tle.satellite_catalog_number, tle.mean_motion), (25544, 0.));
+
+        // Unknown fields, lines and mismatched kinds are rejected
+        let unknown = [FieldSpec { name: "apogee", ..FIELD_SPECS[0] }];
+        assert_eq!(parse_with_spec(entries[0].0, entries[0].1, &unknown), Err(TleError::InvalidFieldSpec { field: "apogee" }));
+        let line3 = [FieldSpec { line: 3, ..FIELD_SPECS[0] }];
+        assert_eq!(parse_with_spec(entries[0].0, entries[0].1, &line3), Err(TleError::InvalidFieldSpec { field: "satellite_catalog_number" }));
+        let mismatched = [FieldSpec { kind: FieldKind::Text, ..FIELD_SPECS[0] }];
+        assert_eq!(parse_with_spec(entries[0].0, entries[0].1, &mismatched), Err(TleError::InvalidFieldSpec { field: "satellite_catalog_number" }));
+
+        // Exponential fields that are not 8 columns wide, or whose multiplier overflows, are rejected before parsing
+        let narrow = [FieldSpec { end: FIELD_SPECS[7].start + 5, ..FIELD_SPECS[7] }];
+        assert_eq!(parse_with_spec(entries[0].0, entries[0].1, &narrow), Err(TleError::InvalidFieldSpec { field: "bstar" }));
+        let wide = [FieldSpec { end: FIELD_SPECS[7].end + 1, ..FIELD_SPECS[7] }];
+        assert_eq!(parse_with_spec(entries[0].0, entries[0].1, &wide), Err(TleError::InvalidFieldSpec { field: "bstar" }));
+        let reversed = [FieldSpec { start: FIELD_SPECS[7].end, end: FIELD_SPECS[7].start, ..FIELD_SPECS[7] }];
+        assert_eq!(parse_with_spec(entries[0].0, entries[0].1, &reversed), Err(TleError::InvalidFieldSpec { field: "bstar" }));
+        let overflow = [FieldSpec { kind: FieldKind::Exponential { multiplier: i64::MIN }, ..FIELD_SPECS[7] }];
+        assert_eq!(parse_with_spec(entries[0].0, entries[0].1, &overflow), Err(TleError::InvalidFieldSpec { field: "bstar" }));
+        let invalid_later = [FIELD_SPECS[0], narrow[0]];
+        assert_eq!(parse_with_spec(entries[0].0, entries[0].1, &invalid_later), Err(TleError::InvalidFieldSpec { field: "bstar" }));
+
+        // Integers that do not fit their field are out of range, and missing columns fail to parse
+        let wide = [FieldSpec { name: "element_set_number", line: 2, start: 52, end: 63, kind: FieldKind::Integer }];
+        assert!(matches!(parse_with_spec(entries[0].0, "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15725391563537", &wide), Err(TleError::OutOfRange { field: "element_set_number", .. })));
+        let beyond = [FieldSpec { start: 70, end: 72, ..FIELD_SPECS[16] }];
+        assert!(matches!(parse_with_spec(entries[0].0, entries[0].1, &beyond), Err(TleError::FieldParse { field: "revolution_number_at_epoch", .. })));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_parsing_from_string() {
+        // Define the TLE string
+        let tle_string = "ISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+        // Parse the TLE string into a TLE struct
+        let tles = from_string(tle_string).unwrap();
+        let tle = &tles[0];
+
+        // Assert the TLE struct is correct
+        assert_eq!(tle.common_name, "ISS (ZARYA)");
+        assert_eq!(tle.satellite_catalog_number, 25544);
+        assert_eq!(tle.classification, 'U');
+        assert_eq!(tle.international_designator, "98067A");
+        assert_eq!(tle.epoch_year, 2008);
+        assert_eq!(tle.epoch_day, 264.51782528);
+        assert_eq!(tle.first_derivative_of_mean_motion, -0.00004364);
+        assert_eq!(tle.second_derivative_of_mean_motion, -6.0e-5);
+        assert_eq!(tle.bstar, -0.000011606);
+        assert_eq!(tle.ephemeris_type, 0);
+        assert_eq!(tle.element_set_number, 292);
+        assert_eq!(tle.inclination, 51.6416);
+        assert_eq!(tle.right_ascension_of_ascending_node, 247.4627);
+        assert_eq!(tle.eccentricity, 0.0006703);
+        assert_eq!(tle.argument_of_perigee, 130.536);
+        assert_eq!(tle.mean_anomaly, 325.0288);
+        assert_eq!(tle.mean_motion, 15.72125391);
+        assert_eq!(tle.revolution_number_at_epoch, 56353);
+    }
+
+    #[test]
+    fn test_tle_parsing_errors() {
+        // Define the TLE lines
+        let tle_line0 = "ISS (ZARYA)";
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+        // Truncated line 2
+        let result = from_lines(tle_line1, &tle_line2[..60], Some(tle_line0));
+        assert_eq!(result.err(), Some(TleError::WrongLineLength { line: 2, length: 60 }));
+
+        // Corrupted checksum on line 1
+        let tle_line1_bad = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2922";
+        let result = from_lines(tle_line1_bad, tle_line2, Some(tle_line0));
+        assert_eq!(result.err(), Some(TleError::ChecksumFailed { line: 1 }));
+
+        // Bad character in the inclination field (with a valid checksum)
+        let tle_line2_bad = "2 25544  51.6x16 247.4627 0006703 130.5360 325.0288 15.72125391563533";
+        let result = from_lines(tle_line1, tle_line2_bad, Some(tle_line0));
+        assert_eq!(result.err(), Some(TleError::FieldParse { field: "inclination", columns: Some((9, 16)), raw: to_tle_string(" 51.6x16") }));
+
+        // Tab in place of the spaces before the epoch of line 1
+        let tle_line1_tab = "1 25544U 98067A\t08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let result = from_lines(tle_line1_tab, tle_line2, Some(tle_line0));
+        assert_eq!(result.err(), Some(TleError::NonAsciiOrTab { line: 1 }));
+
+        // Non-ASCII character straddling the end of the international designator (69 bytes long)
+        let tle_line1_utf8 = "1 25544U 98067A \u{e9}08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        assert_eq!(tle_line1_utf8.len(), 69);
+        let result = from_lines(tle_line1_utf8, tle_line2, Some(tle_line0));
+        assert_eq!(result.err(), Some(TleError::NonAsciiOrTab { line: 1 }));
+
+        // Non-ASCII character in line 2
+        let tle_line2_utf8 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563\u{b0}7";
+        let result = from_lines(tle_line1, tle_line2_utf8, Some(tle_line0));
+        assert_eq!(result.err(), Some(TleError::NonAsciiOrTab { line: 2 }));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_parsing_fuzz() {
+        // A xorshift generator, so the test is repeatable without a dependency
+        let mut state: u64 = 0x9e3779b97f4a7c15;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            return state;
+        };
+
+        // Characters of valid TLEs mixed with tabs, control and multi-byte characters
+        let alphabet: Vec<char> = "0123456789 .-+UE\t\r\n\u{0}\u{e9}\u{b0}\u{20ac}\u{1f6f0}".chars().collect();
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+        for _ in 0..2000 {
+            // Random strings around the length of a data line
+            let (length1, length2) = (62 + next() as usize % 10, 62 + next() as usize % 10);
+            let mut random_line = |length: usize| -> String {
+                return (0..length).map(|_| alphabet[next() as usize % alphabet.len()]).collect();
+            };
+            let line0 = random_line(30);
+            let line1 = random_line(length1);
+            let line2 = random_line(length2);
+
+            // Valid lines with one character replaced at a random position
+            let position = next() as usize % 69;
+            let replacement = alphabet[next() as usize % alphabet.len()];
+            let mutated1: String = tle_line1.chars().enumerate().map(|(i, c)| if i == position { replacement } else { c }).collect();
+            let mutated2: String = tle_line2.chars().enumerate().map(|(i, c)| if i == position { replacement } else { c }).collect();
+
+            // Parsing may fail, but must not panic
+            let _ = from_lines(&line1, &line2, Some(&line0));
+            let _ = from_lines(&mutated1, tle_line2, Some(&line0));
+            let _ = from_lines(tle_line1, &mutated2, None);
+            let _ = from_lines(&with_corrected_checksums(&mutated1, tle_line2).0, tle_line2, None);
+            let _ = from_string(&format!("{}\n{}\n{}", line0, mutated1, mutated2));
+            let _ = tle_checksum(&line1);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_parsing_from_string_errors() {
+        // Line 1 without a line 2
+        let tle_string = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        assert_eq!(from_string(tle_string).err(), Some(TleError::InvalidLineCount));
+
+        // Bad character in the mean motion field (with a valid checksum)
+        let tle_string = "ISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72x25391563536";
+        let error = from_string(tle_string).err().unwrap();
+        assert_eq!(error, TleError::FieldParse { field: "mean_motion", columns: Some((53, 63)), raw: to_tle_string("15.72x25391") });
+        assert_eq!(error.to_string(), "failed to parse 'mean_motion' at columns 53\u{2013}63: '15.72x25391'");
+
+        // The lossy parser skips the bad entry and keeps the good one
+        let tle_string = "1 25544U 98067A\nISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tles = from_string_lossy(tle_string);
+        assert_eq!(tles.len(), 1);
+        assert_eq!(tles[0].common_name, "ISS (ZARYA)");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_to_lines() {
+        // Define TLE lines with normalized exponential fields
+        let starlink_line1 = "1 66925U 25286A   25348.25003472 -.01252121  46857-3 -33539-2 0  9990";
+        let starlink_line2 = "2 66925  53.1578  43.0382 0000844  85.9465  63.2898 15.93822682  2231";
+        let hulianwang_line1 = "1 66957U 25287E   25346.69967332 -.00000151  00000-0 -19373-3 0  9997";
+        let hulianwang_line2 = "2 66957  86.4945 346.1700 0007219 190.5502 169.5507 13.69137019   523";
+
+        // Assert the round trip produces identical lines, for the ISS sample too
+        let starlink_tle = from_lines(starlink_line1, starlink_line2, None).unwrap();
+        assert_eq!(to_lines(&starlink_tle).unwrap(), (starlink_line1.to_string(), starlink_line2.to_string()));
+
+        let hulianwang_tle = from_lines(hulianwang_line1, hulianwang_line2, None).unwrap();
+        assert_eq!(to_lines(&hulianwang_tle).unwrap(), (hulianwang_line1.to_string(), hulianwang_line2.to_string()));
+
+        let tle_line0 = "ISS (ZARYA)";
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let iss_tle = from_lines(tle_line1, tle_line2, Some(tle_line0)).unwrap();
+        let (line1, line2) = to_lines(&iss_tle).unwrap();
+        assert_eq!(line1, tle_line1);
+        assert_eq!(line2, tle_line2);
+
+        // A zero written with a positive exponent is written with the conventional negative exponent
+        let zero_plus_line1 = "1 66957U 25287E   25346.69967332 -.00000151  00000+0 -19373-3 0  9996";
+        let zero_plus_tle = from_lines(zero_plus_line1, hulianwang_line2, None).unwrap();
+        assert_eq!(to_lines(&zero_plus_tle).unwrap().0, hulianwang_line1);
+
+        // An unnormalized 2nd derivative of mean motion (-00100-2) is written normalized (-10000-4)
+        let unnormalized_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let unnormalized_tle = from_lines(unnormalized_line1, tle_line2, Some(tle_line0)).unwrap();
+        let (unnormalized_round_trip, _line2) = to_lines(&unnormalized_tle).unwrap();
+        assert_eq!(unnormalized_round_trip, "1 25544U 98067A   08264.51782528 -.00002182 -10000-4 -11606-4 0  2923");
+
+        // Assert the written lines parse back to the same values
+        let round_trip = from_lines(&unnormalized_round_trip, tle_line2, Some(tle_line0)).unwrap();
+        assert!((round_trip.second_derivative_of_mean_motion - unnormalized_tle.second_derivative_of_mean_motion).abs() < 1e-15);
+        assert_eq!(round_trip.bstar, unnormalized_tle.bstar);
+
+        // Assert the Display implementation includes the name line
+        assert_eq!(iss_tle.to_string(), format!("{}\n{}\n{}", tle_line0, line1, line2));
+        assert_eq!(starlink_tle.to_string(), format!("{}\n{}", starlink_line1, starlink_line2));
+
+        // Assert the prefixed output carries the line number on the name line only, and parses back
+        let (prefixed_line0, prefixed_line1, prefixed_line2) = iss_tle.to_lines_with_name_prefix().unwrap().unwrap();
+        assert_eq!(prefixed_line0, "0 ISS (ZARYA)");
+        assert_eq!((prefixed_line1.clone(), prefixed_line2.clone()), (line1.clone(), line2.clone()));
+        assert_eq!(from_lines(&prefixed_line1, &prefixed_line2, Some(&prefixed_line0)).unwrap().common_name, tle_line0);
+
+        // Assert the prefixed output is skipped without a name
+        assert_eq!(starlink_tle.to_lines_with_name_prefix(), Ok(None));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_to_lines_exponential_limits() {
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+
+        // Values below 1e-10 do not fit a single digit exponent and are written as zero, keeping 69 columns
+        let tiny = Tle { bstar: 1.2e-11, second_derivative_of_mean_motion: -6e-12, ..tle.clone() };
+        let (line1, line2) = to_lines(&tiny).unwrap();
+        assert_eq!(line1.len(), 69);
+        assert_eq!(&line1[44..61], " 00000-0  00000-0");
+        let parsed = from_lines(&line1, &line2, None).unwrap();
+        assert_eq!((parsed.bstar, parsed.second_derivative_of_mean_motion), (0., 0.));
+
+        // Values that round up to 1e-10 still fit
+        assert_eq!(format_exponential_field(9.999996e-11), " 10000-9");
+        assert_eq!(format_exponential_field(1e-10), " 10000-9");
+
+        // Values of 1e9 and above saturate at the largest representable value
+        assert_eq!(format_exponential_field(-5e12), "-99999+9");
+        assert_eq!(format_exponential_field(f64::MAX), " 99999+9");
+        assert_eq!(format_exponential_field(9.999996e8), " 99999+9");
+        assert_eq!(format_exponential_field(f64::MIN_POSITIVE), " 00000-0");
+        let (line1, _line2) = to_lines(&Tle { bstar: 5e12, ..tle.clone() }).unwrap();
+        assert_eq!(line1.len(), 69);
+
+        // Values that are not finite are rejected
+        assert_eq!(to_lines(&Tle { bstar: f64::INFINITY, ..tle.clone() }), Err(TleError::OutOfRange { field: "bstar", value: f64::INFINITY }));
+        assert!(matches!(to_lines(&Tle { second_derivative_of_mean_motion: f64::NAN, ..tle.clone() }), Err(TleError::OutOfRange { field: "second_derivative_of_mean_motion", .. })));
+        assert!(matches!(to_lines(&Tle { mean_motion: f64::NAN, ..tle.clone() }), Err(TleError::OutOfRange { field: "mean_motion", .. })));
+        assert!(Tle { common_name: "ISS (ZARYA)".to_string(), bstar: f64::NAN, ..tle.clone() }.to_lines_with_name_prefix().is_err());
+    }
+
+    #[test]
+    fn test_tle_parsing_from_bytes() {
+        let tle_line0 = "ISS (ZARYA)";
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
 
-        // Calculate the checksum of the TLE line
-        let checksum = tle_checksum(tle_line1);
-        let checksum2 = tle_checksum(tle_line2);
+        // The 3-line and 2-line formats parse the same as from lines
+        let data = b"ISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537\n";
+        assert_eq!(from_bytes(data), from_lines(tle_line1, tle_line2, Some(tle_line0)));
+        assert_eq!(from_bytes(&data[12..]), from_lines(tle_line1, tle_line2, None));
 
-        // Assert the checksum is correct
-        assert_eq!(checksum, true);
-        assert_eq!(checksum2, false);
+        // The bytes parse the same as the string
+        #[cfg(feature = "std")]
+        assert_eq!(from_bytes(data).unwrap(), from_string(core::str::from_utf8(data).unwrap()).unwrap()[0]);
+
+        // A stray byte is reported on its line
+        let mut corrupted = *data;
+        corrupted[3] = 0xff;
+        assert_eq!(from_bytes(&corrupted), Err(TleError::NonAsciiOrTab { line: 0 }));
+        corrupted[60] = 0xe9;
+        assert_eq!(from_bytes(&corrupted[12..]), Err(TleError::NonAsciiOrTab { line: 1 }));
+
+        // Entries with too few or too many lines
+        assert_eq!(from_bytes(&data[12..82]), Err(TleError::InvalidLineCount));
+        assert_eq!(from_bytes(b""), Err(TleError::InvalidLineCount));
+        assert_eq!(from_bytes(b"A\nB\nC\nD"), Err(TleError::InvalidLineCount));
     }
 
     #[test]
-    fn test_tle_parsing_from_lines() {
-        // Define the TLE lines
+    #[cfg(not(feature = "std"))]
+    fn test_tle_parsing_no_std() {
+        // Parse the ISS TLE into fixed-capacity strings
         let tle_line0 = "ISS (ZARYA)";
-        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
         let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, Some(tle_line0)).unwrap();
+        assert_eq!(tle.common_name.as_str(), tle_line0);
+        assert_eq!(tle.international_designator.as_str(), "98067A");
+        assert_eq!(tle.eccentricity, 0.0006703);
 
-        // Parse the TLE lines into a TLE struct
-        let tle = from_lines(tle_line1, tle_line2, Some(tle_line0));
+        // Text longer than the string capacity is truncated
+        let name = TleBuilder::new().with_common_name("A NAME THAT DOES NOT FIT IN 24 CHARACTERS").build().unwrap().common_name;
+        assert_eq!(name.as_str(), "A NAME THAT DOES NOT FIT");
+    }
 
-        // Assert the TLE struct is correct
-        assert_eq!(tle.common_name, "ISS (ZARYA)");
-        assert_eq!(tle.satellite_catalog_number, 25544);
-        assert_eq!(tle.classification, 'U');
-        assert_eq!(tle.international_designator, "98067A");
-        assert_eq!(tle.epoch_year, 2008);
-        assert_eq!(tle.epoch_day, 264.51782528);
-        assert_eq!(tle.first_derivative_of_mean_motion, -0.00004364);
-        assert!((tle.second_derivative_of_mean_motion + 6.0e-5).abs() < 1e-12);
-        assert_eq!(tle.bstar, -0.000011606);
-        assert_eq!(tle.ephemeris_type, 0);
-        assert_eq!(tle.element_set_number, 292);
-        assert_eq!(tle.inclination, 51.6416);
-        assert_eq!(tle.right_ascension_of_ascending_node, 247.4627);
-        assert_eq!(tle.eccentricity, 0.0006703);
-        assert_eq!(tle.argument_of_perigee, 130.536);
-        assert_eq!(tle.mean_anomaly, 325.0288);
-        assert_eq!(tle.mean_motion, 15.72125391);
-        assert_eq!(tle.revolution_number_at_epoch, 56353);
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_debug_clone() {
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, Some("ISS (ZARYA)")).unwrap();
+
+        // Assert a clone compares equal and diverges once modified
+        let mut tle_copy = tle.clone();
+        assert_eq!(tle_copy, tle);
+        tle_copy.mean_motion = tle_copy.mean_motion + 1e-8;
+        assert_ne!(tle_copy, tle);
+
+        // Assert the Debug output names the struct and its fields
+        let debug = format!("{:?}", tle);
+        assert!(debug.starts_with("Tle {"));
+        assert!(debug.contains("satellite_catalog_number: 25544"));
+        assert!(debug.contains("common_name: \"ISS (ZARYA)\""));
     }
 
     #[test]
-    fn test_tle_parsing_from_string() {
-        // Define the TLE string
-        let tle_string = "ISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+    #[cfg(feature = "std")]
+    fn test_tle_builder() {
+        let tle_line0 = "ISS (ZARYA)";
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, Some(tle_line0)).unwrap();
 
-        // Parse the TLE string into a TLE struct
-        let tles = from_string(tle_string);
-        let tle = &tles[0];
+        // An unmodified builder returns the original TLE
+        assert_eq!(TleBuilder::from_tle(&tle).build().unwrap(), tle);
 
-        // Assert the TLE struct is correct
-        assert_eq!(tle.common_name, "ISS (ZARYA)");
-        assert_eq!(tle.satellite_catalog_number, 25544);
-        assert_eq!(tle.classification, 'U');
-        assert_eq!(tle.international_designator, "98067A");
-        assert_eq!(tle.epoch_year, 2008);
-        assert_eq!(tle.epoch_day, 264.51782528);
-        assert_eq!(tle.first_derivative_of_mean_motion, -0.00004364);
-        assert!((tle.second_derivative_of_mean_motion + 6.0e-5).abs() < 1e-12);
-        assert_eq!(tle.bstar, -0.000011606);
+        // Build a modified ISS TLE and assert it re-parses with valid checksums
+        let modified = TleBuilder::from_tle(&tle)
+            .with_inclination(51.6)
+            .with_eccentricity(0.0012345)
+            .with_mean_motion(15.5)
+            .with_element_set_number(999)
+            .build()
+            .unwrap();
+        let (line1, line2) = to_lines(&modified).unwrap();
+        let reparsed = from_lines(&line1, &line2, Some(tle_line0)).unwrap();
+        assert_eq!(reparsed.inclination, 51.6);
+        assert_eq!(reparsed.eccentricity, 0.0012345);
+        assert_eq!(reparsed.mean_motion, 15.5);
+        assert_eq!(reparsed.element_set_number, 999);
+        assert_eq!(reparsed.right_ascension_of_ascending_node, tle.right_ascension_of_ascending_node);
+        assert_eq!(reparsed.common_name, tle.common_name);
+
+        // Build a TLE from scratch
+        let scratch = TleBuilder::new()
+            .with_satellite_catalog_number(99999)
+            .with_international_designator("24001A")
+            .with_epoch(2024, 100.5)
+            .with_inclination(97.5)
+            .with_mean_motion(14.9)
+            .build()
+            .unwrap();
+        let (line1, line2) = to_lines(&scratch).unwrap();
+        let reparsed = from_lines(&line1, &line2, None).unwrap();
+        assert_eq!(reparsed.satellite_catalog_number, 99999);
+        assert_eq!(reparsed.classification, 'U');
+        assert_eq!((reparsed.epoch_year, reparsed.epoch_day), (2024, 100.5));
+
+        // Out of range elements are rejected
+        assert_eq!(TleBuilder::from_tle(&tle).with_eccentricity(1.0).build(), Err(TleError::OutOfRange { field: "eccentricity", value: 1.0 }));
+        assert_eq!(TleBuilder::from_tle(&tle).with_eccentricity(-0.1).build(), Err(TleError::OutOfRange { field: "eccentricity", value: -0.1 }));
+        assert_eq!(TleBuilder::from_tle(&tle).with_inclination(180.5).build(), Err(TleError::OutOfRange { field: "inclination", value: 180.5 }));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_from_elements() {
+        // Define the elements of a Molniya orbit
+        let epoch = DateTime { year: 2006, month: 6, day: 25, hour: 7, minute: 58, second: 18.143616, timezone: Timezone::UTC };
+        let tle = from_elements(&epoch, 64.1586, 279.0717, 0.6877146, 264.7651, 20.2257, 2.00491383, 8195).unwrap();
+
+        // Write the TLE and parse it back
+        let (line1, line2) = to_lines(&tle).unwrap();
+        let parsed = from_lines(&line1, &line2, None).unwrap();
+
+        // The elements survive the round trip
+        assert_eq!(parsed.satellite_catalog_number, 8195);
+        assert_eq!(parsed.epoch_year, 2006);
+        assert!((parsed.epoch_day - 176.33215444).abs() < 1e-8, "Epoch day: expected 176.33215444, got {}", parsed.epoch_day);
+        assert_eq!(parsed.inclination, 64.1586);
+        assert_eq!(parsed.right_ascension_of_ascending_node, 279.0717);
+        assert_eq!(parsed.eccentricity, 0.6877146);
+        assert_eq!(parsed.argument_of_perigee, 264.7651);
+        assert_eq!(parsed.mean_anomaly, 20.2257);
+        assert_eq!(parsed.mean_motion, 2.00491383);
+
+        // Invalid elements and epochs are rejected
+        assert_eq!(from_elements(&epoch, 64.1586, 279.0717, 1.5, 264.7651, 20.2257, 2.00491383, 8195), Err(TleError::OutOfRange { field: "eccentricity", value: 1.5 }));
+        let epoch_ut1 = DateTime { timezone: Timezone::UT1, ..epoch };
+        assert_eq!(from_elements(&epoch_ut1, 64.1586, 279.0717, 0.6877146, 264.7651, 20.2257, 2.00491383, 8195), Err(TleError::OutOfRange { field: "epoch", value: 2006. }));
+    }
+
+    #[test]
+    fn test_tle_orbit_characteristics() {
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+
+        // The ISS has a period of about 92 minutes and a semi-major axis of about 6730 km
+        assert!((tle.period_minutes() - 91.5957).abs() < 1e-4);
+        assert!((tle.mean_motion_rad_per_min() - 0.06859691081788).abs() < 1e-14);
+        assert!((tle.mean_motion_rad_per_sec() - 0.00114328184696).abs() < 1e-14);
+        let a = tle.semi_major_axis(&WGS72);
+        assert!((a - 6730.9627).abs() < 1e-3);
+
+        // The near circular orbit has apogee and perigee around 350 km, separated by 2ae
+        let apogee = tle.apogee_altitude(&WGS72);
+        let perigee = tle.perigee_altitude(&WGS72);
+        assert!((apogee - 357.339).abs() < 1e-3);
+        assert!((perigee - 348.316).abs() < 1e-3);
+        assert!((apogee - perigee - 2. * a * tle.eccentricity).abs() < 1e-9);
+
+        // The J2 secular drift of the node and perigee
+        assert!((tle.nodal_regression(&WGS72) + 5.121364).abs() < 1e-6);
+        assert!((tle.apsidal_rotation(&WGS72) - 3.819241).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_tle_orbit_regime() {
+        // The ISS is in low Earth orbit
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(tle.orbit_regime(&WGS72), OrbitRegime::Leo);
+
+        // A GPS satellite orbits twice a day at about 20200 km
+        let tle_line1 = "1 24876U 97035A   08200.55007882  .00000006  00000-0  10000-3 0  3394";
+        let tle_line2 = "2 24876  55.5261 111.8155 0038237 331.0543  28.7634  2.00561694 81289";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(tle.orbit_regime(&WGS72), OrbitRegime::Meo);
+
+        // A geostationary satellite, a Molniya orbit and a geostationary transfer orbit
+        let geo = Tle { inclination: 0.05, eccentricity: 0.0002, mean_motion: 1.0027, ..Default::default() };
+        assert_eq!(geo.orbit_regime(&WGS72), OrbitRegime::Geo);
+        let molniya = Tle { inclination: 63.4, eccentricity: 0.74, mean_motion: 2.006, ..Default::default() };
+        assert_eq!(molniya.orbit_regime(&WGS72), OrbitRegime::Heo);
+        let gto = Tle { inclination: 6., eccentricity: 0.73, mean_motion: 2.25, ..Default::default() };
+        assert_eq!(gto.orbit_regime(&WGS72), OrbitRegime::Heo);
+
+        // An eccentric geosynchronous orbit, a graveyard orbit and an orbit crossing 2000 km are none of the above
+        assert_eq!(Tle { eccentricity: 0.1, ..geo.clone() }.orbit_regime(&WGS72), OrbitRegime::Other);
+        assert_eq!(Tle { mean_motion: 0.9, ..geo.clone() }.orbit_regime(&WGS72), OrbitRegime::Other);
+        let crossing = Tle { inclination: 30., eccentricity: 0.1, mean_motion: 12., ..Default::default() };
+        assert_eq!(crossing.orbit_regime(&WGS72), OrbitRegime::Other);
+    }
+
+    #[test]
+    fn test_tle_secular_drift_sun_synchronous() {
+        // A sun-synchronous orbit at about 790 km (Sentinel-2A)
+        let tle = Tle { inclination: 98.5683, eccentricity: 0.0001, mean_motion: 14.30818, ..Default::default() };
+
+        // The node follows the mean motion of the Sun
+        let raan_rate = tle.nodal_regression(&WGS72);
+        assert!((raan_rate - 0.9856).abs() < 2e-3, "Nodal regression test failed: expected 0.9856, got {}", raan_rate);
+        assert!(tle.apsidal_rotation(&WGS72) < 0.);
+
+        // The perigee is frozen at the critical inclination
+        let tle = Tle { inclination: 63.4349, ..tle };
+        assert!(tle.apsidal_rotation(&WGS72).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_tle_ephemeris_kind() {
+        // Define the ISS TLE with ephemeris type 0
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let mut tle = from_lines(tle_line1, tle_line2, None).unwrap();
         assert_eq!(tle.ephemeris_type, 0);
-        assert_eq!(tle.element_set_number, 292);
-        assert_eq!(tle.inclination, 51.6416);
-        assert_eq!(tle.right_ascension_of_ascending_node, 247.4627);
-        assert_eq!(tle.eccentricity, 0.0006703);
-        assert_eq!(tle.argument_of_perigee, 130.536);
-        assert_eq!(tle.mean_anomaly, 325.0288);
-        assert_eq!(tle.mean_motion, 15.72125391);
-        assert_eq!(tle.revolution_number_at_epoch, 56353);
+        assert_eq!(tle.ephemeris_kind(), EphemerisType::Sgp4);
+
+        // Type 2 is also SGP4
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 2  2929";
+        let tle_type2 = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(tle_type2.ephemeris_type, 2);
+        assert_eq!(tle_type2.ephemeris_kind(), EphemerisType::Sgp4);
+
+        // The other models
+        assert!(tle.has_sgp4_ephemeris() && tle_type2.has_sgp4_ephemeris());
+        for (value, kind) in [(3, EphemerisType::Sdp4), (4, EphemerisType::Sgp8), (5, EphemerisType::Sdp8), (7, EphemerisType::Unknown(7))] {
+            tle.ephemeris_type = value;
+            assert_eq!(tle.ephemeris_kind(), kind);
+            assert_eq!(tle.has_sgp4_ephemeris(), kind == EphemerisType::Sdp4);
+        }
+    }
+
+    #[test]
+    fn test_tle_classification_kind() {
+        // Define the ISS TLE, classified U
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let mut tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(tle.classification, 'U');
+        assert_eq!(tle.classification_kind(), Classification::Unclassified);
+
+        // An unexpected classification parses and keeps the raw character
+        let tle_line1 = "1 25544X 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_x = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(tle_x.classification, 'X');
+        assert_eq!(tle_x.classification_kind(), Classification::Other('X'));
+
+        // The other classifications
+        for (raw, kind) in [('C', Classification::Classified), ('S', Classification::Secret)] {
+            tle.classification = raw;
+            assert_eq!(tle.classification_kind(), kind);
+        }
+    }
+
+    #[test]
+    fn test_tle_designator_parts() {
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(tle.designator_parts(), Some((1998, 67, to_tle_string("A"))));
+
+        // Launches from 2000 onwards and multi-letter pieces
+        let tle = Tle { international_designator: to_tle_string("98067AB"), ..tle };
+        assert_eq!(tle.designator_parts(), Some((1998, 67, to_tle_string("AB"))));
+        let tle = Tle { international_designator: to_tle_string("24001ABC"), ..tle };
+        assert_eq!(tle.designator_parts(), Some((2024, 1, to_tle_string("ABC"))));
+
+        // A blank piece is returned empty
+        for designator in ["98067", "98067   "] {
+            let tle = Tle { international_designator: to_tle_string(designator), ..tle.clone() };
+            assert_eq!(tle.designator_parts(), Some((1998, 67, to_tle_string(""))), "Designator {:?} should parse", designator);
+        }
+
+        // Blank, short and malformed designators
+        for designator in ["", "9806", "9806A", "98067A1", "98067A B", "98067ABCD"] {
+            let tle = Tle { international_designator: to_tle_string(designator), ..tle.clone() };
+            assert_eq!(tle.designator_parts(), None, "Designator {:?} should not parse", designator);
+        }
+    }
+
+    #[test]
+    fn test_tle_approx_launch_era() {
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+
+        // Vanguard 1, Hubble, the ISS, Envisat and the last object before the 2020 milestone
+        for (catalog_number, era) in [(5, (1957, 1965)), (20580, (1990, 1995)), (25544, (1995, 2000)), (27386, (2000, 2005)), (44899, (2015, 2020))] {
+            let tle = Tle { satellite_catalog_number: catalog_number, ..tle.clone() };
+            assert_eq!(tle.approx_launch_era(), Some(era), "Catalog number {}", catalog_number);
+        }
+
+        // The eras are contiguous at the milestones
+        assert_eq!(Tle { satellite_catalog_number: 26049, ..tle.clone() }.approx_launch_era(), Some((1995, 2000)));
+        assert_eq!(Tle { satellite_catalog_number: 26050, ..tle.clone() }.approx_launch_era(), Some((2000, 2005)));
+
+        // Unassigned catalog numbers, an early Starlink past the last milestone, analyst and Alpha-5 catalog numbers
+        for catalog_number in [0, 44900, 44914, 79999, 80000, 89999, 148493] {
+            let tle = Tle { satellite_catalog_number: catalog_number, ..tle.clone() };
+            assert_eq!(tle.approx_launch_era(), None, "Catalog number {}", catalog_number);
+        }
+    }
+
+    #[test]
+    fn test_tle_epoch_fraction_seconds() {
+        // Day 264.51782528 is 44740.104192 seconds past midnight
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert!((tle.epoch_fraction_seconds() - 44740.104192).abs() < 1e-6, "Epoch seconds: expected 44740.104192, got {}", tle.epoch_fraction_seconds());
+
+        // The last of the 8 decimal digits is preserved (1e-8 days = 864 us)
+        let tle_line1 = "1 25544U 98067A   08366.99999999 -.00002182  00000-0 -11606-4 0  2924";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(tle.epoch_day, 366.99999999);
+        assert!((tle.epoch_fraction_seconds() - 86399.999136).abs() < 1e-6, "Epoch seconds: expected 86399.999136, got {}", tle.epoch_fraction_seconds());
+
+        // Midnight is zero
+        let tle = Tle { epoch_day: 1.0, ..tle };
+        assert_eq!(tle.epoch_fraction_seconds(), 0.);
+    }
+
+    #[test]
+    fn test_tle_true_anomaly() {
+        // Eccentric near-Earth orbit
+        let tle_line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let tle_line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        let true_anom = tle.true_anomaly().unwrap();
+        assert!((true_anom - 28.29413759895786).abs() < 1e-9, "True anomaly: expected 28.294137599, got {}", true_anom);
+
+        // Near circular orbit just before perigee
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        let true_anom = tle.true_anomaly().unwrap();
+        assert!((true_anom - 324.984744569446).abs() < 1e-9, "True anomaly: expected 324.984744569, got {}", true_anom);
+
+        // An unbound orbit has no true anomaly from Kepler's equation
+        let tle = Tle { eccentricity: 1.2, ..tle };
+        assert!(matches!(tle.true_anomaly(), Err(PropagationError::EccentricityOutOfRange { .. })));
+    }
+
+    #[test]
+    fn test_tle_angles_rad() {
+        let tle_line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let tle_line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(tle.inclination_rad(), Rad(34.2682_f64.to_radians()));
+        assert_eq!(tle.right_ascension_of_ascending_node_rad(), Rad(348.7242_f64.to_radians()));
+        assert_eq!(tle.argument_of_perigee_rad(), Rad(331.7664_f64.to_radians()));
+        assert_eq!(tle.mean_anomaly_rad(), Rad(19.3264_f64.to_radians()));
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_tle_parsing_from_file() {
         // Define the TLE file path
         let tle_file_path = "assets/test.tle";
@@ -553,7 +3860,7 @@ mod tests {
         assert_eq!(iss_tle.epoch_year, 2008);
         assert_eq!(iss_tle.epoch_day, 264.51782528);
         assert_eq!(iss_tle.first_derivative_of_mean_motion, -0.00004364);
-        assert!((iss_tle.second_derivative_of_mean_motion + 6.0e-5).abs() < 1e-12);
+        assert_eq!(iss_tle.second_derivative_of_mean_motion, -6.0e-5);
         assert_eq!(iss_tle.bstar, -0.000011606);
         assert_eq!(iss_tle.ephemeris_type, 0);
         assert_eq!(iss_tle.element_set_number, 292);
@@ -584,4 +3891,477 @@ mod tests {
         assert_eq!(hulianwang_tle.mean_motion, 13.69137019);
         assert_eq!(hulianwang_tle.revolution_number_at_epoch, 52);
     }
+
+    #[test]
+    fn test_tle_epoch_datetime() {
+        // ISS epoch, day 264.51782528 of 2008
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        let epoch = tle.epoch_datetime().unwrap();
+        assert_eq!((epoch.year, epoch.month, epoch.day, epoch.hour, epoch.minute), (2008, 9, 20, 12, 25));
+        assert!((epoch.second - 40.104192).abs() < 1e-3, "Epoch seconds test failed: expected 40.104192, got {}", epoch.second);
+
+        // Two-digit year 00 expands to the leap year 2000, day 179.78495062 = June 27th
+        let tle_line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let tle_line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        let epoch = tle.epoch_datetime().unwrap();
+        assert_eq!((epoch.year, epoch.month, epoch.day, epoch.hour, epoch.minute), (2000, 6, 27, 18, 50));
+        assert!((epoch.second - 19.733568).abs() < 1e-3, "Epoch seconds test failed: expected 19.733568, got {}", epoch.second);
+
+        // Day 60 of a leap year is February 29th
+        let tle = Tle { epoch_year: 2000, epoch_day: 60.75, ..Default::default() };
+        let epoch = tle.epoch_datetime().unwrap();
+        assert_eq!((epoch.year, epoch.month, epoch.day, epoch.hour, epoch.minute), (2000, 2, 29, 18, 0));
+
+        // Day 1.0 is January 1st at midnight
+        let tle = Tle { epoch_year: 2024, epoch_day: 1.0, ..Default::default() };
+        let epoch = tle.epoch_datetime().unwrap();
+        assert_eq!((epoch.year, epoch.month, epoch.day, epoch.hour, epoch.minute), (2024, 1, 1, 0, 0));
+        assert!(epoch.second.abs() < 1e-9);
+
+        // An epoch day outside the year is an error
+        let tle = Tle { epoch_year: 2023, epoch_day: 366.5, ..Default::default() };
+        assert_eq!(tle.epoch_datetime().err(), Some(DateError::InvalidDayOfYear));
+    }
+
+    #[test]
+    fn test_tle_epoch_julian_date() {
+        // ISS epoch, day 264.51782528 of 2008
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        let jd = tle.epoch_julian_date().unwrap();
+        let mjd = tle.epoch_mjd().unwrap();
+        assert!((jd - 2454730.0178).abs() < 1e-4, "Julian date test failed: expected 2454730.0178, got {}", jd);
+        assert!((mjd - 54729.5178).abs() < 1e-4, "MJD test failed: expected 54729.5178, got {}", mjd);
+        assert!((jd - mjd - 2400000.5).abs() < 1e-9);
+
+        // An epoch day outside the year is an error
+        let tle = Tle { epoch_year: 2023, epoch_day: 366.5, ..Default::default() };
+        assert_eq!(tle.epoch_julian_date(), Err(DateError::InvalidDayOfYear));
+        assert_eq!(tle.epoch_mjd(), Err(DateError::InvalidDayOfYear));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_tle_epoch_chrono() {
+        use chrono::{TimeZone, Utc};
+
+        // ISS epoch is 2008-09-20T12:25:40Z
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        let epoch = tle.epoch_chrono().unwrap();
+        let expected = Utc.with_ymd_and_hms(2008, 9, 20, 12, 25, 40).unwrap();
+        assert!((epoch - expected).num_milliseconds().abs() < 1000, "Epoch test failed: expected {}, got {}", expected, epoch);
+
+        // The chrono epoch agrees with the calendar datetime epoch
+        let datetime = tle.epoch_datetime().unwrap();
+        assert_eq!(DateTime::from(epoch).minute, datetime.minute);
+        assert!((DateTime::from(epoch).second - datetime.second).abs() < 1e-3);
+
+        // An epoch day outside the year is an error
+        let tle = Tle { epoch_year: 2023, epoch_day: 366.5, ..Default::default() };
+        assert_eq!(tle.epoch_chrono().err(), Some(DateError::InvalidDayOfYear));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_tle_age() {
+        use chrono::Duration;
+
+        // Define the ISS TLE
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+
+        // An epoch exactly one day before now is 24 hours old
+        let now = tle.epoch_chrono().unwrap() + Duration::days(1);
+        assert_eq!(tle.age(now).unwrap(), Duration::hours(24));
+        assert!(tle.is_stale(now, Duration::hours(23)));
+        assert!(!tle.is_stale(now, Duration::hours(24)));
+
+        // An epoch after now has a negative age and is fresh
+        let now = tle.epoch_chrono().unwrap() - Duration::hours(1);
+        assert_eq!(tle.age(now).unwrap(), Duration::hours(-1));
+        assert!(!tle.is_stale(now, Duration::zero()));
+
+        // An invalid epoch has no age and is always stale
+        let tle = Tle { epoch_year: 2023, epoch_day: 366.5, ..Default::default() };
+        assert_eq!(tle.age(now).err(), Some(DateError::InvalidDayOfYear));
+        assert!(tle.is_stale(now, Duration::days(365)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_parsing_alpha5() {
+        // A numeric catalog number
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(tle.satellite_catalog_number, 25544);
+
+        // An Alpha-5 catalog number, E = 14
+        let tle_line1 = "1 E8493U 24001A   24187.50000000  .00001000  00000-0  10000-3 0  9993";
+        let tle_line2 = "2 E8493  53.0000 120.0000 0001000  90.0000 270.0000 15.10000000  1004";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert_eq!(tle.satellite_catalog_number, 148493);
+
+        // Alpha-5 catalog numbers are written back out in the Alpha-5 format
+        let (line1, line2) = to_lines(&tle).unwrap();
+        assert_eq!(line1, tle_line1);
+        assert_eq!(line2, tle_line2);
+
+        // The alphabet skips I and O
+        assert_eq!(parse_catalog_number("J0000", 0, 5, "satellite_catalog_number"), Ok(180000));
+        assert_eq!(parse_catalog_number("P0001", 0, 5, "satellite_catalog_number"), Ok(230001));
+        assert_eq!(parse_catalog_number("Z9999", 0, 5, "satellite_catalog_number"), Ok(339999));
+        assert_eq!(format_catalog_number(180000), "J0000");
+        assert_eq!(format_catalog_number(339999), "Z9999");
+        assert_eq!(format_catalog_number(99999), "99999");
+
+        // I, O and malformed remainders are rejected
+        assert_eq!(
+            parse_catalog_number("I0000", 0, 5, "satellite_catalog_number"),
+            Err(TleError::FieldParse { field: "satellite_catalog_number", columns: Some((1, 5)), raw: to_tle_string("I0000") })
+        );
+        assert!(parse_catalog_number("O1234", 0, 5, "satellite_catalog_number").is_err());
+        assert!(parse_catalog_number("E84A3", 0, 5, "satellite_catalog_number").is_err());
+        assert!(parse_catalog_number("E 493", 0, 5, "satellite_catalog_number").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tle_serde_roundtrip() {
+        // Parse the ISS TLE
+        let tle_line0 = "ISS (ZARYA)";
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, Some(tle_line0)).unwrap();
+
+        // Serialize to JSON, with the classification as a one-character string
+        let json = serde_json::to_string(&tle).unwrap();
+        assert!(json.contains("\"classification\":\"U\""), "Unexpected JSON: {}", json);
+
+        // Deserialize back to an identical TLE
+        let tle_back: Tle = serde_json::from_str(&json).unwrap();
+        assert!(tle_back == tle);
+        assert_eq!(tle_back.bstar.to_bits(), tle.bstar.to_bits());
+        assert_eq!(tle_back.epoch_day.to_bits(), tle.epoch_day.to_bits());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tle_parsing_from_json_array() {
+        // A named ISS entry and an unnamed entry
+        let json = r#"[
+            {"name": "ISS (ZARYA)",
+             "line1": "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921",
+             "line2": "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537"},
+            {"line1": "1 66957U 25287E   25346.69967332 -.00000151  00000+0 -19373-3 0  9996",
+             "line2": "2 66957  86.4945 346.1700 0007219 190.5502 169.5507 13.69137019   523"}
+        ]"#;
+
+        // Each entry parses as its lines do
+        let tles = from_json_array(json).unwrap();
+        assert_eq!(tles.len(), 2);
+        assert_eq!(
+            tles[0],
+            from_lines(
+                "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921",
+                "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+                Some("ISS (ZARYA)")
+            )
+            .unwrap()
+        );
+        assert_eq!(tles[1].satellite_catalog_number, 66957);
+        assert_eq!(tles[1].common_name, "");
+
+        // An empty array holds no TLEs
+        assert_eq!(from_json_array("[]"), Ok(Vec::new()));
+
+        // Malformed JSON and missing lines are rejected
+        assert_eq!(from_json_array("not json"), Err(TleError::InvalidJson));
+        assert_eq!(from_json_array(r#"{"line1": "1", "line2": "2"}"#), Err(TleError::InvalidJson));
+        assert_eq!(from_json_array(r#"[{"name": "ISS (ZARYA)", "line1": "1 25544U"}]"#), Err(TleError::InvalidJson));
+
+        // An entry that fails to parse reports the error of from_lines
+        let corrupted = json.replace("15.72125391563537", "15.72125391563538");
+        assert_eq!(from_json_array(&corrupted), Err(TleError::ChecksumFailed { line: 2 }));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_parsing_from_file_contents() {
+        // Three satellites where the middle one has a corrupted checksum
+        let entries = from_path("assets/three_sats.tle").unwrap();
+        assert_eq!(entries.len(), 3);
+
+        // The named ISS record parses
+        let iss_tle = entries[0].as_ref().ok().unwrap();
+        assert_eq!(iss_tle.common_name, "ISS (ZARYA)");
+        assert_eq!(iss_tle.satellite_catalog_number, 25544);
+
+        // The malformed record fails on its own
+        assert_eq!(entries[1].as_ref().err(), Some(&TleError::ChecksumFailed { line: 1 }));
+
+        // The unnamed record after it still parses
+        let noaa_tle = entries[2].as_ref().ok().unwrap();
+        assert_eq!(noaa_tle.common_name, "");
+        assert_eq!(noaa_tle.satellite_catalog_number, 28057);
+
+        // CRLF line endings and trailing blank lines parse the same way
+        let contents = fs::read_to_string("assets/three_sats.tle").unwrap();
+        let crlf_contents = contents.replace('\n', "\r\n") + "\r\n\r\n";
+        let crlf_entries = from_file_contents(&crlf_contents);
+        assert_eq!(crlf_entries.len(), 3);
+        assert!(crlf_entries[0] == entries[0]);
+        assert!(crlf_entries[2] == entries[2]);
+
+        // A truncated record does not swallow the record after it, and reports a single error
+        let lines: Vec<&str> = contents.lines().collect();
+        let truncated = [lines[0], lines[1], lines[2], lines[3], lines[4], lines[6], lines[7]].join("\n");
+        let truncated_entries = from_file_contents(&truncated);
+        assert_eq!(truncated_entries.len(), 3);
+        assert!(truncated_entries[0] == entries[0]);
+        assert_eq!(truncated_entries[1].as_ref().err(), Some(&TleError::InvalidLineCount));
+        assert!(truncated_entries[2] == entries[2]);
+
+        // A Space-Track bulk export with header comments and blank separators parses every record
+        let bulk_entries = from_path("assets/spacetrack_bulk.txt").unwrap();
+        assert_eq!(bulk_entries.len(), 3);
+        assert!(bulk_entries[0] == entries[0]);
+        assert_eq!(bulk_entries[1].as_ref().map(|tle| tle.common_name.as_str()), Ok("VANGUARD 1"));
+        assert!(bulk_entries[2] == entries[2]);
+
+        // Stray data lines are still reported, while stray text lines are skipped
+        let stray = format!("{}\nEND OF FILE\n{}", lines[2], contents);
+        let stray_entries = from_file_contents(&stray);
+        assert_eq!(stray_entries.len(), 4);
+        assert_eq!(stray_entries[0].as_ref().err(), Some(&TleError::InvalidLineCount));
+        assert!(stray_entries[1] == entries[0]);
+        assert_eq!(from_string(&format!("Header\n{}\n{}\n{}\n# Footer", lines[0], lines[1], lines[2])), Ok(vec![entries[0].clone().unwrap()]));
+
+        // A missing file is an I/O error
+        assert!(from_path("assets/missing.tle").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_iter() {
+        /// A reader that repeats a TLE entry, standing in for a large catalog file
+        struct RepeatedEntry {
+            entry: &'static [u8],
+            remaining: usize,
+            offset: usize,
+        }
+
+        impl io::Read for RepeatedEntry {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if self.remaining == 0 {
+                    return Ok(0);
+                }
+                let n = buf.len().min(self.entry.len() - self.offset);
+                buf[..n].copy_from_slice(&self.entry[self.offset..self.offset + n]);
+                self.offset += n;
+                if self.offset == self.entry.len() {
+                    self.offset = 0;
+                    self.remaining -= 1;
+                }
+                return Ok(n);
+            }
+        }
+
+        // Stream about 10 MB of entries without collecting them
+        let entry = b"ISS (ZARYA)\r\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\r\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537\r\n";
+        let reader = io::BufReader::new(RepeatedEntry { entry: entry, remaining: 60000, offset: 0 });
+        let mut count = 0;
+        for result in tle_iter(reader) {
+            assert_eq!(result.unwrap().satellite_catalog_number, 25544);
+            count += 1;
+        }
+        assert_eq!(count, 60000);
+
+        // Bad records are reported and the iteration continues
+        let file = fs::File::open("assets/three_sats.tle").unwrap();
+        let entries: Vec<_> = tle_iter(io::BufReader::new(file)).collect();
+        assert_eq!(entries, from_path("assets/three_sats.tle").unwrap());
+
+        // A catalog with \r line endings is read one line at a time
+        let entry = b"ISS (ZARYA)\r1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\r2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537\r";
+        let reader = io::BufReader::new(RepeatedEntry { entry: entry, remaining: 1000, offset: 0 });
+        let mut records = TleRecords { reader: reader, pending: VecDeque::with_capacity(3), finished: false, error: None };
+        let mut count = 0;
+        while let Some(result) = records.next() {
+            assert_eq!(result.unwrap().satellite_catalog_number, 25544);
+            assert!(records.pending.len() <= 3);
+            count += 1;
+        }
+        assert_eq!(count, 1000);
+
+        // A reader without line endings is an error rather than being buffered whole
+        let mut entries = tle_iter(io::BufReader::new(io::repeat(b'1')));
+        assert_eq!(entries.next(), Some(Err(TleError::Io { kind: io::ErrorKind::InvalidData })));
+        assert_eq!(entries.next(), None);
+
+        // The records read before the line endings stop are still returned
+        let failing = io::Read::chain(&b"1 28057U 03049A   06177.78615833  .00000060  00000-0  35940-4 0  1836\n2 28057  98.4283 247.6961 0000884  88.1964 271.9322 14.35478080140550\n"[..], io::BufReader::new(io::repeat(b'X')));
+        let entries: Vec<_> = tle_iter(io::BufReader::new(failing)).collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].as_ref().unwrap().satellite_catalog_number, 28057);
+        assert_eq!(entries[1], Err(TleError::Io { kind: io::ErrorKind::InvalidData }));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_iter_unreadable_lines() {
+        let tle_line1 = "1 28057U 03049A   06177.78615833  .00000060  00000-0  35940-4 0  1836";
+        let tle_line2 = "2 28057  98.4283 247.6961 0000884  88.1964 271.9322 14.35478080140550";
+        let iss = "ISS (ZARYA)\n1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537\n";
+
+        // An overlong comment between two records is skipped, keeping the records before and after it
+        let contents = format!("{}\n{}\n#{}\n{}", tle_line1, tle_line2, "-".repeat(299), iss);
+        let catalog_numbers: Vec<_> = from_file_contents(&contents).into_iter().map(|result| result.unwrap().satellite_catalog_number).collect();
+        assert_eq!(catalog_numbers, vec![28057, 25544]);
+        assert_eq!(from_string(&contents).unwrap().len(), 2);
+        assert_eq!(from_string_lossy(&contents).len(), 2);
+
+        // A line of exactly the maximum length is still read
+        let name = "X".repeat(MAX_STREAM_LINE_LENGTH);
+        let tles = from_string(&format!("{}\n{}\n{}\n", name, tle_line1, tle_line2)).unwrap();
+        assert_eq!(tles[0].common_name, name[..24]);
+
+        // An overlong name line spoils its own entry only
+        let contents = format!("{}\n{}\n{}\n{}\n{}", tle_line1, tle_line2, "X".repeat(300), tle_line1, tle_line2);
+        let entries = from_file_contents(&contents);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].as_ref().unwrap().satellite_catalog_number, 28057);
+        assert_eq!(entries[1], Err(TleError::Io { kind: io::ErrorKind::InvalidData }));
+
+        // As does a name line that is not UTF-8, while an unreadable comment is skipped
+        let mut contents = b"\xff\xfe comment\n".to_vec();
+        contents.extend_from_slice(iss.as_bytes());
+        contents.extend_from_slice(b"\xff\xfe\n");
+        contents.extend_from_slice(format!("{}\n{}\n", tle_line1, tle_line2).as_bytes());
+        let entries: Vec<_> = tle_iter(&contents[..]).collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].as_ref().unwrap().common_name, "ISS (ZARYA)");
+        assert_eq!(entries[1], Err(TleError::Io { kind: io::ErrorKind::InvalidData }));
+    }
+
+    #[test]
+    #[cfg(feature = "flate2")]
+    fn test_from_gzip_reader() {
+        // The compressed bulk export parses to the same satellites as the plain text
+        let tles = from_gzip_reader(fs::File::open("assets/spacetrack_bulk.txt.gz").unwrap()).unwrap();
+        let expected = from_string(&fs::read_to_string("assets/spacetrack_bulk.txt").unwrap()).unwrap();
+        assert_eq!(tles.len(), 3);
+        assert_eq!(tles, expected);
+        assert_eq!(tles.iter().map(|tle| tle.satellite_catalog_number).collect::<Vec<_>>(), vec![25544, 5, 28057]);
+
+        // Text that is not gzip-compressed is an I/O error
+        let plain = fs::read("assets/spacetrack_bulk.txt").unwrap();
+        assert!(matches!(from_gzip_reader(&plain[..]), Err(TleError::Io { .. })));
+
+        // As is a truncated download
+        let gz = fs::read("assets/spacetrack_bulk.txt.gz").unwrap();
+        assert!(matches!(from_gzip_reader(&gz[..gz.len() / 2]), Err(TleError::Io { .. })));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_parsing_line_endings() {
+        // Define the TLE lines
+        let tle_line0 = "ISS (ZARYA)";
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182 -00100-2 -11606-4 0  2921";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, Some(tle_line0)).unwrap();
+
+        // Line terminators and trailing spaces are ignored by from_lines
+        let tle_crlf = from_lines(&format!("{}\r\n", tle_line1), &format!("{}  \r", tle_line2), Some("ISS (ZARYA)             \r")).unwrap();
+        assert!(tle_crlf == tle);
+
+        // A truncated line is still rejected after trimming
+        assert_eq!(
+            from_lines(&format!("{}\r", &tle_line1[..68]), tle_line2, None).err(),
+            Some(TleError::WrongLineLength { line: 1, length: 68 })
+        );
+
+        // from_string splits on \r\n, \r and \n
+        for ending in ["\r\n", "\r", "\n"] {
+            let tle_string = [tle_line0, tle_line1, tle_line2].join(ending) + ending;
+            let tles = from_string(&tle_string).unwrap();
+            assert_eq!(tles.len(), 1);
+            assert!(tles[0] == tle);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tle_validate_physical() {
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+
+        // A valid TLE has no warnings
+        assert!(tle.validate_physical().is_empty());
+
+        // An eccentricity of one is not an elliptical orbit
+        let hyperbolic = Tle { eccentricity: 1.2, ..tle.clone() };
+        assert_eq!(hyperbolic.validate_physical(), vec![TleWarning::OutOfRange { field: "eccentricity", value: 1.2 }]);
+
+        // A negative mean motion and inclination above 180 degrees parse (with valid checksums) but are flagged
+        let tle_line2_bad = "2 25544 181.0000 247.4627 0006703 130.5360 325.0288 -5.72125391563534";
+        let tle = from_lines(tle_line1, tle_line2_bad, None).unwrap();
+        assert_eq!(tle.validate_physical(), vec![
+            TleWarning::InvalidInclination { value: 181.0 },
+            TleWarning::OutOfRange { field: "mean_motion", value: -5.72125391 },
+        ]);
+
+        // A retrograde inclination is valid, but a negative one is flagged
+        let retrograde = Tle { inclination: 135.0, ..tle.clone() };
+        assert!(!retrograde.validate_physical().contains(&TleWarning::InvalidInclination { value: 135.0 }));
+        let tle_line2_retrograde = "2 25544 135.0000 247.4627 0006703 130.5360 325.0288 15.72125391563533";
+        assert!(from_lines(tle_line1, tle_line2_retrograde, None).unwrap().validate_physical().is_empty());
+        let negative = Tle { inclination: -1.0, mean_motion: 15.72125391, ..tle.clone() };
+        assert_eq!(negative.validate_physical(), vec![TleWarning::InvalidInclination { value: -1.0 }]);
+
+        // The node and argument of perigee are checked in [0, 360)
+        let angles = Tle { inclination: 51.6416, mean_motion: 15.72125391, right_ascension_of_ascending_node: 360.0, argument_of_perigee: -0.5, ..tle };
+        assert_eq!(angles.validate_physical(), vec![
+            TleWarning::OutOfRange { field: "right_ascension_of_ascending_node", value: 360.0 },
+            TleWarning::OutOfRange { field: "argument_of_perigee", value: -0.5 },
+        ]);
+    }
+
+    #[test]
+    fn test_tle_parsing_exponential_field() {
+        // The mantissa sign may be a space, + or -
+        assert!((parse_exponential_field(" 11606-4", 0, 8, "bstar", 1).unwrap() - 0.11606e-4).abs() < 1e-15);
+        assert!((parse_exponential_field("+11606-4", 0, 8, "bstar", 1).unwrap() - 0.11606e-4).abs() < 1e-15);
+        assert!((parse_exponential_field("-11606+0", 0, 8, "bstar", 1).unwrap() - -0.11606).abs() < 1e-15);
+
+        // The exponent sign may be a space, + or -
+        assert!((parse_exponential_field("-11606 1", 0, 8, "bstar", 1).unwrap() - -1.1606).abs() < 1e-15);
+        assert!((parse_exponential_field(" 00000-0", 0, 8, "bstar", 1).unwrap()).abs() < 1e-15);
+
+        // The scaled value is the closest f64 to the exact decimal
+        assert_eq!(parse_exponential_field("-00100-2", 0, 8, "second_derivative_of_mean_motion", 6), Ok(-6.0e-5));
+        assert_eq!(parse_exponential_field(" 12345-3", 0, 8, "second_derivative_of_mean_motion", 6), Ok(7.407e-4));
+        assert_eq!(parse_exponential_field("-11606-4", 0, 8, "bstar", 1), Ok(-1.1606e-5));
+        assert_eq!(parse_exponential_field(" 50000 9", 0, 8, "bstar", 1), Ok(5.0e8));
+
+        // Other characters are rejected
+        assert_eq!(parse_exponential_field("x11606-4", 0, 8, "bstar", 1), Err(TleError::FieldParse { field: "bstar", columns: Some((1, 8)), raw: to_tle_string("x11606-4") }));
+        assert_eq!(parse_exponential_field("-11606x4", 0, 8, "bstar", 1), Err(TleError::FieldParse { field: "bstar", columns: Some((1, 8)), raw: to_tle_string("-11606x4") }));
+        assert_eq!(parse_exponential_field("-11606-x", 0, 8, "bstar", 1), Err(TleError::FieldParse { field: "bstar", columns: Some((1, 8)), raw: to_tle_string("-11606-x") }));
+
+        // A TLE with an unsigned B* exponent parses
+        let tle_line1 = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 +11606 4 0  2925";
+        let tle_line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = from_lines(tle_line1, tle_line2, None).unwrap();
+        assert!((tle.bstar - 1160.6).abs() < 1e-9);
+    }
 }
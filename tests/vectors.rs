@@ -0,0 +1,95 @@
+// Verification of the SGP4 propagator against the AIAA-2006-6753 reference vectors
+#![allow(clippy::needless_return)]
+#![allow(clippy::redundant_field_names)]
+
+// ------------------
+// External Libraries
+// ------------------
+
+// ------------------
+// Internal Libraries
+// ------------------
+use Rusty_SGP4::common::CoordinateFrame;
+use Rusty_SGP4::sgp4::{init_sgp4, propagate};
+use Rusty_SGP4::tle::{from_lines, Tle};
+
+// -------
+// Structs
+// -------
+
+/// A verification satellite, its TLE and the reference states
+struct VerificationCase {
+    /// The parsed TLE
+    tle: Tle,
+
+    /// The (minutes since epoch, position \[km\], velocity \[km/s\]) reference states in TEME coordinates
+    states: Vec<(f64, [f64; 3], [f64; 3])>,
+}
+
+// ---------
+// Constants
+// ---------
+
+/// The verification vectors, as two TLE lines per satellite followed by one row per reference state
+const VECTORS: &str = include_str!("data/aiaa_2006_6753.txt");
+
+/// The position tolerance \[km\]
+const POSITION_TOLERANCE: f64 = 1e-6;
+
+/// The velocity tolerance \[km/s\]
+const VELOCITY_TOLERANCE: f64 = 1e-9;
+
+// ---------
+// Functions
+// ---------
+
+/// Load the verification cases from the fixture data
+///
+/// # Returns
+/// * `Vec<VerificationCase>` - The verification cases, in the order of the fixture data
+fn load_cases() -> Vec<VerificationCase> {
+    let mut cases: Vec<VerificationCase> = Vec::new();
+    let mut lines = VECTORS.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'));
+    while let Some(line) = lines.next() {
+        if line.starts_with("1 ") {
+            // A new satellite starts with its TLE
+            let line2 = lines.next().expect("Line 1 is not followed by line 2");
+            let tle = from_lines(line, line2, None).unwrap_or_else(|error| panic!("Invalid TLE {}: {:?}", line, error));
+            cases.push(VerificationCase { tle: tle, states: Vec::new() });
+        } else {
+            // A reference state of the current satellite
+            let values: Vec<f64> = line.split_whitespace().map(|value| value.parse().unwrap()).collect();
+            assert_eq!(values.len(), 7, "Malformed state row: {}", line);
+            let case = cases.last_mut().expect("State row before the first TLE");
+            case.states.push((values[0], [values[1], values[2], values[3]], [values[4], values[5], values[6]]));
+        }
+    }
+    return cases;
+}
+
+// ----------
+// Unit Tests
+// ----------
+
+#[test]
+fn test_verification_vectors() {
+    let cases = load_cases();
+    assert!(cases.len() >= 6);
+
+    for case in cases {
+        let sgp4 = init_sgp4(&case.tle, None);
+        assert!(!case.states.is_empty(), "No reference states for {}", case.tle.satellite_catalog_number);
+
+        for (minutes, r_expect, v_expect) in case.states {
+            let state = propagate(&sgp4, minutes)
+                .unwrap_or_else(|error| panic!("Propagation of {} failed at t = {}: {:?}", case.tle.satellite_catalog_number, minutes, error));
+            let r = [state.position.x, state.position.y, state.position.z];
+            let v = [state.velocity.x, state.velocity.y, state.velocity.z];
+            for k in 0..3 {
+                assert!((r[k] - r_expect[k]).abs() < POSITION_TOLERANCE, "Position test of {} failed at t = {}: expected {:?}, got {:?}", case.tle.satellite_catalog_number, minutes, r_expect, r);
+                assert!((v[k] - v_expect[k]).abs() < VELOCITY_TOLERANCE, "Velocity test of {} failed at t = {}: expected {:?}, got {:?}", case.tle.satellite_catalog_number, minutes, v_expect, v);
+            }
+            assert_eq!(state.coordinate_frame, CoordinateFrame::TEME);
+        }
+    }
+}